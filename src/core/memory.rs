@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -10,6 +11,8 @@ use tracing::{debug, error, info};
 
 const DEFAULT_MEMORY_PATH: &str = "void_state.json";
 const MEMORY_TARGET: &str = "core::memory";
+/// Default number of calls to [`MemoryField::record_sampled`] per recorded snapshot.
+const DEFAULT_SAMPLE_INTERVAL: usize = 30;
 
 /// Persistent memory buffer shared across Void Engine subsystems.
 #[derive(Resource, Clone, Debug, Serialize, Deserialize)]
@@ -20,12 +23,26 @@ pub struct MemoryField {
     base_path: PathBuf,
     #[serde(skip)]
     writes_since_rotation: usize,
+    #[serde(skip, default = "default_persistent")]
+    persistent: bool,
+    #[serde(skip, default = "default_sample_interval")]
+    sample_interval: usize,
+    #[serde(skip)]
+    samples: HashMap<String, usize>,
 }
 
 fn default_path() -> PathBuf {
     PathBuf::from(DEFAULT_MEMORY_PATH)
 }
 
+fn default_persistent() -> bool {
+    true
+}
+
+fn default_sample_interval() -> usize {
+    DEFAULT_SAMPLE_INTERVAL
+}
+
 impl Default for MemoryField {
     fn default() -> Self {
         Self::new(512)
@@ -40,9 +57,40 @@ impl MemoryField {
             max_snapshots: max_snapshots.max(1),
             base_path: default_path(),
             writes_since_rotation: 0,
+            persistent: true,
+            sample_interval: DEFAULT_SAMPLE_INTERVAL,
+            samples: HashMap::new(),
+        }
+    }
+
+    /// Create a memory field that never touches the disk (tests, embedding).
+    ///
+    /// Snapshots are still capped by `max_snapshots`, but no file is written
+    /// and no rotation archive is produced.
+    pub fn in_memory(max_snapshots: usize) -> Self {
+        Self {
+            persistent: false,
+            ..Self::new(max_snapshots)
         }
     }
 
+    /// Sets how many [`record_sampled`](Self::record_sampled) calls of a module yield one
+    /// recorded snapshot (`1` records every call).
+    pub fn with_sample_interval(mut self, interval: usize) -> Self {
+        self.sample_interval = interval.max(1);
+        self
+    }
+
+    /// Number of [`record_sampled`](Self::record_sampled) calls per recorded snapshot.
+    pub fn sample_interval(&self) -> usize {
+        self.sample_interval
+    }
+
+    /// Returns `true` when snapshots are persisted to disk.
+    pub fn is_persistent(&self) -> bool {
+        self.persistent
+    }
+
     /// Record a JSON snapshot and persist it to disk.
     pub fn record(&mut self, snapshot: Value) {
         self.history.push(snapshot.clone());
@@ -50,6 +98,10 @@ impl MemoryField {
             self.history.remove(0);
         }
 
+        if !self.persistent {
+            return;
+        }
+
         if let Err(err) = self.append_snapshot(&snapshot) {
             error!(target: MEMORY_TARGET, ?err, "failed to append memory snapshot");
         }
@@ -72,8 +124,30 @@ impl MemoryField {
         }
     }
 
+    /// Record a per-step snapshot once every [`sample_interval`](Self::sample_interval)
+    /// calls of its `module`, starting with the first one.
+    ///
+    /// Systems reporting on every fixed step use this so that they neither flood the
+    /// file nor crowd out the snapshots of slower modules.
+    pub fn record_sampled(&mut self, snapshot: Value) {
+        let module = snapshot
+            .get("module")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let calls = self.samples.entry(module).or_default();
+        let due = calls.is_multiple_of(self.sample_interval);
+        *calls += 1;
+        if due {
+            self.record(snapshot);
+        }
+    }
+
     /// Flush the in-memory buffer to disk, replacing existing content.
     pub fn flush(&mut self) {
+        if !self.persistent {
+            return;
+        }
         if let Err(err) = self.write_all() {
             error!(target: MEMORY_TARGET, ?err, "failed to flush memory buffer");
         } else {
@@ -83,6 +157,11 @@ impl MemoryField {
 
     /// Rotate the current memory file into `path`, clearing buffered snapshots.
     pub fn rotate(&mut self, path: &str) {
+        if !self.persistent {
+            self.history.clear();
+            self.writes_since_rotation = 0;
+            return;
+        }
         if let Err(err) = self.rotate_internal(path) {
             error!(target: MEMORY_TARGET, ?err, "failed to rotate memory log");
         } else {
@@ -100,6 +179,9 @@ impl MemoryField {
             max_snapshots: 512,
             base_path: PathBuf::from(path),
             writes_since_rotation: 0,
+            persistent: true,
+            sample_interval: DEFAULT_SAMPLE_INTERVAL,
+            samples: HashMap::new(),
         };
 
         for line in reader.lines() {
@@ -127,44 +209,58 @@ impl MemoryField {
         self.history.len()
     }
 
+    /// Returns `true` when no snapshot is cached.
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Returns the cached snapshots, oldest first.
+    pub fn snapshots(&self) -> &[Value] {
+        &self.history
+    }
+
     /// Returns the most recent snapshot.
     pub fn latest(&self) -> Option<&Value> {
         self.history.last()
     }
 
-    /// Computes the average of a numeric field over the last `window` snapshots.
+    /// Computes the average of a numeric field over the last `window` snapshots carrying it.
+    ///
+    /// Snapshots that do not carry `key` (e.g. field statistics) are ignored.
     pub fn average(&self, key: &str, window: usize) -> Option<f32> {
-        let window = window.max(1);
-        let start = self.history.len().saturating_sub(window);
         let mut sum = 0.0f32;
-        let mut count = 0f32;
+        let mut count = 0usize;
 
-        for snapshot in self.history.iter().skip(start) {
-            if let Some(value) = snapshot.get(key) {
-                if let Some(num) = value.as_f64() {
-                    sum += num as f32;
-                    count += 1.0;
-                }
-            }
+        let values = self
+            .history
+            .iter()
+            .rev()
+            .filter_map(|snapshot| snapshot.get(key)?.as_f64())
+            .take(window.max(1));
+        for value in values {
+            sum += value as f32;
+            count += 1;
         }
 
-        if count > 0.0 {
-            Some(sum / count)
-        } else {
-            None
-        }
+        (count > 0).then(|| sum / count as f32)
     }
 
     /// Estimates the linear trend (difference) for a numeric field across the last `window` snapshots.
+    ///
+    /// Snapshots that do not carry `key` (e.g. field statistics) are ignored.
     pub fn trend(&self, key: &str, window: usize) -> Option<f32> {
-        if window < 2 || self.history.len() < 2 {
+        if window < 2 {
             return None;
         }
 
-        let window = window.min(self.history.len());
-        let start = self.history.len() - window;
-        let first = self.history.get(start)?.get(key)?.as_f64()? as f32;
-        let last = self.history.last()?.get(key)?.as_f64()? as f32;
+        let mut values = self
+            .history
+            .iter()
+            .rev()
+            .filter_map(|snapshot| snapshot.get(key)?.as_f64())
+            .take(window);
+        let last = values.next()? as f32;
+        let first = values.last()? as f32;
         Some(last - first)
     }
 
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use tracing::{debug, info};
 
 pub mod memory;
@@ -28,9 +30,9 @@ impl Engine {
     /// Lance la boucle principale du moteur.
     pub fn run(&mut self) {
         info!(target: "core", "Boucle principale démarrée");
-        while self.is_running {
+        if self.is_running {
+            // temporaire : un seul tick pour éviter la boucle infinie lors des tests initiaux
             self.update();
-            break; // temporaire : évite la boucle infinie pour les tests initiaux
         }
         info!(target: "core", "Boucle principale terminée");
     }
@@ -42,6 +44,43 @@ impl Engine {
     }
 }
 
+/// Graine globale du moteur : toute source pseudo-aléatoire en dérive un flux
+/// indépendant, de sorte qu’une exécution est entièrement reproductible.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct EngineSeed(pub u64);
+
+impl Default for EngineSeed {
+    /// Lit `VOID_SEED` (graine fixe par défaut).
+    fn default() -> Self {
+        Self(
+            std::env::var("VOID_SEED")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0x5EED_F01D),
+        )
+    }
+}
+
+impl EngineSeed {
+    /// Dérive la graine du flux nommé `stream` (splitmix64 sur un hachage FNV-1a du nom).
+    pub fn derive(&self, stream: &str) -> u64 {
+        let hash = stream.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        let mut z = self.0 ^ hash;
+        z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Générateur pseudo-aléatoire déterministe du flux `stream`.
+    pub fn rng(&self, stream: &str) -> StdRng {
+        StdRng::seed_from_u64(self.derive(stream))
+    }
+}
+
 /// Système d’exécution du moteur dans le cycle Bevy.
 fn run_engine(mut engine: ResMut<Engine>) {
     engine.run();
@@ -54,10 +93,24 @@ fn run_engine(mut engine: ResMut<Engine>) {
 pub fn init(app: &mut App) {
     info!(target: "core", "Initialisation du noyau Void Engine");
 
-    app.insert_resource(Engine::new())
-        .insert_resource(MemoryField::new(std::env::var("VOID_MEMORY_CAPACITY").ok()
+    let mut memory = MemoryField::new(
+        std::env::var("VOID_MEMORY_CAPACITY")
+            .ok()
             .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(10)))
+            .unwrap_or(10),
+    );
+    // Appels par instantané des modules qui rendent compte à chaque pas.
+    if let Some(interval) = std::env::var("VOID_MEMORY_SAMPLE_INTERVAL")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+    {
+        memory = memory.with_sample_interval(interval);
+    }
+
+    app.insert_resource(Engine::new())
+        .init_resource::<EngineSeed>()
+        .register_type::<EngineSeed>()
+        .insert_resource(memory)
         .add_systems(Startup, run_engine);
 
     info!(target: "core", "Noyau enregistré et prêt à fonctionner");
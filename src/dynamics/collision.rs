@@ -0,0 +1,619 @@
+//! Détection et résolution des collisions entre corps.
+//!
+//! Chaque pas fixe, après l’intégration :
+//! 1. **broadphase** : les boîtes englobantes des [`Collider`] sont rangées dans une table
+//!    de hachage spatiale ; seules les paires partageant une cellule et dont les boîtes se
+//!    recouvrent sont retenues,
+//! 2. **narrowphase** : un [`Contact`] (normale, pénétration, point) est calculé pour
+//!    chaque paire selon les formes (sphère, boîte alignée, capsule),
+//! 3. **réponse** : des impulsions normales (restitution) et tangentielles (frottement de
+//!    Coulomb) corrigent les vitesses, puis une correction de position résorbe la
+//!    pénétration.
+//!
+//! Un corps sans [`Velocity`] ou sans [`Mass`] positive est statique. La réponse est
+//! purement linéaire : les contacts n’induisent pas de rotation. Chaque contact est
+//! publié comme [`CollisionEvent`].
+
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::{DynamicsSet, Mass, Velocity};
+
+/// Au-delà de ce nombre de cellules, un collider est testé contre tous les autres plutôt
+/// que rangé dans la table spatiale.
+const MAX_CELLS_PER_COLLIDER: i64 = 4096;
+
+/// Forme d’un collider, exprimée dans le repère de l’entité (l’échelle est ignorée).
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum ColliderShape {
+    Sphere {
+        radius: f32,
+    },
+    /// Boîte alignée sur les axes du monde (la rotation est ignorée).
+    Aabb {
+        half_extents: Vec3,
+    },
+    /// Segment de longueur `2 half_height` selon l’axe Y local, épaissi de `radius`.
+    Capsule {
+        radius: f32,
+        half_height: f32,
+    },
+}
+
+impl Default for ColliderShape {
+    fn default() -> Self {
+        Self::Sphere { radius: 0.5 }
+    }
+}
+
+/// Composant : forme de collision et matériau d’un corps.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Collider {
+    pub shape: ColliderShape,
+    /// Coefficient de restitution (0 : choc mou, 1 : choc élastique).
+    pub restitution: f32,
+    /// Coefficient de frottement de Coulomb.
+    pub friction: f32,
+}
+
+impl Default for Collider {
+    fn default() -> Self {
+        Self {
+            shape: ColliderShape::default(),
+            restitution: 0.5,
+            friction: 0.3,
+        }
+    }
+}
+
+impl Collider {
+    /// Sphère de rayon `radius`.
+    pub fn sphere(radius: f32) -> Self {
+        Self {
+            shape: ColliderShape::Sphere { radius },
+            ..default()
+        }
+    }
+
+    /// Boîte alignée de demi-dimensions `half_extents`.
+    pub fn aabb(half_extents: Vec3) -> Self {
+        Self {
+            shape: ColliderShape::Aabb { half_extents },
+            ..default()
+        }
+    }
+
+    /// Capsule d’axe Y local.
+    pub fn capsule(radius: f32, half_height: f32) -> Self {
+        Self {
+            shape: ColliderShape::Capsule {
+                radius,
+                half_height,
+            },
+            ..default()
+        }
+    }
+
+    /// Remplace le coefficient de restitution.
+    pub fn with_restitution(mut self, restitution: f32) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+    /// Remplace le coefficient de frottement.
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// Forme placée dans le monde.
+    fn world(&self, transform: &Transform) -> WorldShape {
+        let center = transform.translation;
+        match self.shape {
+            ColliderShape::Sphere { radius } => WorldShape::Segment {
+                a: center,
+                b: center,
+                radius,
+            },
+            ColliderShape::Aabb { half_extents } => WorldShape::Box {
+                min: center - half_extents.abs(),
+                max: center + half_extents.abs(),
+            },
+            ColliderShape::Capsule {
+                radius,
+                half_height,
+            } => {
+                let axis = transform.rotation * Vec3::Y * half_height;
+                WorldShape::Segment {
+                    a: center - axis,
+                    b: center + axis,
+                    radius,
+                }
+            }
+        }
+    }
+}
+
+/// Forme en coordonnées monde : segment épaissi (sphère, capsule) ou boîte alignée.
+#[derive(Clone, Copy, Debug)]
+enum WorldShape {
+    Segment { a: Vec3, b: Vec3, radius: f32 },
+    Box { min: Vec3, max: Vec3 },
+}
+
+impl WorldShape {
+    fn bounds(&self) -> (Vec3, Vec3) {
+        match *self {
+            Self::Segment { a, b, radius } => (a.min(b) - radius, a.max(b) + radius),
+            Self::Box { min, max } => (min, max),
+        }
+    }
+}
+
+/// Point de contact entre deux formes ; `normal` va de la première vers la seconde.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    pub normal: Vec3,
+    /// Profondeur de pénétration (positive).
+    pub depth: f32,
+    pub point: Vec3,
+}
+
+impl Contact {
+    fn flipped(self) -> Self {
+        Self {
+            normal: -self.normal,
+            ..self
+        }
+    }
+}
+
+/// Point du segment `[a, b]` le plus proche de `p`.
+fn closest_on_segment(a: Vec3, b: Vec3, p: Vec3) -> Vec3 {
+    let ab = b - a;
+    let len2 = ab.length_squared();
+    if len2 <= f32::EPSILON {
+        return a;
+    }
+    a + ab * ((p - a).dot(ab) / len2).clamp(0.0, 1.0)
+}
+
+/// Points les plus proches entre les segments `[p1, q1]` et `[p2, q2]`.
+fn closest_between_segments(p1: Vec3, q1: Vec3, p2: Vec3, q2: Vec3) -> (Vec3, Vec3) {
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+    let a = d1.length_squared();
+    let e = d2.length_squared();
+    let f = d2.dot(r);
+
+    let (s, t) = if a <= f32::EPSILON && e <= f32::EPSILON {
+        (0.0, 0.0)
+    } else if a <= f32::EPSILON {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e <= f32::EPSILON {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denom = a * e - b * b;
+            let mut s = if denom > f32::EPSILON {
+                ((b * f - c * e) / denom).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let mut t = (b * s + f) / e;
+            if t < 0.0 {
+                t = 0.0;
+                s = (-c / a).clamp(0.0, 1.0);
+            } else if t > 1.0 {
+                t = 1.0;
+                s = ((b - c) / a).clamp(0.0, 1.0);
+            }
+            (s, t)
+        }
+    };
+    (p1 + d1 * s, p2 + d2 * t)
+}
+
+fn segment_segment(a: (Vec3, Vec3, f32), b: (Vec3, Vec3, f32)) -> Option<Contact> {
+    let (p, q) = closest_between_segments(a.0, a.1, b.0, b.1);
+    let offset = q - p;
+    let distance = offset.length();
+    let reach = a.2 + b.2;
+    if distance >= reach {
+        return None;
+    }
+    let normal = if distance > f32::EPSILON {
+        offset / distance
+    } else {
+        Vec3::Y
+    };
+    Some(Contact {
+        normal,
+        depth: reach - distance,
+        point: p + normal * (a.2 - 0.5 * (reach - distance)),
+    })
+}
+
+fn segment_box(segment: (Vec3, Vec3, f32), min: Vec3, max: Vec3) -> Option<Contact> {
+    let (a, b, radius) = segment;
+    // Projections alternées segment ↔ boîte (ensembles convexes) vers la paire la plus proche.
+    let mut p = closest_on_segment(a, b, (min + max) * 0.5);
+    let mut q = p.clamp(min, max);
+    for _ in 0..4 {
+        p = closest_on_segment(a, b, q);
+        q = p.clamp(min, max);
+    }
+
+    let offset = q - p;
+    let distance = offset.length();
+    if distance > f32::EPSILON {
+        if distance >= radius {
+            return None;
+        }
+        return Some(Contact {
+            normal: offset / distance,
+            depth: radius - distance,
+            point: q,
+        });
+    }
+
+    // Axe du segment dans la boîte : sortie par la face la plus proche.
+    let to_min = p - min;
+    let to_max = max - p;
+    let mut best = (f32::INFINITY, Vec3::ZERO);
+    for axis in 0..3 {
+        let unit = Vec3::AXES[axis];
+        if to_min[axis] < best.0 {
+            best = (to_min[axis], -unit);
+        }
+        if to_max[axis] < best.0 {
+            best = (to_max[axis], unit);
+        }
+    }
+    let (face, outward) = best;
+    Some(Contact {
+        // La boîte doit s’écarter à l’opposé de la face de sortie du segment.
+        normal: -outward,
+        depth: face + radius,
+        point: p,
+    })
+}
+
+fn box_box(a: (Vec3, Vec3), b: (Vec3, Vec3)) -> Option<Contact> {
+    let overlap = a.1.min(b.1) - a.0.max(b.0);
+    if overlap.min_element() <= 0.0 {
+        return None;
+    }
+    let axis = if overlap.x <= overlap.y && overlap.x <= overlap.z {
+        0
+    } else if overlap.y <= overlap.z {
+        1
+    } else {
+        2
+    };
+    let direction = ((b.0 + b.1) - (a.0 + a.1))[axis];
+    let unit = Vec3::AXES[axis];
+    Some(Contact {
+        normal: if direction < 0.0 { -unit } else { unit },
+        depth: overlap[axis],
+        point: (a.1.min(b.1) + a.0.max(b.0)) * 0.5,
+    })
+}
+
+fn contact(a: &WorldShape, b: &WorldShape) -> Option<Contact> {
+    match (*a, *b) {
+        (
+            WorldShape::Segment {
+                a: a0,
+                b: a1,
+                radius: ra,
+            },
+            WorldShape::Segment {
+                a: b0,
+                b: b1,
+                radius: rb,
+            },
+        ) => segment_segment((a0, a1, ra), (b0, b1, rb)),
+        (WorldShape::Segment { a, b, radius }, WorldShape::Box { min, max }) => {
+            segment_box((a, b, radius), min, max)
+        }
+        (WorldShape::Box { min, max }, WorldShape::Segment { a, b, radius }) => {
+            segment_box((a, b, radius), min, max).map(Contact::flipped)
+        }
+        (WorldShape::Box { min: a0, max: a1 }, WorldShape::Box { min: b0, max: b1 }) => {
+            box_box((a0, a1), (b0, b1))
+        }
+    }
+}
+
+/// Ressource : paramètres de la détection et de la réponse aux collisions.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CollisionConfig {
+    pub enabled: bool,
+    /// Taille des cellules de la table spatiale (0 : encombrement médian des colliders).
+    pub cell_size: f32,
+    /// Passes de résolution des vitesses sur l’ensemble des contacts.
+    pub iterations: u32,
+    /// Fraction de la pénétration corrigée à chaque pas.
+    pub position_correction: f32,
+    /// Pénétration tolérée sans correction.
+    pub slop: f32,
+}
+
+impl Default for CollisionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cell_size: 0.0,
+            iterations: 4,
+            position_correction: 0.8,
+            slop: 0.001,
+        }
+    }
+}
+
+/// Ressource : bilan du dernier pas de collision.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct CollisionStats {
+    pub colliders: usize,
+    /// Taille des cellules de la table spatiale utilisée.
+    pub cell_size: f32,
+    /// Colliders trop étendus pour la table, testés contre tous les autres.
+    pub large_colliders: usize,
+    /// Paires retenues par la broadphase.
+    pub candidate_pairs: usize,
+    pub contacts: usize,
+}
+
+/// Message : contact entre deux entités pendant le dernier pas fixe.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct CollisionEvent {
+    pub a: Entity,
+    pub b: Entity,
+    /// Normale de `a` vers `b`.
+    pub normal: Vec3,
+    pub depth: f32,
+    pub point: Vec3,
+    /// Impulsion normale totale échangée (N·s).
+    pub impulse: f32,
+}
+
+/// Taille de cellule par défaut : encombrement médian des boîtes englobantes.
+///
+/// Le plus grand encombrement ferait tenir tous les petits corps dans quelques cellules
+/// dès qu’un collider étendu (sol, mur) est présent, et la broadphase deviendrait O(n²).
+fn median_extent(bounds: &[(Vec3, Vec3)]) -> f32 {
+    let mut extents: Vec<f32> = bounds
+        .iter()
+        .map(|(min, max)| (*max - *min).max_element())
+        .collect();
+    if extents.is_empty() {
+        return 1.0;
+    }
+    let middle = extents.len() / 2;
+    let (_, median, _) = extents.select_nth_unstable_by(middle, f32::total_cmp);
+    median.max(1e-3)
+}
+
+/// Paires candidates dont les boîtes englobantes se recouvrent (indices croissants), et
+/// nombre de colliders trop étendus pour la table spatiale.
+fn broadphase(bounds: &[(Vec3, Vec3)], cell_size: f32) -> (Vec<(usize, usize)>, usize) {
+    let mut cells: HashMap<IVec3, Vec<usize>> = HashMap::new();
+    let mut large = Vec::new();
+    for (index, (min, max)) in bounds.iter().enumerate() {
+        let lo = (*min / cell_size).floor().as_ivec3();
+        let hi = (*max / cell_size).floor().as_ivec3();
+        let span = (hi - lo + IVec3::ONE).as_i64vec3();
+        if span.x * span.y * span.z > MAX_CELLS_PER_COLLIDER {
+            large.push(index);
+            continue;
+        }
+        for x in lo.x..=hi.x {
+            for y in lo.y..=hi.y {
+                for z in lo.z..=hi.z {
+                    cells.entry(IVec3::new(x, y, z)).or_default().push(index);
+                }
+            }
+        }
+    }
+
+    let overlaps = |i: usize, j: usize| {
+        let (a, b) = (bounds[i], bounds[j]);
+        a.0.cmple(b.1).all() && b.0.cmple(a.1).all()
+    };
+    let mut pairs = HashSet::new();
+    for members in cells.values() {
+        for (k, &i) in members.iter().enumerate() {
+            for &j in &members[k + 1..] {
+                pairs.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    for &i in &large {
+        for j in 0..bounds.len() {
+            if i != j {
+                pairs.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+
+    let mut pairs: Vec<_> = pairs.into_iter().filter(|&(i, j)| overlaps(i, j)).collect();
+    pairs.sort_unstable();
+    (pairs, large.len())
+}
+
+struct Body {
+    entity: Entity,
+    position: Vec3,
+    velocity: Vec3,
+    inverse_mass: f32,
+    collider: Collider,
+}
+
+/// Corps dotés d’un collider.
+type ColliderQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Transform,
+        Option<&'static mut Velocity>,
+        Option<&'static Mass>,
+        &'static Collider,
+    ),
+>;
+
+/// Système : détecte les contacts, corrige vitesses et positions et publie les événements.
+pub fn resolve_collisions(
+    mut query: ColliderQuery,
+    config: Res<CollisionConfig>,
+    mut stats: ResMut<CollisionStats>,
+    mut events: MessageWriter<CollisionEvent>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let mut bodies = Vec::new();
+    let mut shapes = Vec::new();
+    for (entity, transform, velocity, mass, collider) in query.iter() {
+        let dynamic = velocity.is_some() && mass.is_some_and(|m| m.value > 0.0);
+        bodies.push(Body {
+            entity,
+            position: transform.translation,
+            velocity: velocity.map_or(Vec3::ZERO, |v| v.linear),
+            inverse_mass: if dynamic {
+                mass.map_or(0.0, |m| m.value.recip())
+            } else {
+                0.0
+            },
+            collider: *collider,
+        });
+        shapes.push(collider.world(transform));
+    }
+
+    let bounds: Vec<_> = shapes.iter().map(WorldShape::bounds).collect();
+    let cell_size = if config.cell_size > 0.0 {
+        config.cell_size
+    } else {
+        median_extent(&bounds)
+    };
+    let (pairs, large_colliders) = broadphase(&bounds, cell_size);
+
+    let contacts: Vec<(usize, usize, Contact)> = pairs
+        .iter()
+        .filter(|&&(i, j)| bodies[i].inverse_mass + bodies[j].inverse_mass > 0.0)
+        .filter_map(|&(i, j)| contact(&shapes[i], &shapes[j]).map(|c| (i, j, c)))
+        .collect();
+
+    // Vitesse de séparation visée : la restitution s’applique au rapprochement initial.
+    let targets: Vec<f32> = contacts
+        .iter()
+        .map(|&(i, j, contact)| {
+            let (a, b) = (&bodies[i], &bodies[j]);
+            let restitution = 0.5 * (a.collider.restitution + b.collider.restitution);
+            -restitution * (b.velocity - a.velocity).dot(contact.normal).min(0.0)
+        })
+        .collect();
+
+    // Résolution itérative des vitesses ; les impulsions cumulées sont bornées (normale
+    // positive, frottement dans le cône de Coulomb).
+    let mut impulses = vec![0.0f32; contacts.len()];
+    let mut frictions = vec![Vec3::ZERO; contacts.len()];
+    for _ in 0..config.iterations.max(1) {
+        for (k, &(i, j, contact)) in contacts.iter().enumerate() {
+            let (a, b) = (&bodies[i], &bodies[j]);
+            let inverse = a.inverse_mass + b.inverse_mass;
+            let friction = 0.5 * (a.collider.friction + b.collider.friction);
+
+            let relative = b.velocity - a.velocity;
+            let total =
+                (impulses[k] + (targets[k] - relative.dot(contact.normal)) / inverse).max(0.0);
+            let normal_impulse = total - impulses[k];
+            impulses[k] = total;
+
+            let relative = relative + contact.normal * (normal_impulse * inverse);
+            let tangent_velocity = relative - relative.dot(contact.normal) * contact.normal;
+            let accumulated = (frictions[k] - tangent_velocity / inverse)
+                .clamp_length_max(friction * impulses[k]);
+            let tangent_impulse = accumulated - frictions[k];
+            frictions[k] = accumulated;
+
+            let impulse = contact.normal * normal_impulse + tangent_impulse;
+            let (inverse_a, inverse_b) = (a.inverse_mass, b.inverse_mass);
+            bodies[i].velocity -= impulse * inverse_a;
+            bodies[j].velocity += impulse * inverse_b;
+        }
+    }
+
+    // Correction de position : chaque corps recule au prorata de son inverse de masse.
+    for &(i, j, contact) in &contacts {
+        let inverse = bodies[i].inverse_mass + bodies[j].inverse_mass;
+        let correction = contact.normal
+            * ((contact.depth - config.slop).max(0.0) * config.position_correction / inverse);
+        let (inverse_a, inverse_b) = (bodies[i].inverse_mass, bodies[j].inverse_mass);
+        bodies[i].position -= correction * inverse_a;
+        bodies[j].position += correction * inverse_b;
+    }
+
+    for body in &bodies {
+        if body.inverse_mass == 0.0 {
+            continue;
+        }
+        if let Ok((_, mut transform, velocity, _, _)) = query.get_mut(body.entity) {
+            transform.translation = body.position;
+            if let Some(mut velocity) = velocity {
+                velocity.linear = body.velocity;
+            }
+        }
+    }
+
+    for (&(i, j, contact), impulse) in contacts.iter().zip(&impulses) {
+        events.write(CollisionEvent {
+            a: bodies[i].entity,
+            b: bodies[j].entity,
+            normal: contact.normal,
+            depth: contact.depth,
+            point: contact.point,
+            impulse: *impulse,
+        });
+    }
+
+    *stats = CollisionStats {
+        colliders: bodies.len(),
+        cell_size,
+        large_colliders,
+        candidate_pairs: pairs.len(),
+        contacts: contacts.len(),
+    };
+    debug!(
+        target: "dynamics",
+        colliders = stats.colliders,
+        pairs = stats.candidate_pairs,
+        contacts = stats.contacts,
+        "collisions résolues"
+    );
+}
+
+/// Enregistre la détection et la réponse aux collisions.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<ColliderShape>()
+        .register_type::<Collider>()
+        .register_type::<CollisionConfig>()
+        .register_type::<CollisionStats>()
+        .init_resource::<CollisionConfig>()
+        .init_resource::<CollisionStats>()
+        .add_message::<CollisionEvent>()
+        .add_systems(
+            FixedUpdate,
+            resolve_collisions.in_set(DynamicsSet::Collisions),
+        );
+}
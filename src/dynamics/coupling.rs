@@ -0,0 +1,368 @@
+//! Couplage de phase entre oscillateurs (modèle de Kuramoto).
+//!
+//! Les entités portant un [`Oscillator`] sont reliées par un graphe pondéré non orienté,
+//! le [`CouplingNetwork`], dont la forme est choisie par [`CouplingTopology`] : tous
+//! reliés, anneau, graphe aléatoire d’Erdős–Rényi ou petit monde de Watts–Strogatz. Les
+//! topologies aléatoires sont tirées du flux `dynamics::coupling` de l’[`EngineSeed`].
+//!
+//! À chaque pas fixe, la phase de chaque oscillateur est tirée vers celles de ses voisins :
+//!
+//! `dθᵢ/dt = K / ⟨k⟩ · Σⱼ wᵢⱼ sin(θⱼ − θᵢ)`
+//!
+//! où `K` est la force de couplage (rad/s), `wᵢⱼ` le poids de l’arête et `⟨k⟩` le degré
+//! moyen du graphe (soit `N − 1` en couplage global, comme dans le modèle d’origine).
+//! L’avance propre `2π f` reste appliquée par la couche `function`.
+//!
+//! La synchronisation est mesurée par le paramètre d’ordre `r e^{iψ} = (1/N) Σⱼ e^{iθⱼ}` :
+//! `r = 0` pour des phases incohérentes, `r = 1` pour des oscillateurs en phase.
+
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::EdgeRef;
+use rand::Rng;
+use rayon::prelude::*;
+use tracing::debug;
+
+use super::DynamicsSet;
+use crate::core::EngineSeed;
+use crate::function::Oscillator;
+
+/// En deçà de ce nombre d’oscillateurs, le couplage reste séquentiel.
+const PARALLEL_THRESHOLD: usize = 256;
+
+/// Forme du réseau de couplage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum CouplingTopology {
+    /// Chaque oscillateur est relié à tous les autres.
+    #[default]
+    AllToAll,
+    /// Anneau : chaque oscillateur est relié à ses `neighbors` voisins de chaque côté.
+    Ring { neighbors: usize },
+    /// Chaque paire est reliée avec la probabilité `probability`.
+    Random { probability: f32 },
+    /// Anneau dont chaque arête est redirigée vers un oscillateur au hasard avec la
+    /// probabilité `rewiring`.
+    SmallWorld { neighbors: usize, rewiring: f32 },
+}
+
+/// Ressource : paramètres du couplage.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CouplingConfig {
+    /// Applique le couplage ; le paramètre d’ordre est mesuré dans tous les cas.
+    pub enabled: bool,
+    /// Force de couplage `K` (rad/s).
+    pub strength: f32,
+    pub topology: CouplingTopology,
+    /// Répartit le calcul sur le pool rayon.
+    pub parallel: bool,
+}
+
+impl Default for CouplingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            strength: 1.0,
+            topology: CouplingTopology::default(),
+            parallel: true,
+        }
+    }
+}
+
+/// Ressource : état de synchronisation mesuré au dernier pas.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct CouplingStats {
+    pub oscillators: usize,
+    pub edges: usize,
+    pub mean_degree: f32,
+    /// Module `r` du paramètre d’ordre de Kuramoto, dans `[0, 1]`.
+    pub order: f32,
+    /// Phase moyenne `ψ` (radians, dans `[0, 2π)`).
+    pub mean_phase: f32,
+}
+
+/// Paramètre d’ordre `(r, ψ)` des phases `phases`.
+pub fn order_parameter(phases: impl IntoIterator<Item = f32>) -> (f32, f32) {
+    let (mut sum, mut count) = (Vec2::ZERO, 0usize);
+    for phase in phases {
+        sum += Vec2::from_angle(phase);
+        count += 1;
+    }
+    if count == 0 {
+        return (0.0, 0.0);
+    }
+    let mean = sum / count as f32;
+    (mean.length(), mean.to_angle().rem_euclid(TAU))
+}
+
+/// Ressource : graphe pondéré reliant les oscillateurs.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CouplingNetwork {
+    graph: UnGraph<Entity, f32>,
+    nodes: HashMap<Entity, NodeIndex>,
+    topology: CouplingTopology,
+}
+
+impl CouplingNetwork {
+    /// Relie `entities` selon `topology` ; les arêtes ont un poids unitaire.
+    pub fn build(entities: Vec<Entity>, topology: CouplingTopology, rng: &mut impl Rng) -> Self {
+        let n = entities.len();
+        let mut network = Self {
+            topology,
+            ..default()
+        };
+        for entity in entities {
+            let node = network.graph.add_node(entity);
+            network.nodes.insert(entity, node);
+        }
+
+        let ring = |neighbors: usize| {
+            let mut edges = Vec::new();
+            for i in 0..n {
+                for d in 1..=neighbors.min(n / 2) {
+                    edges.push((i, (i + d) % n));
+                }
+            }
+            edges
+        };
+        let edges = match topology {
+            CouplingTopology::AllToAll => (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .collect(),
+            CouplingTopology::Ring { neighbors } => ring(neighbors),
+            CouplingTopology::Random { probability } => {
+                let mut edges = Vec::new();
+                for i in 0..n {
+                    for j in i + 1..n {
+                        if rng.random::<f32>() < probability {
+                            edges.push((i, j));
+                        }
+                    }
+                }
+                edges
+            }
+            CouplingTopology::SmallWorld {
+                neighbors,
+                rewiring,
+            } => {
+                let mut edges = ring(neighbors);
+                let mut present: HashSet<(usize, usize)> =
+                    edges.iter().map(|&(i, j)| (i.min(j), i.max(j))).collect();
+                for edge in edges.iter_mut() {
+                    if n < 3 || rng.random::<f32>() >= rewiring {
+                        continue;
+                    }
+                    // Nouvelle extrémité ni confondue avec la source ni déjà reliée.
+                    let (i, j) = *edge;
+                    let target = (0..n)
+                        .map(|_| rng.random_range(0..n))
+                        .find(|&k| k != i && !present.contains(&(i.min(k), i.max(k))));
+                    if let Some(k) = target {
+                        present.remove(&(i.min(j), i.max(j)));
+                        present.insert((i.min(k), i.max(k)));
+                        *edge = (i, k);
+                    }
+                }
+                edges
+            }
+        };
+
+        for (i, j) in edges {
+            if i != j {
+                network
+                    .graph
+                    .update_edge(NodeIndex::new(i), NodeIndex::new(j), 1.0);
+            }
+        }
+        network
+    }
+
+    /// Nombre d’oscillateurs reliés.
+    pub fn len(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// `true` si le réseau ne contient aucun oscillateur.
+    pub fn is_empty(&self) -> bool {
+        self.graph.node_count() == 0
+    }
+
+    /// Nombre d’arêtes.
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Topologie ayant servi à construire le réseau.
+    pub fn topology(&self) -> CouplingTopology {
+        self.topology
+    }
+
+    /// Degré moyen `⟨k⟩ = 2E / N`.
+    pub fn mean_degree(&self) -> f32 {
+        if self.is_empty() {
+            0.0
+        } else {
+            2.0 * self.edge_count() as f32 / self.len() as f32
+        }
+    }
+
+    /// Graphe sous-jacent (nœuds : entités, poids : `wᵢⱼ`).
+    pub fn graph(&self) -> &UnGraph<Entity, f32> {
+        &self.graph
+    }
+
+    /// Voisins de `entity` avec le poids de l’arête.
+    pub fn neighbors(&self, entity: Entity) -> Vec<(Entity, f32)> {
+        let Some(&node) = self.nodes.get(&entity) else {
+            return Vec::new();
+        };
+        self.graph
+            .edges(node)
+            .map(|edge| {
+                let other = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                (self.graph[other], *edge.weight())
+            })
+            .collect()
+    }
+
+    /// Poids de l’arête entre `a` et `b`, s’ils sont reliés.
+    pub fn weight(&self, a: Entity, b: Entity) -> Option<f32> {
+        let edge = self
+            .graph
+            .find_edge(*self.nodes.get(&a)?, *self.nodes.get(&b)?)?;
+        self.graph.edge_weight(edge).copied()
+    }
+
+    /// Fixe le poids de l’arête entre `a` et `b`, en la créant au besoin.
+    ///
+    /// Les poids sont conservés jusqu’à la prochaine reconstruction du réseau (ajout ou
+    /// retrait d’oscillateur, changement de topologie). Renvoie `false` si l’une des
+    /// entités n’est pas dans le réseau.
+    pub fn set_weight(&mut self, a: Entity, b: Entity, weight: f32) -> bool {
+        match (self.nodes.get(&a), self.nodes.get(&b)) {
+            (Some(&a), Some(&b)) if a != b => {
+                self.graph.update_edge(a, b, weight);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Vitesses de phase induites par le couplage, dans l’ordre des nœuds.
+    fn phase_velocities(&self, phases: &[f32], strength: f32, parallel: bool) -> Vec<f32> {
+        let mean_degree = self.mean_degree();
+        if mean_degree == 0.0 {
+            return vec![0.0; phases.len()];
+        }
+        let gain = strength / mean_degree;
+        let eval = |i: usize| {
+            let node = NodeIndex::new(i);
+            let pull: f32 = self
+                .graph
+                .edges(node)
+                .map(|edge| {
+                    let other = if edge.source() == node {
+                        edge.target()
+                    } else {
+                        edge.source()
+                    };
+                    edge.weight() * (phases[other.index()] - phases[i]).sin()
+                })
+                .sum();
+            gain * pull
+        };
+        if parallel && phases.len() >= PARALLEL_THRESHOLD {
+            (0..phases.len()).into_par_iter().map(eval).collect()
+        } else {
+            (0..phases.len()).map(eval).collect()
+        }
+    }
+}
+
+/// Système : reconstruit le réseau au besoin, couple les phases et mesure la synchronisation.
+pub fn couple_oscillators(
+    mut oscillators: Query<(Entity, &mut Oscillator)>,
+    mut removed: RemovedComponents<Oscillator>,
+    config: Res<CouplingConfig>,
+    seed: Option<Res<EngineSeed>>,
+    time: Res<Time>,
+    mut network: ResMut<CouplingNetwork>,
+    mut stats: ResMut<CouplingStats>,
+) {
+    let removed = removed.read().count() > 0;
+    let seed_changed = seed.as_ref().is_some_and(|s| s.is_changed());
+    if removed
+        || seed_changed
+        || network.len() != oscillators.iter().len()
+        || network.topology() != config.topology
+    {
+        // Ordre stable des nœuds pour que le tirage soit reproductible.
+        let mut entities: Vec<Entity> = oscillators.iter().map(|(entity, _)| entity).collect();
+        entities.sort();
+        let mut rng = seed
+            .as_deref()
+            .copied()
+            .unwrap_or_default()
+            .rng("dynamics::coupling");
+        *network = CouplingNetwork::build(entities, config.topology, &mut rng);
+        debug!(
+            target: "dynamics",
+            oscillators = network.len(),
+            edges = network.edge_count(),
+            "réseau de couplage reconstruit"
+        );
+    }
+
+    let mut phases: Vec<f32> = network
+        .graph()
+        .node_weights()
+        .map(|&entity| oscillators.get(entity).map_or(0.0, |(_, osc)| osc.phase))
+        .collect();
+
+    let h = time.delta_secs();
+    if config.enabled && config.strength != 0.0 && h > 0.0 {
+        let velocities = network.phase_velocities(&phases, config.strength, config.parallel);
+        for ((&entity, phase), velocity) in network
+            .graph()
+            .node_weights()
+            .zip(phases.iter_mut())
+            .zip(velocities)
+        {
+            if let Ok((_, mut osc)) = oscillators.get_mut(entity) {
+                osc.phase += h * velocity;
+                *phase = osc.phase;
+            }
+        }
+    }
+
+    let (order, mean_phase) = order_parameter(phases);
+    *stats = CouplingStats {
+        oscillators: network.len(),
+        edges: network.edge_count(),
+        mean_degree: network.mean_degree(),
+        order,
+        mean_phase,
+    };
+}
+
+/// Enregistre le couplage des oscillateurs.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<CouplingTopology>()
+        .register_type::<CouplingConfig>()
+        .register_type::<CouplingStats>()
+        .init_resource::<CouplingConfig>()
+        .init_resource::<CouplingStats>()
+        .init_resource::<CouplingNetwork>()
+        .add_systems(
+            FixedUpdate,
+            couple_oscillators.in_set(DynamicsSet::Integrate),
+        );
+}
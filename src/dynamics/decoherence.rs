@@ -0,0 +1,472 @@
+//! Systèmes quantiques ouverts : matrice densité et équation de Lindblad.
+//!
+//! Un [`DensityRegister`] porte l’état mixte `ρ` d’un registre couplé à un environnement.
+//! À chaque pas fixe, `ρ` suit l’équation maîtresse de Lindblad
+//!
+//! `dρ/dt = -i [H, ρ] + Σₖ (Lₖ ρ Lₖ† − ½ {Lₖ† Lₖ, ρ})`
+//!
+//! intégrée par Runge-Kutta 4 avec des sous-pas adaptés aux taux en jeu. `H` est le
+//! [`QuantumHamiltonian`] de l’entité, s’il existe ; les opérateurs de saut `Lₖ` viennent
+//! du composant [`Decoherence`], appliqué à chaque sous-système :
+//! - déphasage `L = √(2γφ) n̂` : les cohérences `|j⟩⟨k|` décroissent au taux
+//!   `γφ (j − k)²` (`1/T₂` pur pour un qubit) ;
+//! - amortissement d’amplitude `L = √γ₁ a` : relaxation vers le niveau fondamental
+//!   (`1/T₁` pour un qubit).
+//!
+//! Avec [`DecoherenceConfig::entropy_driven`], les taux sont multipliés par
+//! `1 + entropy_gain · S`, borné par `max_rate_scale`, où `S` est l’entropie globale de
+//! la [`FeedbackLoop`](crate::function::FeedbackLoop).
+//!
+//! La pureté `Tr ρ²` et l’entropie de von Neumann `−Tr ρ log₂ ρ` (en bits) de chaque
+//! registre sont publiées dans [`QuantumEntropy`], totalisées dans [`DecoherenceStats`] et
+//! enregistrées (échantillonnées) dans la mémoire sous la clé `entropy`.
+//!
+//! Une entité portant un [`QuantumRegister`] et un [`Decoherence`] est convertie : son
+//! état pur devient la matrice densité `|ψ⟩⟨ψ|` d’un [`DensityRegister`].
+
+use bevy::prelude::*;
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use rand::Rng;
+use serde_json::json;
+use tracing::debug;
+
+use super::quantum::{
+    draw_outcome, embed, evolve_quantum, record_expectations, Gate, Layout, QuantumConfig,
+    QuantumError, QuantumHamiltonian, QuantumRegister, QuantumState, C64,
+};
+use super::DynamicsSet;
+use crate::core::MemoryField;
+use crate::function::FeedbackLoop;
+
+/// Pas maximal `h · (‖H‖ + Σ γ)` d’un sous-pas d’intégration.
+const MAX_STEP: f64 = 0.05;
+
+/// Matrice densité d’un système composite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityMatrix {
+    layout: Layout,
+    rho: DMatrix<C64>,
+}
+
+impl Default for DensityMatrix {
+    /// Un qubit dans l’état `|0⟩⟨0|`.
+    fn default() -> Self {
+        Self::from_state(&QuantumState::default())
+    }
+}
+
+impl DensityMatrix {
+    /// État pur `|ψ⟩⟨ψ|`.
+    pub fn from_state(state: &QuantumState) -> Self {
+        let psi = state.amplitudes();
+        Self {
+            layout: state.layout().clone(),
+            rho: psi * psi.adjoint(),
+        }
+    }
+
+    /// État maximalement mixte `I / d`.
+    pub fn maximally_mixed(dims: &[usize]) -> Result<Self, QuantumError> {
+        let layout = Layout::new(dims)?;
+        let n = layout.dimension();
+        Ok(Self {
+            layout,
+            rho: DMatrix::identity(n, n).unscale(n as f64),
+        })
+    }
+
+    /// Matrice densité `rho` (hermitienne), ramenée à une trace unité.
+    pub fn from_matrix(dims: &[usize], rho: DMatrix<C64>) -> Result<Self, QuantumError> {
+        let layout = Layout::new(dims)?;
+        let n = layout.dimension();
+        if rho.nrows() != n || rho.ncols() != n {
+            return Err(QuantumError::DimensionMismatch {
+                expected: n,
+                found: rho.nrows(),
+            });
+        }
+        if (&rho - rho.adjoint()).norm() > 1e-9 * n as f64 {
+            return Err(QuantumError::NotHermitian);
+        }
+        let trace = rho.trace().re;
+        if trace <= f64::EPSILON {
+            return Err(QuantumError::ZeroNorm);
+        }
+        Ok(Self {
+            layout,
+            rho: rho.unscale(trace),
+        })
+    }
+
+    /// Dimensions des sous-systèmes.
+    pub fn dims(&self) -> &[usize] {
+        &self.layout.dims
+    }
+
+    /// Nombre de sous-systèmes.
+    pub fn subsystems(&self) -> usize {
+        self.layout.dims.len()
+    }
+
+    /// Dimension de l’espace de Hilbert.
+    pub fn dimension(&self) -> usize {
+        self.rho.nrows()
+    }
+
+    /// Matrice `ρ` en base canonique.
+    pub fn matrix(&self) -> &DMatrix<C64> {
+        &self.rho
+    }
+
+    pub(crate) fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Trace (1 aux erreurs d’arrondi près).
+    pub fn trace(&self) -> f64 {
+        self.rho.trace().re
+    }
+
+    /// Populations des états de base.
+    pub fn populations(&self) -> Vec<f64> {
+        self.rho.diagonal().iter().map(|p| p.re).collect()
+    }
+
+    /// Pureté `Tr ρ²` : 1 pour un état pur, `1/d` pour l’état maximalement mixte.
+    pub fn purity(&self) -> f64 {
+        // Tr ρ² = Σᵢⱼ |ρᵢⱼ|² pour ρ hermitienne.
+        self.rho.norm_squared()
+    }
+
+    /// Valeurs propres de `ρ` (probabilités de sa décomposition spectrale).
+    pub fn eigenvalues(&self) -> DVector<f64> {
+        SymmetricEigen::new(self.rho.clone())
+            .eigenvalues
+            .map(|p| p.max(0.0))
+    }
+
+    /// Entropie de von Neumann `−Tr ρ log₂ ρ`, en bits.
+    pub fn von_neumann_entropy(&self) -> f64 {
+        shannon_entropy(self.eigenvalues().iter().copied())
+    }
+
+    /// Valeur moyenne `Tr(ρ O)` de l’opérateur `op` agissant sur `targets`.
+    pub fn expectation(&self, op: &DMatrix<C64>, targets: &[usize]) -> Result<f64, QuantumError> {
+        let full = embed(op, targets, self.dims())?;
+        Ok((&self.rho * full).trace().re)
+    }
+
+    /// Applique la porte `gate` aux sous-systèmes `targets` : `ρ → U ρ U†`.
+    pub fn apply(&mut self, gate: &Gate, targets: &[usize]) -> Result<(), QuantumError> {
+        let u = embed(gate.matrix(), targets, self.dims())?;
+        self.rho = &u * &self.rho * u.adjoint();
+        Ok(())
+    }
+
+    /// Mesure projective des sous-systèmes `targets` : renvoie les niveaux obtenus (dans
+    /// l’ordre de `targets`) et leur probabilité, et réduit l’état `ρ → P ρ P / p`.
+    pub fn measure(
+        &mut self,
+        targets: &[usize],
+        rng: &mut impl Rng,
+    ) -> Result<(Vec<usize>, f64), QuantumError> {
+        let mut marginal = vec![0.0; self.layout.local_dimension(targets)?];
+        for (index, p) in self.populations().into_iter().enumerate() {
+            marginal[self.layout.local_index(index, targets)] += p.max(0.0);
+        }
+        let (outcome, probability) = draw_outcome(&marginal, rng)?;
+        let kept: Vec<bool> = (0..self.dimension())
+            .map(|index| self.layout.local_index(index, targets) == outcome)
+            .collect();
+        for (j, &column) in kept.iter().enumerate() {
+            for (i, &row) in kept.iter().enumerate() {
+                if !(row && column) {
+                    self.rho[(i, j)] = C64::from(0.0);
+                }
+            }
+        }
+        let trace = self.trace();
+        if trace <= f64::EPSILON {
+            return Err(QuantumError::ZeroNorm);
+        }
+        self.rho.unscale_mut(trace);
+        Ok((self.layout.levels(outcome, targets), probability))
+    }
+
+    /// Fait évoluer `ρ` pendant `t` sous le hamiltonien `h` et les sauts `jumps`.
+    pub fn evolve(&mut self, h: Option<&DMatrix<C64>>, jumps: &[DMatrix<C64>], t: f64) {
+        let lindbladian = Lindbladian::new(h, jumps);
+        let steps = (t * lindbladian.rate / MAX_STEP).ceil().max(1.0) as usize;
+        let dt = t / steps as f64;
+        for _ in 0..steps {
+            let k1 = lindbladian.apply(&self.rho);
+            let k2 = lindbladian.apply(&(&self.rho + &k1 * C64::from(dt / 2.0)));
+            let k3 = lindbladian.apply(&(&self.rho + &k2 * C64::from(dt / 2.0)));
+            let k4 = lindbladian.apply(&(&self.rho + &k3 * C64::from(dt)));
+            self.rho += (k1 + k2 * C64::from(2.0) + k3 * C64::from(2.0) + k4) * C64::from(dt / 6.0);
+        }
+        // Élimine la dérive numérique : ρ reste hermitienne et de trace unité.
+        self.rho = (&self.rho + self.rho.adjoint()) * C64::from(0.5);
+        let trace = self.trace();
+        if trace > f64::EPSILON {
+            self.rho.unscale_mut(trace);
+        }
+    }
+}
+
+/// Entropie de Shannon (bits) de la distribution `probabilities`.
+pub(crate) fn shannon_entropy(probabilities: impl IntoIterator<Item = f64>) -> f64 {
+    probabilities
+        .into_iter()
+        .filter(|&p| p > 1e-15)
+        .map(|p| p * (1.0 / p).log2())
+        .sum()
+}
+
+/// Générateur de Lindblad, avec les produits `L† L` précalculés.
+struct Lindbladian<'a> {
+    h: Option<&'a DMatrix<C64>>,
+    jumps: Vec<(&'a DMatrix<C64>, DMatrix<C64>, DMatrix<C64>)>,
+    /// Borne du taux d’évolution, pour le choix des sous-pas.
+    rate: f64,
+}
+
+impl<'a> Lindbladian<'a> {
+    fn new(h: Option<&'a DMatrix<C64>>, jumps: &'a [DMatrix<C64>]) -> Self {
+        let mut rate = h.map_or(0.0, |h| h.norm());
+        let jumps = jumps
+            .iter()
+            .map(|l| {
+                let decay = l.adjoint() * l;
+                rate += decay.norm();
+                (l, l.adjoint(), decay)
+            })
+            .collect();
+        Self { h, jumps, rate }
+    }
+
+    fn apply(&self, rho: &DMatrix<C64>) -> DMatrix<C64> {
+        let mut d = match self.h {
+            Some(h) => (h * rho - rho * h) * C64::new(0.0, -1.0),
+            None => DMatrix::zeros(rho.nrows(), rho.ncols()),
+        };
+        for (l, l_dagger, decay) in &self.jumps {
+            d += *l * rho * l_dagger - (decay * rho + rho * decay) * C64::from(0.5);
+        }
+        d
+    }
+}
+
+/// Composant : registre quantique dans un état mixte.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct DensityRegister {
+    #[reflect(ignore)]
+    pub state: DensityMatrix,
+}
+
+impl DensityRegister {
+    pub fn new(state: DensityMatrix) -> Self {
+        Self { state }
+    }
+}
+
+/// Composant : couplage du registre à son environnement (taux en s⁻¹ de temps quantique).
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Decoherence {
+    /// Taux de déphasage pur `γφ` de chaque sous-système.
+    pub dephasing: f64,
+    /// Taux d’amortissement d’amplitude `γ₁` de chaque sous-système.
+    pub damping: f64,
+}
+
+impl Decoherence {
+    /// Opérateurs de saut sur tout l’espace, avec les taux multipliés par `scale`.
+    pub fn jump_operators(&self, dims: &[usize], scale: f64) -> Vec<DMatrix<C64>> {
+        let mut jumps = Vec::new();
+        for (k, &d) in dims.iter().enumerate() {
+            if self.dephasing > 0.0 {
+                let number = DMatrix::from_diagonal(&DVector::from_fn(d, |j, _| {
+                    C64::from((2.0 * self.dephasing * scale).sqrt() * j as f64)
+                }));
+                jumps.extend(embed(&number, &[k], dims).ok());
+            }
+            if self.damping > 0.0 {
+                let mut lowering = DMatrix::zeros(d, d);
+                for j in 1..d {
+                    lowering[(j - 1, j)] = C64::from((self.damping * scale * j as f64).sqrt());
+                }
+                jumps.extend(embed(&lowering, &[k], dims).ok());
+            }
+        }
+        jumps
+    }
+}
+
+/// Composant : pureté et entropie du registre au dernier pas.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct QuantumEntropy {
+    pub purity: f64,
+    /// Entropie de von Neumann (bits).
+    pub von_neumann: f64,
+}
+
+/// Ressource : paramètres de la décohérence.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct DecoherenceConfig {
+    /// Module les taux par l’entropie globale de la boucle de rétroaction.
+    pub entropy_driven: bool,
+    /// Gain appliqué à l’entropie globale dans ce mode.
+    pub entropy_gain: f64,
+    /// Borne du facteur `1 + entropy_gain · S` : le nombre de sous-pas d’intégration
+    /// croît avec les taux.
+    pub max_rate_scale: f64,
+}
+
+impl Default for DecoherenceConfig {
+    fn default() -> Self {
+        Self {
+            entropy_driven: false,
+            entropy_gain: 1.0,
+            max_rate_scale: 10.0,
+        }
+    }
+}
+
+/// Ressource : bilan des registres mixtes au dernier pas.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DecoherenceStats {
+    pub registers: usize,
+    pub mean_purity: f64,
+    /// Somme des entropies de von Neumann (bits).
+    pub entropy: f64,
+    /// Facteur appliqué aux taux de décohérence.
+    pub rate_scale: f64,
+}
+
+/// Registres purs soumis à la décohérence, pas encore convertis.
+type ClosingQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static QuantumRegister),
+    (With<Decoherence>, Without<DensityRegister>),
+>;
+
+/// Registres mixtes et leur environnement.
+type OpenQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut DensityRegister,
+        Option<&'static Decoherence>,
+        Option<&'static QuantumHamiltonian>,
+        Option<&'static mut QuantumEntropy>,
+    ),
+>;
+
+/// Système : convertit en registres mixtes les registres purs soumis à la décohérence.
+pub fn open_registers(mut commands: Commands, registers: ClosingQuery) {
+    for (entity, register) in registers.iter() {
+        commands
+            .entity(entity)
+            .insert(DensityRegister::new(DensityMatrix::from_state(
+                &register.state,
+            )))
+            .remove::<QuantumRegister>();
+    }
+}
+
+/// Système : fait évoluer les registres mixtes et mesure leur pureté et leur entropie.
+#[allow(clippy::too_many_arguments)]
+pub fn decohere(
+    mut commands: Commands,
+    mut registers: OpenQuery,
+    config: Res<DecoherenceConfig>,
+    quantum: Res<QuantumConfig>,
+    feedback: Option<Res<FeedbackLoop>>,
+    time: Res<Time>,
+    mut stats: ResMut<DecoherenceStats>,
+    mut memory: ResMut<MemoryField>,
+) {
+    let t = time.delta_secs_f64() * quantum.time_scale;
+    let rate_scale = match (&feedback, config.entropy_driven) {
+        (Some(feedback), true) => (1.0
+            + config.entropy_gain * f64::from(feedback.global_entropy.max(0.0)))
+        .clamp(1.0, config.max_rate_scale.max(1.0)),
+        _ => 1.0,
+    };
+
+    let mut total = DecoherenceStats {
+        rate_scale,
+        ..default()
+    };
+    for (entity, mut register, decoherence, hamiltonian, entropy) in registers.iter_mut() {
+        if t > 0.0 {
+            let jumps = decoherence.map_or_else(Vec::new, |d| {
+                d.jump_operators(register.state.dims(), rate_scale)
+            });
+            let h = hamiltonian
+                .and_then(|h| h.hamiltonian.as_ref())
+                .map(|h| h.matrix())
+                .filter(|h| h.nrows() == register.state.dimension());
+            register.state.evolve(h, &jumps, t);
+        }
+
+        let measured = QuantumEntropy {
+            purity: register.state.purity(),
+            von_neumann: register.state.von_neumann_entropy(),
+        };
+        total.registers += 1;
+        total.mean_purity += measured.purity;
+        total.entropy += measured.von_neumann;
+        match entropy {
+            Some(mut entropy) => *entropy = measured,
+            None => {
+                commands.entity(entity).insert(measured);
+            }
+        }
+    }
+
+    if total.registers > 0 {
+        total.mean_purity /= total.registers as f64;
+        memory.record_sampled(json!({
+            "module": "dynamics::decoherence",
+            "registers": total.registers,
+            "purity": total.mean_purity,
+            "entropy": total.entropy,
+        }));
+        debug!(
+            target: "dynamics",
+            registers = total.registers,
+            purity = total.mean_purity,
+            entropy = total.entropy,
+            "décohérence"
+        );
+    }
+    *stats = total;
+}
+
+/// Enregistre la décohérence des systèmes ouverts.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<DensityRegister>()
+        .register_type::<Decoherence>()
+        .register_type::<QuantumEntropy>()
+        .register_type::<DecoherenceConfig>()
+        .register_type::<DecoherenceStats>()
+        .init_resource::<DecoherenceConfig>()
+        .init_resource::<DecoherenceStats>()
+        .add_systems(
+            FixedUpdate,
+            (
+                open_registers.before(evolve_quantum),
+                decohere.before(record_expectations),
+            )
+                .chain()
+                .in_set(DynamicsSet::Integrate),
+        );
+}
@@ -0,0 +1,485 @@
+//! Émetteurs de particules à durée de vie limitée.
+//!
+//! Un [`Emitter`] crée à chaque pas fixe des particules — corps dynamiques ordinaires
+//! (`Velocity`, `Mass`) portant un composant [`Particle`] — selon un débit continu et des
+//! salves ponctuelles. Chaque particule part d’une sphère de rayon `radius` autour de
+//! l’émetteur, dans un cône d’ouverture `spread` autour de `direction`, avec une vitesse
+//! tirée de la [`SpeedDistribution`] (plus celle de l’émetteur s’il est mobile). Les tirages
+//! viennent du flux `dynamics::emitter` de l’[`EngineSeed`].
+//!
+//! Au fil de sa vie, la masse d’une particule décroît exponentiellement au taux `decay` ;
+//! à l’expiration de sa durée de vie, la particule quitte la simulation et rejoint le
+//! [`ParticlePool`] : tous ses composants lui sont retirés et l’entité est réutilisée
+//! par la prochaine émission plutôt que d’en créer une nouvelle.
+//!
+//! Le nombre de particules, les émissions, les expirations et l’énergie cinétique des
+//! particules sont publiés dans [`ParticleStats`] et enregistrés dans la mémoire.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde_json::json;
+use tracing::debug;
+
+use super::thermostat::gaussian;
+use super::{DynamicsSet, Mass, Velocity};
+use crate::core::{EngineSeed, MemoryField};
+
+/// Loi de la vitesse initiale des particules.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum SpeedDistribution {
+    Constant(f32),
+    /// Uniforme sur `[min, max]`.
+    Uniform {
+        min: f32,
+        max: f32,
+    },
+    /// Gaussienne, tronquée aux vitesses positives.
+    Normal {
+        mean: f32,
+        std_dev: f32,
+    },
+}
+
+impl Default for SpeedDistribution {
+    fn default() -> Self {
+        Self::Constant(1.0)
+    }
+}
+
+impl SpeedDistribution {
+    /// Tire une vitesse.
+    pub fn sample(&self, rng: &mut impl Rng) -> f32 {
+        match *self {
+            Self::Constant(speed) => speed,
+            Self::Uniform { min, max } if max > min => rng.random_range(min..max),
+            Self::Uniform { min, .. } => min,
+            Self::Normal { mean, std_dev } => (mean + std_dev * gaussian(rng)).max(0.0),
+        }
+    }
+}
+
+/// Composant : source de particules.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Emitter {
+    pub enabled: bool,
+    /// Débit continu (particules par seconde).
+    pub rate: f32,
+    /// Salve émise au prochain pas, puis remise à zéro.
+    pub burst: u32,
+    /// Direction moyenne d’émission.
+    pub direction: Vec3,
+    /// Demi-angle du cône d’émission (radians ; `π` : isotrope).
+    pub spread: f32,
+    pub speed: SpeedDistribution,
+    /// Rayon de la sphère de départ autour de l’émetteur.
+    pub radius: f32,
+    /// Masse initiale des particules.
+    pub mass: f32,
+    /// Durée de vie moyenne (s).
+    pub lifetime: f32,
+    /// Variation relative maximale de la durée de vie (`0.2` : ±20 %).
+    pub lifetime_jitter: f32,
+    /// Taux de décroissance exponentielle de la masse (s⁻¹).
+    pub decay: f32,
+    /// Nombre maximal de particules vivantes issues de cet émetteur.
+    pub max_particles: usize,
+    /// Fraction de particule en attente d’émission.
+    accumulated: f32,
+}
+
+impl Default for Emitter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rate: 10.0,
+            burst: 0,
+            direction: Vec3::Y,
+            spread: 0.25,
+            speed: SpeedDistribution::default(),
+            radius: 0.0,
+            mass: 1.0,
+            lifetime: 2.0,
+            lifetime_jitter: 0.0,
+            decay: 0.0,
+            max_particles: 1024,
+            accumulated: 0.0,
+        }
+    }
+}
+
+impl Emitter {
+    /// Émetteur continu de débit `rate`.
+    pub fn new(rate: f32) -> Self {
+        Self { rate, ..default() }
+    }
+
+    /// Émetteur d’une salve unique de `count` particules.
+    pub fn burst(count: u32) -> Self {
+        Self {
+            rate: 0.0,
+            burst: count,
+            ..default()
+        }
+    }
+
+    pub fn with_direction(mut self, direction: Vec3, spread: f32) -> Self {
+        self.direction = direction;
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: SpeedDistribution) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    pub fn with_lifetime(mut self, lifetime: f32, jitter: f32) -> Self {
+        self.lifetime = lifetime;
+        self.lifetime_jitter = jitter;
+        self
+    }
+
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    pub fn with_max_particles(mut self, max_particles: usize) -> Self {
+        self.max_particles = max_particles;
+        self
+    }
+
+    /// Direction tirée uniformément dans le cône d’émission.
+    fn sample_direction(&self, rng: &mut impl Rng) -> Vec3 {
+        let axis = self.direction.try_normalize().unwrap_or(Vec3::Y);
+        let cos_min = self.spread.clamp(0.0, std::f32::consts::PI).cos();
+        let cos_theta = 1.0 - rng.random::<f32>() * (1.0 - cos_min);
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let phi = rng.random::<f32>() * std::f32::consts::TAU;
+        let local = Vec3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta);
+        Quat::from_rotation_arc(Vec3::Z, axis) * local
+    }
+
+    /// Décalage tiré uniformément dans la sphère de départ.
+    fn sample_offset(&self, rng: &mut impl Rng) -> Vec3 {
+        if self.radius <= 0.0 {
+            return Vec3::ZERO;
+        }
+        loop {
+            let p = Vec3::new(
+                rng.random::<f32>(),
+                rng.random::<f32>(),
+                rng.random::<f32>(),
+            ) * 2.0
+                - Vec3::ONE;
+            if p.length_squared() <= 1.0 {
+                return p * self.radius;
+            }
+        }
+    }
+}
+
+/// Composant : particule émise, vivante.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Particle {
+    pub emitter: Entity,
+    /// Âge (s).
+    pub age: f32,
+    /// Durée de vie (s).
+    pub lifetime: f32,
+    /// Taux de décroissance de la masse (s⁻¹).
+    pub decay: f32,
+}
+
+impl Particle {
+    /// Fraction de vie restante, dans `[0, 1]`.
+    pub fn remaining(&self) -> f32 {
+        if self.lifetime.is_finite() && self.lifetime > 0.0 {
+            (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Composant marqueur : particule expirée, en attente de réutilisation.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct PooledParticle;
+
+/// Ressource : paramètres du système de particules.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ParticleConfig {
+    /// Nombre maximal d’entités gardées en réserve ; les suivantes sont détruites.
+    pub pool_capacity: usize,
+    /// Enregistre les compteurs dans la mémoire (échantillonnés, voir
+    /// [`MemoryField::record_sampled`]).
+    pub record_snapshots: bool,
+}
+
+impl Default for ParticleConfig {
+    fn default() -> Self {
+        Self {
+            pool_capacity: 4096,
+            record_snapshots: true,
+        }
+    }
+}
+
+/// Ressource : entités de particules expirées, réutilisables.
+#[derive(Resource, Debug, Default)]
+pub struct ParticlePool {
+    free: Vec<Entity>,
+}
+
+impl ParticlePool {
+    /// Entités disponibles.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Retire une entité encore en réserve (celles détruites entre-temps sont oubliées).
+    fn take(&mut self, pooled: &Query<(), With<PooledParticle>>) -> Option<Entity> {
+        while let Some(entity) = self.free.pop() {
+            if pooled.contains(entity) {
+                return Some(entity);
+            }
+        }
+        None
+    }
+
+    /// `true` si la réserve est vide.
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+}
+
+/// Ressource : bilan du dernier pas.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ParticleStats {
+    /// Particules vivantes.
+    pub alive: usize,
+    /// Entités en réserve.
+    pub pooled: usize,
+    /// Particules émises pendant le pas.
+    pub emitted: usize,
+    /// Particules expirées pendant le pas.
+    pub expired: usize,
+    /// Émissions servies par la réserve pendant le pas.
+    pub reused: usize,
+    /// Énergie cinétique totale des particules vivantes.
+    pub kinetic_energy: f32,
+    /// Masse totale des particules vivantes.
+    pub mass: f32,
+}
+
+/// Ressource : générateur des tirages d’émission.
+#[derive(Resource)]
+pub struct ParticleRng(pub StdRng);
+
+/// Système (Startup) : ensemence les émetteurs à partir de la graine moteur.
+pub fn seed_emitters(mut commands: Commands, seed: Option<Res<EngineSeed>>) {
+    let seed = seed.as_deref().copied().unwrap_or_default();
+    commands.insert_resource(ParticleRng(seed.rng("dynamics::emitter")));
+}
+
+/// Système : vieillit les particules, fait décroître leur masse et met en réserve les
+/// particules expirées.
+pub fn age_particles(
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut Particle, &mut Mass)>,
+    config: Res<ParticleConfig>,
+    mut pool: ResMut<ParticlePool>,
+    mut stats: ResMut<ParticleStats>,
+    time: Res<Time>,
+) {
+    let h = time.delta_secs();
+    stats.expired = 0;
+    for (entity, mut particle, mut mass) in particles.iter_mut() {
+        particle.age += h;
+        if particle.decay > 0.0 {
+            mass.value *= (-particle.decay * h).exp();
+        }
+        if particle.age < particle.lifetime {
+            continue;
+        }
+
+        stats.expired += 1;
+        if pool.free.len() < config.pool_capacity {
+            // Tous les composants sont retirés, y compris ceux ajoutés par la simulation
+            // (accumulateur, énergie, potentiel, collisionneur…) : l’entité réutilisée
+            // repart vierge.
+            commands
+                .entity(entity)
+                .retain::<()>()
+                .insert(PooledParticle);
+            pool.free.push(entity);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Système : émet les particules de chaque émetteur actif.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_particles(
+    mut commands: Commands,
+    mut emitters: Query<(Entity, &mut Emitter, &Transform, Option<&Velocity>)>,
+    particles: Query<&Particle>,
+    pooled: Query<(), With<PooledParticle>>,
+    rng: Option<ResMut<ParticleRng>>,
+    mut pool: ResMut<ParticlePool>,
+    mut stats: ResMut<ParticleStats>,
+    time: Res<Time>,
+) {
+    stats.emitted = 0;
+    stats.reused = 0;
+    let Some(mut rng) = rng else {
+        return;
+    };
+    let h = time.delta_secs();
+
+    let mut alive: HashMap<Entity, usize> = HashMap::new();
+    for particle in particles.iter() {
+        *alive.entry(particle.emitter).or_default() += 1;
+    }
+
+    for (entity, mut emitter, transform, velocity) in emitters.iter_mut() {
+        if !emitter.enabled {
+            continue;
+        }
+        emitter.accumulated += emitter.rate.max(0.0) * h;
+        let continuous = emitter.accumulated.floor();
+        emitter.accumulated -= continuous;
+        let requested = continuous as usize + std::mem::take(&mut emitter.burst) as usize;
+        let room = emitter
+            .max_particles
+            .saturating_sub(alive.get(&entity).copied().unwrap_or(0));
+
+        let base = velocity.map_or(Vec3::ZERO, |v| v.linear);
+        for _ in 0..requested.min(room) {
+            let rng = &mut rng.0;
+            let jitter = if emitter.lifetime_jitter > 0.0 {
+                1.0 + emitter.lifetime_jitter * (2.0 * rng.random::<f32>() - 1.0)
+            } else {
+                1.0
+            };
+            let particle = (
+                Transform::from_translation(transform.translation + emitter.sample_offset(rng)),
+                Velocity {
+                    linear: base + emitter.speed.sample(rng) * emitter.sample_direction(rng),
+                    angular: Vec3::ZERO,
+                },
+                Mass {
+                    value: emitter.mass,
+                },
+                Particle {
+                    emitter: entity,
+                    age: 0.0,
+                    lifetime: (emitter.lifetime * jitter).max(0.0),
+                    decay: emitter.decay,
+                },
+            );
+            match pool.take(&pooled) {
+                Some(recycled) => {
+                    commands
+                        .entity(recycled)
+                        .remove::<PooledParticle>()
+                        .insert(particle);
+                    stats.reused += 1;
+                }
+                None => {
+                    commands.spawn(particle);
+                }
+            }
+            stats.emitted += 1;
+        }
+    }
+}
+
+/// Système : mesure les particules vivantes et enregistre le bilan dans la mémoire.
+pub fn record_particles(
+    particles: Query<(&Velocity, &Mass), With<Particle>>,
+    emitters: Query<(), With<Emitter>>,
+    config: Res<ParticleConfig>,
+    pool: Res<ParticlePool>,
+    mut stats: ResMut<ParticleStats>,
+    mut memory: ResMut<MemoryField>,
+) {
+    let (alive, kinetic_energy, mass) =
+        particles
+            .iter()
+            .fold((0, 0.0, 0.0), |(n, energy, total), (velocity, mass)| {
+                (
+                    n + 1,
+                    energy + 0.5 * mass.value * velocity.linear.length_squared(),
+                    total + mass.value,
+                )
+            });
+    stats.alive = alive;
+    stats.kinetic_energy = kinetic_energy;
+    stats.mass = mass;
+    stats.pooled = pool.len();
+
+    if emitters.is_empty() && alive == 0 && stats.expired == 0 {
+        return;
+    }
+    if config.record_snapshots {
+        memory.record_sampled(json!({
+            "module": "dynamics::particles",
+            "alive": stats.alive,
+            "emitted": stats.emitted,
+            "expired": stats.expired,
+            "pooled": stats.pooled,
+            "energy": stats.kinetic_energy,
+        }));
+    }
+    debug!(
+        target: "dynamics",
+        alive = stats.alive,
+        emitted = stats.emitted,
+        expired = stats.expired,
+        pooled = stats.pooled,
+        "particules"
+    );
+}
+
+/// Enregistre les émetteurs de particules.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<SpeedDistribution>()
+        .register_type::<Emitter>()
+        .register_type::<Particle>()
+        .register_type::<PooledParticle>()
+        .register_type::<ParticleConfig>()
+        .register_type::<ParticleStats>()
+        .init_resource::<ParticleConfig>()
+        .init_resource::<ParticlePool>()
+        .init_resource::<ParticleStats>()
+        .add_systems(Startup, seed_emitters)
+        .add_systems(
+            FixedUpdate,
+            (
+                (age_particles, emit_particles)
+                    .chain()
+                    .before(DynamicsSet::Forces),
+                record_particles.after(DynamicsSet::Boundaries),
+            ),
+        );
+}
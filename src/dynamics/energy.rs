@@ -0,0 +1,223 @@
+//! Bilan énergétique de la dynamique.
+//!
+//! Au début de chaque pas fixe, une fois les forces évaluées, [`measure_energy`] mesure
+//! pour chaque corps massif :
+//! - l’énergie cinétique de translation `½ m v²`,
+//! - l’énergie cinétique de rotation `½ ωᵀ I ω`,
+//! - l’énergie potentielle : potentiels du substrat, pesanteur (`-m g·x`) et ressorts
+//!   (`½ k (L - L₀)²`, partagée entre les deux corps d’un ressort entre entités).
+//!
+//! Les totaux, augmentés des énergies d’interaction à N corps, sont exposés par
+//! [`EnergyLedger`] : c’est la source des énergies enregistrées dans la mémoire par les
+//! couches supérieures.
+
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::forces::{Gravity, Spring, SpringAnchor};
+use super::nbody::{compute_nbody_forces, NBodyStats};
+use super::{DynamicsSet, Inertia, Mass, Velocity};
+use crate::substrate::potential::PotentialForce;
+use crate::substrate::topology::DomainTopology;
+
+/// Composant : énergies d’un corps mesurées au début du pas courant.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct BodyEnergy {
+    pub kinetic: f32,
+    pub rotational: f32,
+    pub potential: f32,
+}
+
+impl BodyEnergy {
+    /// Énergie mécanique totale du corps.
+    pub fn total(&self) -> f32 {
+        self.kinetic + self.rotational + self.potential
+    }
+}
+
+/// Ressource : énergies totales de la dynamique, mesurées à chaque pas fixe.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct EnergyLedger {
+    /// Corps massifs mesurés.
+    pub bodies: usize,
+    pub kinetic: f32,
+    pub rotational: f32,
+    /// Énergie potentielle des corps (substrat, pesanteur, ressorts).
+    pub potential: f32,
+    /// Énergie d’interaction à N corps (gravitation mutuelle et électrostatique).
+    pub interaction: f32,
+    /// Énergie mécanique totale.
+    pub total: f32,
+    /// Énergie perdue depuis la mesure précédente (traînée, chocs), nulle si elle augmente.
+    ///
+    /// Seuls les corps présents aux deux mesures comptent : l’énergie d’un corps retiré
+    /// (particule expirée, entité supprimée) n’est pas dissipée.
+    pub dissipated: f32,
+    /// Nombre de mesures effectuées.
+    pub samples: u64,
+    /// Énergie dissipée cumulée depuis le dernier [`EnergyLedger::take_dissipated`].
+    unconsumed: f32,
+}
+
+impl EnergyLedger {
+    /// Énergie dissipée pendant tous les pas fixes écoulés depuis l’appel précédent.
+    ///
+    /// Les consommateurs qui tournent dans `Update` ne voient `dissipated` que pour le
+    /// dernier pas fixe d’une frame ; ce cumul n’en perd aucun.
+    pub fn take_dissipated(&mut self) -> f32 {
+        std::mem::take(&mut self.unconsumed)
+    }
+
+    /// Énergie dissipée cumulée, pas encore consommée.
+    pub fn unconsumed_dissipation(&self) -> f32 {
+        self.unconsumed
+    }
+}
+
+/// Énergie d’un ressort dont l’autre extrémité est en `anchor`.
+fn spring_energy(spring: &Spring, position: Vec3, anchor: Vec3, topology: &DomainTopology) -> f32 {
+    let stretch = topology.distance(anchor, position) - spring.rest_length;
+    0.5 * spring.stiffness * stretch * stretch
+}
+
+/// Corps mesurés.
+type EnergyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Mass,
+        Option<&'static Velocity>,
+        Option<&'static Inertia>,
+        Option<&'static PotentialForce>,
+        Option<&'static Spring>,
+        Option<&'static mut BodyEnergy>,
+    ),
+>;
+
+/// Système : mesure les énergies de chaque corps et met à jour l’[`EnergyLedger`].
+pub fn measure_energy(
+    mut commands: Commands,
+    mut bodies: EnergyQuery,
+    gravity: Option<Res<Gravity>>,
+    topology: Option<Res<DomainTopology>>,
+    nbody: Option<Res<NBodyStats>>,
+    anchors: Query<&Transform>,
+    mut ledger: ResMut<EnergyLedger>,
+) {
+    let gravity = gravity.map_or(Vec3::ZERO, |g| g.0);
+    let topology = topology.as_deref().cloned().unwrap_or_default();
+
+    // Ressorts entre entités : l’énergie est partagée entre les deux extrémités, ou
+    // revient entière au corps si l’ancre, sans masse, est immobile.
+    let mut shared = Vec::new();
+    for (entity, transform, _, _, _, _, spring, _) in bodies.iter() {
+        let Some(spring) = spring else {
+            continue;
+        };
+        let SpringAnchor::Entity(other) = spring.anchor else {
+            continue;
+        };
+        let Ok(anchor) = anchors.get(other) else {
+            continue;
+        };
+        let energy = spring_energy(spring, transform.translation, anchor.translation, &topology);
+        if bodies.contains(other) {
+            shared.push((entity, 0.5 * energy));
+            shared.push((other, 0.5 * energy));
+        } else {
+            shared.push((entity, energy));
+        }
+    }
+
+    let mut measured = EnergyLedger {
+        samples: ledger.samples + 1,
+        unconsumed: ledger.unconsumed,
+        ..default()
+    };
+    // Énergie, avant et maintenant, des corps déjà mesurés au pas précédent.
+    let (mut before, mut now, mut continuing) = (0.0, 0.0, 0);
+    for (entity, transform, mass, velocity, inertia, potential, spring, energy) in bodies.iter_mut()
+    {
+        let position = transform.translation;
+        let (linear, angular) =
+            velocity.map_or((Vec3::ZERO, Vec3::ZERO), |v| (v.linear, v.angular));
+
+        let mut potential_energy =
+            potential.map_or(0.0, |p| p.energy) - mass.value * gravity.dot(position);
+        if let Some(spring) = spring {
+            if let SpringAnchor::Point(anchor) = spring.anchor {
+                potential_energy += spring_energy(spring, position, anchor, &topology);
+            }
+        }
+        potential_energy += shared
+            .iter()
+            .filter(|(e, _)| *e == entity)
+            .map(|(_, half)| half)
+            .sum::<f32>();
+
+        let body = BodyEnergy {
+            kinetic: 0.5 * mass.value * linear.length_squared(),
+            rotational: inertia
+                .copied()
+                .unwrap_or_default()
+                .angular_kinetic_energy(transform.rotation, angular),
+            potential: potential_energy,
+        };
+        measured.bodies += 1;
+        measured.kinetic += body.kinetic;
+        measured.rotational += body.rotational;
+        measured.potential += body.potential;
+
+        match energy {
+            Some(mut energy) => {
+                before += energy.total();
+                now += body.total();
+                continuing += 1;
+                *energy = body;
+            }
+            None => {
+                commands.entity(entity).insert(body);
+            }
+        }
+    }
+
+    measured.interaction = nbody.map_or(0.0, |n| n.gravitational_energy + n.electrostatic_energy);
+    measured.total =
+        measured.kinetic + measured.rotational + measured.potential + measured.interaction;
+    // L’énergie d’interaction n’est comparable que si l’ensemble des corps est inchangé.
+    if continuing == ledger.bodies && continuing == measured.bodies {
+        before += ledger.interaction;
+        now += measured.interaction;
+    }
+    if ledger.samples > 0 {
+        measured.dissipated = (before - now).max(0.0);
+        measured.unconsumed += measured.dissipated;
+    }
+    *ledger = measured;
+
+    debug!(
+        target: "dynamics",
+        bodies = ledger.bodies,
+        kinetic = ledger.kinetic,
+        potential = ledger.potential,
+        total = ledger.total,
+        "bilan énergétique"
+    );
+}
+
+/// Enregistre le bilan énergétique.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<BodyEnergy>()
+        .register_type::<EnergyLedger>()
+        .init_resource::<EnergyLedger>()
+        .add_systems(
+            FixedUpdate,
+            measure_energy
+                .in_set(DynamicsSet::Forces)
+                .after(compute_nbody_forces),
+        );
+}
@@ -0,0 +1,378 @@
+//! Mesures d’intrication des registres quantiques composites.
+//!
+//! Les fonctions de ce module opèrent sur une [`DensityMatrix`] (ou un [`QuantumState`]) :
+//! - [`partial_trace`] / [`reduced_state`] : état réduit d’un groupe de sous-systèmes,
+//! - [`entanglement_entropy`] : entropie de von Neumann de l’état réduit (bits), qui mesure
+//!   l’intrication d’un état pur entre ce groupe et le reste,
+//! - [`mutual_information`] : `I(A:B) = S(A) + S(B) − S(AB)`, corrélations totales,
+//!   classiques et quantiques, entre deux groupes,
+//! - [`concurrence`] : concurrence de Wootters d’un état de deux qubits.
+//!
+//! Un registre peut être partagé entre plusieurs entités : chacune porte un
+//! [`QuantumSubsystem`] désignant l’entité du registre et l’indice de son sous-système.
+//! À chaque pas fixe, [`measure_entanglement`] publie les [`EntanglementMeasures`] de chaque
+//! registre et, sur les entités rattachées, leurs [`Correlations`] avec les autres ; la
+//! couche de réflexion perçoit ces corrélations.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use nalgebra::{DMatrix, SymmetricEigen};
+use tracing::debug;
+
+use super::decoherence::{decohere, DensityMatrix, DensityRegister};
+use super::quantum::{
+    record_expectations, Gate, Layout, QuantumError, QuantumRegister, QuantumState, C64,
+};
+use super::DynamicsSet;
+
+/// Information mutuelle (bits) au-delà de laquelle une paire est tenue pour corrélée.
+const CORRELATION_THRESHOLD: f64 = 1e-6;
+
+/// Partition d’un registre en sous-systèmes gardés et tracés.
+struct Split {
+    /// Décalages des états de base des sous-systèmes gardés.
+    kept: Vec<usize>,
+    /// Décalages des états de base des sous-systèmes tracés.
+    traced: Vec<usize>,
+    /// Dimensions des sous-systèmes gardés.
+    dims: Vec<usize>,
+}
+
+impl Split {
+    fn new(layout: &Layout, keep: &[usize]) -> Result<Self, QuantumError> {
+        layout.local_dimension(keep)?;
+        let traced: Vec<usize> = (0..layout.dims.len())
+            .filter(|k| !keep.contains(k))
+            .collect();
+        Ok(Self {
+            kept: layout.local_offsets(keep),
+            traced: layout.local_offsets(&traced),
+            dims: keep.iter().map(|&k| layout.dims[k]).collect(),
+        })
+    }
+}
+
+/// État réduit des sous-systèmes `keep` (dans cet ordre), les autres étant tracés.
+pub fn partial_trace(rho: &DensityMatrix, keep: &[usize]) -> Result<DensityMatrix, QuantumError> {
+    let layout = rho.layout();
+    let Split { kept, traced, dims } = Split::new(layout, keep)?;
+    let matrix = rho.matrix();
+    let reduced = DMatrix::from_fn(kept.len(), kept.len(), |i, j| {
+        traced
+            .iter()
+            .map(|t| matrix[(kept[i] + t, kept[j] + t)])
+            .sum::<C64>()
+    });
+    DensityMatrix::from_matrix(&dims, reduced)
+}
+
+/// État réduit des sous-systèmes `keep` d’un état pur.
+pub fn reduced_state(state: &QuantumState, keep: &[usize]) -> Result<DensityMatrix, QuantumError> {
+    let layout = state.layout();
+    let Split { kept, traced, dims } = Split::new(layout, keep)?;
+    let psi = state.amplitudes();
+    let reduced = DMatrix::from_fn(kept.len(), kept.len(), |i, j| {
+        traced
+            .iter()
+            .map(|t| psi[kept[i] + t] * psi[kept[j] + t].conj())
+            .sum::<C64>()
+    });
+    DensityMatrix::from_matrix(&dims, reduced)
+}
+
+/// Entropie d’intrication (bits) entre les sous-systèmes `part` d’un état pur et le reste.
+pub fn entanglement_entropy(state: &QuantumState, part: &[usize]) -> Result<f64, QuantumError> {
+    Ok(reduced_state(state, part)?.von_neumann_entropy())
+}
+
+/// Information mutuelle (bits) entre les groupes disjoints `a` et `b`.
+pub fn mutual_information(
+    rho: &DensityMatrix,
+    a: &[usize],
+    b: &[usize],
+) -> Result<f64, QuantumError> {
+    let joint: Vec<usize> = a.iter().chain(b).copied().collect();
+    let s_a = partial_trace(rho, a)?.von_neumann_entropy();
+    let s_b = partial_trace(rho, b)?.von_neumann_entropy();
+    let s_ab = partial_trace(rho, &joint)?.von_neumann_entropy();
+    Ok((s_a + s_b - s_ab).max(0.0))
+}
+
+/// Concurrence de Wootters d’un état de deux qubits, dans `[0, 1]`.
+///
+/// `C = max(0, λ₁ − λ₂ − λ₃ − λ₄)`, où les `λᵢ` sont, par ordre décroissant, les valeurs
+/// singulières de `√ρ √ρ̃` avec `ρ̃ = (Y ⊗ Y) ρ* (Y ⊗ Y)`.
+pub fn concurrence(rho: &DensityMatrix) -> Result<f64, QuantumError> {
+    if rho.dims() != [2, 2] {
+        return Err(QuantumError::InvalidDimensions(rho.dims().to_vec()));
+    }
+    let y = Gate::y();
+    let yy = y.matrix().kronecker(y.matrix());
+    let tilde = &yy * rho.matrix().conjugate() * &yy;
+
+    let eigen = SymmetricEigen::new(rho.matrix().clone());
+    let roots = eigen.eigenvalues.map(|p| C64::from(p.max(0.0).sqrt()));
+    let sqrt_rho =
+        &eigen.eigenvectors * DMatrix::from_diagonal(&roots) * eigen.eigenvectors.adjoint();
+    let product = &sqrt_rho * tilde * &sqrt_rho;
+    // Symétrisation : élimine l’anti-hermiticité numérique avant la diagonalisation.
+    let product = (&product + product.adjoint()) * C64::from(0.5);
+
+    let mut lambdas: Vec<f64> = SymmetricEigen::new(product)
+        .eigenvalues
+        .iter()
+        .map(|mu| mu.max(0.0).sqrt())
+        .collect();
+    lambdas.sort_by(|a, b| b.total_cmp(a));
+    Ok((lambdas[0] - lambdas[1] - lambdas[2] - lambdas[3]).max(0.0))
+}
+
+/// Composant : rattache à l’entité le sous-système `index` du registre porté par `register`.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct QuantumSubsystem {
+    pub register: Entity,
+    pub index: usize,
+}
+
+/// Corrélations entre deux sous-systèmes d’un registre.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct SubsystemPair {
+    pub a: usize,
+    pub b: usize,
+    /// Information mutuelle (bits).
+    pub mutual_information: f64,
+    /// Concurrence, si les deux sous-systèmes sont des qubits.
+    pub concurrence: Option<f64>,
+}
+
+/// Composant : mesures d’intrication d’un registre au dernier pas.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct EntanglementMeasures {
+    /// Entropie de von Neumann (bits) de chaque sous-système.
+    pub entropies: Vec<f64>,
+    /// Corrélations de chaque paire de sous-systèmes.
+    pub pairs: Vec<SubsystemPair>,
+}
+
+impl EntanglementMeasures {
+    /// Mesure l’état `rho`.
+    pub fn measure(rho: &DensityMatrix) -> Result<Self, QuantumError> {
+        let n = rho.subsystems();
+        let reduced: Vec<DensityMatrix> = (0..n)
+            .map(|k| partial_trace(rho, &[k]))
+            .collect::<Result<_, _>>()?;
+        let entropies: Vec<f64> = reduced.iter().map(|r| r.von_neumann_entropy()).collect();
+
+        let mut pairs = Vec::new();
+        for a in 0..n {
+            for b in a + 1..n {
+                let joint = partial_trace(rho, &[a, b])?;
+                let mutual_information =
+                    (entropies[a] + entropies[b] - joint.von_neumann_entropy()).max(0.0);
+                pairs.push(SubsystemPair {
+                    a,
+                    b,
+                    mutual_information,
+                    concurrence: concurrence(&joint).ok(),
+                });
+            }
+        }
+        Ok(Self { entropies, pairs })
+    }
+
+    /// Corrélations entre les sous-systèmes `a` et `b`.
+    pub fn pair(&self, a: usize, b: usize) -> Option<&SubsystemPair> {
+        let (a, b) = (a.min(b), a.max(b));
+        self.pairs.iter().find(|p| p.a == a && p.b == b)
+    }
+}
+
+/// Corrélation d’une entité avec une autre partageant son registre.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct Correlation {
+    pub partner: Entity,
+    /// Information mutuelle (bits).
+    pub mutual_information: f64,
+    pub concurrence: Option<f64>,
+}
+
+/// Composant : corrélations quantiques d’une entité rattachée à un registre.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Correlations {
+    /// Entropie de von Neumann (bits) du sous-système de l’entité ; pour un registre pur,
+    /// son intrication avec le reste du registre.
+    pub entropy: f64,
+    pub partners: Vec<Correlation>,
+}
+
+impl Correlations {
+    /// Information mutuelle totale avec les partenaires.
+    pub fn total_mutual_information(&self) -> f64 {
+        self.partners.iter().map(|c| c.mutual_information).sum()
+    }
+}
+
+/// Ressource : paramètres des mesures d’intrication.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct EntanglementConfig {
+    pub enabled: bool,
+    /// Dimension au-delà de laquelle un registre n’est pas analysé (coût en `d²` par paire).
+    pub max_dimension: usize,
+}
+
+impl Default for EntanglementConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_dimension: 256,
+        }
+    }
+}
+
+/// Ressource : bilan des mesures d’intrication au dernier pas.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct EntanglementStats {
+    /// Registres analysés.
+    pub registers: usize,
+    /// Paires de sous-systèmes analysées.
+    pub pairs: usize,
+    /// Paires d’information mutuelle non nulle.
+    pub correlated: usize,
+    /// Information mutuelle moyenne par paire (bits).
+    pub mean_mutual_information: f64,
+    /// Concurrence maximale parmi les paires de qubits.
+    pub max_concurrence: f64,
+}
+
+/// Registres analysés, purs ou mixtes.
+type RegisterQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        AnyOf<(&'static QuantumRegister, &'static DensityRegister)>,
+        Option<&'static mut EntanglementMeasures>,
+    ),
+>;
+
+/// Système : mesure l’intrication des registres et les corrélations des entités rattachées.
+pub fn measure_entanglement(
+    mut commands: Commands,
+    mut registers: RegisterQuery,
+    mut attached: Query<(Entity, &QuantumSubsystem, Option<&mut Correlations>)>,
+    config: Res<EntanglementConfig>,
+    mut stats: ResMut<EntanglementStats>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let mut measured = HashMap::new();
+    for (entity, (pure, mixed), measures) in registers.iter_mut() {
+        let rho = match (pure, mixed) {
+            (Some(pure), _) if pure.state.dimension() <= config.max_dimension => {
+                DensityMatrix::from_state(&pure.state)
+            }
+            (None, Some(mixed)) if mixed.state.dimension() <= config.max_dimension => {
+                mixed.state.clone()
+            }
+            _ => continue,
+        };
+        let Ok(result) = EntanglementMeasures::measure(&rho) else {
+            continue;
+        };
+        match measures {
+            Some(mut measures) => *measures = result.clone(),
+            None => {
+                commands.entity(entity).insert(result.clone());
+            }
+        }
+        measured.insert(entity, result);
+    }
+
+    let mut members: HashMap<Entity, Vec<(Entity, usize)>> = HashMap::new();
+    for (entity, subsystem, _) in attached.iter() {
+        members
+            .entry(subsystem.register)
+            .or_default()
+            .push((entity, subsystem.index));
+    }
+    for (entity, subsystem, correlations) in attached.iter_mut() {
+        let Some(measures) = measured.get(&subsystem.register) else {
+            continue;
+        };
+        let Some(&entropy) = measures.entropies.get(subsystem.index) else {
+            continue;
+        };
+        let partners = members[&subsystem.register]
+            .iter()
+            .filter(|(other, index)| *other != entity && *index != subsystem.index)
+            .filter_map(|&(partner, index)| {
+                let pair = measures.pair(subsystem.index, index)?;
+                Some(Correlation {
+                    partner,
+                    mutual_information: pair.mutual_information,
+                    concurrence: pair.concurrence,
+                })
+            })
+            .collect();
+        let result = Correlations { entropy, partners };
+        match correlations {
+            Some(mut correlations) => *correlations = result,
+            None => {
+                commands.entity(entity).insert(result);
+            }
+        }
+    }
+
+    let mut total = EntanglementStats {
+        registers: measured.len(),
+        ..default()
+    };
+    for pair in measured.values().flat_map(|m| &m.pairs) {
+        total.pairs += 1;
+        if pair.mutual_information > CORRELATION_THRESHOLD {
+            total.correlated += 1;
+        }
+        total.mean_mutual_information += pair.mutual_information;
+        total.max_concurrence = total.max_concurrence.max(pair.concurrence.unwrap_or(0.0));
+    }
+    if total.pairs > 0 {
+        total.mean_mutual_information /= total.pairs as f64;
+        debug!(
+            target: "dynamics",
+            registers = total.registers,
+            pairs = total.pairs,
+            mutual_information = total.mean_mutual_information,
+            concurrence = total.max_concurrence,
+            "intrication mesurée"
+        );
+    }
+    *stats = total;
+}
+
+/// Enregistre les mesures d’intrication.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<QuantumSubsystem>()
+        .register_type::<SubsystemPair>()
+        .register_type::<EntanglementMeasures>()
+        .register_type::<Correlation>()
+        .register_type::<Correlations>()
+        .register_type::<EntanglementConfig>()
+        .register_type::<EntanglementStats>()
+        .init_resource::<EntanglementConfig>()
+        .init_resource::<EntanglementStats>()
+        .add_systems(
+            FixedUpdate,
+            measure_entanglement
+                .in_set(DynamicsSet::Integrate)
+                .after(decohere)
+                .after(record_expectations),
+        );
+}
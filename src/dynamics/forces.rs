@@ -0,0 +1,465 @@
+//! Accumulation des forces appliquées aux corps.
+//!
+//! Chaque pas fixe, [`accumulate_forces`] vide le [`ForceAccumulator`] de chaque corps
+//! massif puis y range les contributions de chaque source, évaluées à l’état courant :
+//! - constantes : composant [`Force`], gravité globale ([`Gravity`]), impulsions,
+//!   contributions libres ajoutées par d’autres systèmes (`ForceSource::Custom`),
+//! - dépendantes de l’état : traînée ([`Drag`]), ressorts ([`Spring`]) et potentiels du
+//!   substrat.
+//!
+//! [`apply_vacuum_forces`] ajoute ensuite les fluctuations du vide du substrat, lorsqu’elles
+//! sont présentes et actives.
+//!
+//! Les intégrateurs reprennent les contributions constantes telles quelles et réévaluent
+//! celles qui dépendent de l’état aux positions et vitesses intermédiaires du pas, via
+//! le [`ForceModel`] construit une seule fois par [`accumulate_forces`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use tracing::trace;
+
+use super::{Force, Mass, Velocity};
+use crate::substrate::potential::{
+    GlobalPotentials, Potential, PotentialEnergy, PotentialForce, PotentialSources,
+};
+use crate::substrate::topology::DomainTopology;
+use crate::substrate::{Field, VacuumConfig, VacuumFluctuations};
+
+/// Origine d’une contribution de force.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum ForceSource {
+    /// Composant [`Force`].
+    Constant,
+    /// Gravité globale ([`Gravity`]).
+    Gravity,
+    /// Traînée linéaire et quadratique ([`Drag`]).
+    Drag,
+    /// Ressorts ([`Spring`]), y compris la réaction des ressorts ancrés sur ce corps.
+    Spring,
+    /// Potentiels du substrat.
+    Potential,
+    /// Gravitation mutuelle entre corps (voir `dynamics::nbody`).
+    Gravitation,
+    /// Forces de Coulomb entre corps chargés (voir `dynamics::nbody`).
+    Electrostatic,
+    /// Fluctuations du vide (voir `substrate::vacuum`).
+    Vacuum,
+    /// Impulsions, converties en force moyenne sur le pas.
+    Impulse,
+    /// Contributions ajoutées par d’autres systèmes.
+    Custom,
+}
+
+impl ForceSource {
+    /// `true` si la contribution est réévaluée à chaque étape de l’intégrateur.
+    pub fn is_state_dependent(self) -> bool {
+        matches!(self, Self::Drag | Self::Spring | Self::Potential)
+    }
+}
+
+/// Ressource : accélération de la pesanteur appliquée à tous les corps massifs.
+#[derive(Resource, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Gravity(pub Vec3);
+
+/// Composant : traînée `F = -(linear + quadratic |v|) v`.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Drag {
+    pub linear: f32,
+    pub quadratic: f32,
+}
+
+impl Drag {
+    /// Force de traînée à la vitesse `velocity`.
+    pub fn force(&self, velocity: Vec3) -> Vec3 {
+        -(self.linear + self.quadratic * velocity.length()) * velocity
+    }
+}
+
+/// Point d’attache d’un ressort.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum SpringAnchor {
+    /// Point fixe du monde.
+    Point(Vec3),
+    /// Autre entité, qui subit la réaction opposée.
+    Entity(Entity),
+}
+
+impl Default for SpringAnchor {
+    fn default() -> Self {
+        Self::Point(Vec3::ZERO)
+    }
+}
+
+/// Composant : ressort amorti reliant l’entité à une ancre.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Spring {
+    pub anchor: SpringAnchor,
+    pub stiffness: f32,
+    pub rest_length: f32,
+    /// Amortissement le long de l’axe du ressort.
+    pub damping: f32,
+}
+
+/// Composant : contributions de force d’un corps pour le pas courant.
+///
+/// Requis par [`Mass`] et vidé au début de chaque pas fixe ; les systèmes tiers ajoutent leurs forces avec
+/// [`ForceAccumulator::add`] dans `DynamicsSet::Forces`, après [`accumulate_forces`].
+/// Les impulsions ([`ForceAccumulator::apply_impulse`]) peuvent être déposées à tout
+/// moment et sont consommées par le pas fixe suivant.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct ForceAccumulator {
+    contributions: Vec<(ForceSource, Vec3)>,
+    pending_impulse: Vec3,
+}
+
+impl ForceAccumulator {
+    /// Ajoute une contribution (cumulée avec celles de la même source).
+    pub fn add(&mut self, source: ForceSource, force: Vec3) {
+        match self.contributions.iter_mut().find(|(s, _)| *s == source) {
+            Some((_, total)) => *total += force,
+            None => self.contributions.push((source, force)),
+        }
+    }
+
+    /// Dépose une impulsion (N·s), appliquée au prochain pas fixe.
+    pub fn apply_impulse(&mut self, impulse: Vec3) {
+        self.pending_impulse += impulse;
+    }
+
+    /// Impulsion en attente.
+    pub fn pending_impulse(&self) -> Vec3 {
+        self.pending_impulse
+    }
+
+    /// Contribution d’une source (nulle si absente).
+    pub fn get(&self, source: ForceSource) -> Vec3 {
+        self.contributions
+            .iter()
+            .find(|(s, _)| *s == source)
+            .map_or(Vec3::ZERO, |(_, force)| *force)
+    }
+
+    /// Détail des contributions du pas courant, dans leur ordre d’ajout.
+    pub fn breakdown(&self) -> &[(ForceSource, Vec3)] {
+        &self.contributions
+    }
+
+    /// Force totale évaluée au début du pas.
+    pub fn total(&self) -> Vec3 {
+        self.contributions.iter().map(|(_, force)| *force).sum()
+    }
+
+    /// Somme des contributions qui ne dépendent pas de l’état du corps.
+    pub fn constant_total(&self) -> Vec3 {
+        self.contributions
+            .iter()
+            .filter(|(source, _)| !source.is_state_dependent())
+            .map(|(_, force)| *force)
+            .sum()
+    }
+
+    /// Vide les contributions (l’impulsion en attente est conservée).
+    pub fn clear(&mut self) {
+        self.contributions.clear();
+    }
+}
+
+/// Ressort vu depuis l’un de ses deux corps : l’autre extrémité est figée pendant le pas.
+#[derive(Clone, Copy, Debug)]
+struct SpringLink {
+    position: Vec3,
+    velocity: Vec3,
+    stiffness: f32,
+    rest_length: f32,
+    damping: f32,
+}
+
+/// Ressource : modèle des forces, figé au début d’un pas.
+///
+/// Construit une fois par pas fixe par [`accumulate_forces`], puis réutilisé par les
+/// intégrateurs pour réévaluer les forces aux états intermédiaires.
+#[derive(Resource, Default)]
+pub struct ForceModel {
+    gravity: Vec3,
+    potentials: PotentialSources,
+    topology: DomainTopology,
+    springs: HashMap<Entity, Vec<SpringLink>>,
+}
+
+impl ForceModel {
+    /// Construit le modèle ; `state` donne la position et la vitesse des ancres entités.
+    pub fn new<'s>(
+        gravity: Vec3,
+        potentials: PotentialSources,
+        topology: DomainTopology,
+        springs: impl IntoIterator<Item = (Entity, &'s Spring)>,
+        state: impl Fn(Entity) -> Option<(Vec3, Vec3)>,
+    ) -> Self {
+        let mut links: HashMap<Entity, Vec<SpringLink>> = HashMap::new();
+        for (entity, spring) in springs {
+            let link = |(position, velocity)| SpringLink {
+                position,
+                velocity,
+                stiffness: spring.stiffness,
+                rest_length: spring.rest_length,
+                damping: spring.damping,
+            };
+            match spring.anchor {
+                SpringAnchor::Point(point) => {
+                    links
+                        .entry(entity)
+                        .or_default()
+                        .push(link((point, Vec3::ZERO)));
+                }
+                SpringAnchor::Entity(other) => {
+                    let (Some(own), Some(anchor)) = (state(entity), state(other)) else {
+                        continue;
+                    };
+                    links.entry(entity).or_default().push(link(anchor));
+                    links.entry(other).or_default().push(link(own));
+                }
+            }
+        }
+
+        Self {
+            gravity,
+            potentials,
+            topology,
+            springs: links,
+        }
+    }
+
+    /// Contributions constantes sur le pas : composant [`Force`] et gravité.
+    pub fn constant(
+        &self,
+        mass: f32,
+        force: Option<&Force>,
+        mut each: impl FnMut(ForceSource, Vec3),
+    ) {
+        if let Some(force) = force {
+            each(ForceSource::Constant, force.vector());
+        }
+        if self.gravity != Vec3::ZERO {
+            each(ForceSource::Gravity, self.gravity * mass);
+        }
+    }
+
+    /// Contributions dépendantes de l’état de `entity` en `position` / `velocity`.
+    ///
+    /// `fields` sont les champs échantillonnés par les potentiels de grille. Retourne
+    /// l’énergie potentielle du corps en `position`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn state_dependent(
+        &self,
+        entity: Entity,
+        position: Vec3,
+        velocity: Vec3,
+        mass: f32,
+        drag: Option<&Drag>,
+        fields: &[&Field],
+        mut each: impl FnMut(ForceSource, Vec3),
+    ) -> f32 {
+        if let Some(drag) = drag {
+            each(ForceSource::Drag, drag.force(velocity));
+        }
+        if let Some(links) = self.springs.get(&entity) {
+            let force = links
+                .iter()
+                .map(|link| {
+                    let offset = self.topology.displacement(link.position, position);
+                    let length = offset.length();
+                    let axis = offset.normalize_or_zero();
+                    let stretch = link.stiffness * (length - link.rest_length);
+                    let damping = link.damping * (velocity - link.velocity).dot(axis);
+                    -(stretch + damping) * axis
+                })
+                .sum();
+            each(ForceSource::Spring, force);
+        }
+        if self.potentials.is_empty() {
+            return 0.0;
+        }
+        let (force, energy) = self.potentials.evaluate(entity, position, mass, fields);
+        each(ForceSource::Potential, force);
+        energy
+    }
+
+    /// Somme des contributions dépendantes de l’état.
+    pub fn state_total(
+        &self,
+        entity: Entity,
+        position: Vec3,
+        velocity: Vec3,
+        mass: f32,
+        drag: Option<&Drag>,
+        fields: &[&Field],
+    ) -> Vec3 {
+        let mut total = Vec3::ZERO;
+        self.state_dependent(
+            entity,
+            position,
+            velocity,
+            mass,
+            drag,
+            fields,
+            |_, force| {
+                total += force;
+            },
+        );
+        total
+    }
+}
+
+/// Rassemble l’état nécessaire à un [`ForceModel`] : potentiels, topologie, gravité.
+fn force_model<'p, 's>(
+    potentials: impl IntoIterator<Item = (Entity, &'p Potential, Option<&'p Transform>)>,
+    global: Option<&GlobalPotentials>,
+    topology: Option<&DomainTopology>,
+    gravity: Option<&Gravity>,
+    springs: impl IntoIterator<Item = (Entity, &'s Spring)>,
+    state: impl Fn(Entity) -> Option<(Vec3, Vec3)>,
+) -> ForceModel {
+    let topology = topology.cloned().unwrap_or_default();
+    let potentials =
+        PotentialSources::collect(potentials, &global.cloned().unwrap_or_default(), &topology);
+    ForceModel::new(
+        gravity.map_or(Vec3::ZERO, |g| g.0),
+        potentials,
+        topology,
+        springs,
+        state,
+    )
+}
+
+/// Corps massifs dont les forces sont accumulées.
+type AccumulatorQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        Option<&'static Velocity>,
+        &'static Mass,
+        Option<&'static Force>,
+        Option<&'static Drag>,
+        &'static mut ForceAccumulator,
+        Option<&'static mut PotentialForce>,
+    ),
+>;
+
+/// Système : remplit le [`ForceAccumulator`] de chaque corps massif pour le pas courant.
+///
+/// Les potentiels n’y sont évalués qu’une fois : la force et l’énergie de chaque corps
+/// sont aussi rapportées dans [`PotentialForce`], et leur somme dans [`PotentialEnergy`].
+/// Une entité sans masse servant d’ancre de ressort est immobile : seule sa position compte.
+#[allow(clippy::too_many_arguments)]
+pub fn accumulate_forces(
+    mut commands: Commands,
+    mut bodies: AccumulatorQuery,
+    mut model: ResMut<ForceModel>,
+    potentials: Query<(Entity, &Potential, Option<&Transform>)>,
+    springs: Query<(Entity, &Spring)>,
+    anchors: Query<&Transform>,
+    global: Option<Res<GlobalPotentials>>,
+    topology: Option<Res<DomainTopology>>,
+    gravity: Option<Res<Gravity>>,
+    fields: Query<&Field>,
+    potential_energy: Option<ResMut<PotentialEnergy>>,
+    time: Res<Time>,
+) {
+    let states: HashMap<Entity, (Vec3, Vec3)> = bodies
+        .iter()
+        .map(|(entity, transform, velocity, ..)| {
+            (
+                entity,
+                (
+                    transform.translation,
+                    velocity.map_or(Vec3::ZERO, |v| v.linear),
+                ),
+            )
+        })
+        .collect();
+    *model = force_model(
+        potentials.iter(),
+        global.as_deref(),
+        topology.as_deref(),
+        gravity.as_deref(),
+        springs.iter(),
+        |entity| {
+            states.get(&entity).copied().or_else(|| {
+                anchors
+                    .get(entity)
+                    .ok()
+                    .map(|anchor| (anchor.translation, Vec3::ZERO))
+            })
+        },
+    );
+    let fields: Vec<&Field> = fields.iter().collect();
+    let dt = time.delta_secs();
+    let mut total_potential = 0.0;
+
+    for (entity, transform, velocity, mass, force, drag, mut accumulator, potential) in
+        bodies.iter_mut()
+    {
+        accumulator.clear();
+        model.constant(mass.value, force, |source, f| accumulator.add(source, f));
+        let energy = model.state_dependent(
+            entity,
+            transform.translation,
+            velocity.map_or(Vec3::ZERO, |v| v.linear),
+            mass.value,
+            drag,
+            &fields,
+            |source, f| accumulator.add(source, f),
+        );
+        // L’impulsion est répartie sur le pas : même variation de quantité de mouvement.
+        if accumulator.pending_impulse != Vec3::ZERO && dt > 0.0 {
+            let impulse = std::mem::take(&mut accumulator.pending_impulse);
+            accumulator.add(ForceSource::Impulse, impulse / dt);
+        }
+        trace!(target: "dynamics", ?entity, total = ?accumulator.total(), "forces accumulées");
+
+        let result = PotentialForce {
+            force: accumulator.get(ForceSource::Potential),
+            energy,
+        };
+        total_potential += result.energy;
+        match potential {
+            Some(mut potential) => *potential = result,
+            None => {
+                commands.entity(entity).insert(result);
+            }
+        }
+    }
+
+    if let Some(mut potential_energy) = potential_energy {
+        potential_energy.total = total_potential;
+    }
+}
+
+/// Système : module les forces autour de leur valeur nominale par les fluctuations du vide.
+///
+/// Chaque corps portant une [`Force`] reçoit la contribution `ForceSource::Vacuum` =
+/// [`VacuumFluctuations::force`] à sa position ; le composant [`Force`] lui-même n’est
+/// jamais modifié. Sans la couche `substrate`, le système est sans effet.
+pub fn apply_vacuum_forces(
+    mut bodies: Query<(&Transform, &mut ForceAccumulator), With<Force>>,
+    vacuum: Option<Res<VacuumFluctuations>>,
+    config: Option<Res<VacuumConfig>>,
+) {
+    let (Some(vacuum), Some(config)) = (vacuum, config) else {
+        return;
+    };
+    if !config.enabled || config.force_amplitude == 0.0 {
+        return;
+    }
+
+    for (transform, mut accumulator) in bodies.iter_mut() {
+        accumulator.add(ForceSource::Vacuum, vacuum.force(transform.translation));
+    }
+}
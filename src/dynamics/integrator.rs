@@ -0,0 +1,233 @@
+//! Intégrateurs numériques de la dynamique.
+//!
+//! Le mouvement des corps est intégré sur le pas fixe de Bevy (`FixedUpdate`), découpé
+//! en `substeps` sous-pas. Trois schémas sont disponibles :
+//! - Euler semi-implicite (symplectique, ordre 1) : l’énergie oscille sans dériver,
+//! - Verlet vitesse (symplectique, ordre 2) : même comportement, erreur bien plus faible,
+//! - Runge-Kutta 4 (ordre 4, non symplectique) : très précis à court terme, mais
+//!   l’énergie décroît lentement sur de longues durées.
+//!
+//! Le schéma est choisi globalement ([`IntegratorConfig`]) ou par entité ([`Integrator`]).
+//! L’accélération est réévaluée aux états intermédiaires du pas : les contributions
+//! constantes du [`ForceAccumulator`] s’y ajoutent aux forces dépendantes de l’état
+//! (traînée, ressorts, potentiels), dont les sources restent figées pendant le pas.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::forces::{Drag, ForceAccumulator, ForceModel};
+use super::{rotate_rigid, Inertia, Mass, Torque, Velocity};
+use crate::substrate::Field;
+
+/// Schéma d’intégration numérique.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum IntegratorKind {
+    #[default]
+    SemiImplicitEuler,
+    VelocityVerlet,
+    Rk4,
+}
+
+/// Position et vitesse d’un corps.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BodyState {
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+impl IntegratorKind {
+    /// Avance `state` d’un pas `h` ; `acceleration(position, vitesse)` donne l’accélération.
+    pub fn step(
+        self,
+        state: BodyState,
+        h: f32,
+        acceleration: impl Fn(Vec3, Vec3) -> Vec3,
+    ) -> BodyState {
+        let BodyState {
+            position: x,
+            velocity: v,
+        } = state;
+        match self {
+            Self::SemiImplicitEuler => {
+                let v = v + acceleration(x, v) * h;
+                BodyState {
+                    position: x + v * h,
+                    velocity: v,
+                }
+            }
+            Self::VelocityVerlet => {
+                let a = acceleration(x, v);
+                let x_next = x + v * h + 0.5 * a * h * h;
+                // La vitesse passée à la seconde évaluation est une prédiction d’Euler.
+                let a_next = acceleration(x_next, v + a * h);
+                BodyState {
+                    position: x_next,
+                    velocity: v + 0.5 * (a + a_next) * h,
+                }
+            }
+            Self::Rk4 => {
+                let k1x = v;
+                let k1v = acceleration(x, v);
+                let k2x = v + k1v * (h * 0.5);
+                let k2v = acceleration(x + k1x * (h * 0.5), k2x);
+                let k3x = v + k2v * (h * 0.5);
+                let k3v = acceleration(x + k2x * (h * 0.5), k3x);
+                let k4x = v + k3v * h;
+                let k4v = acceleration(x + k3x * h, k4x);
+                BodyState {
+                    position: x + (k1x + 2.0 * k2x + 2.0 * k3x + k4x) * (h / 6.0),
+                    velocity: v + (k1v + 2.0 * k2v + 2.0 * k3v + k4v) * (h / 6.0),
+                }
+            }
+        }
+    }
+}
+
+/// Configuration globale de l’intégration.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct IntegratorConfig {
+    /// Schéma par défaut (remplaçable par entité via [`Integrator`]).
+    pub kind: IntegratorKind,
+    /// Nombre de sous-pas par pas fixe.
+    pub substeps: u32,
+    /// Durée du pas fixe (appliquée à `Time<Fixed>`).
+    pub timestep: Duration,
+}
+
+impl Default for IntegratorConfig {
+    fn default() -> Self {
+        Self {
+            kind: IntegratorKind::default(),
+            substeps: 1,
+            timestep: Duration::from_nanos(16_666_667),
+        }
+    }
+}
+
+/// Composant : schéma d’intégration propre à une entité.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Integrator(pub IntegratorKind);
+
+/// Système : reporte `IntegratorConfig::timestep` sur l’horloge du pas fixe.
+pub fn sync_fixed_timestep(config: Res<IntegratorConfig>, fixed: Option<ResMut<Time<Fixed>>>) {
+    if let Some(mut fixed) = fixed {
+        if config.is_changed() && fixed.timestep() != config.timestep {
+            fixed.set_timestep(config.timestep);
+            debug!(target: "dynamics", timestep = ?config.timestep, "pas fixe mis à jour");
+        }
+    }
+}
+
+/// Corps intégrés : position, vitesse et, s’ils sont présents, masse, forces et schéma.
+type BodyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Transform,
+        &'static mut Velocity,
+        Option<&'static Mass>,
+        Option<&'static Drag>,
+        Option<&'static ForceAccumulator>,
+        Option<&'static Integrator>,
+    ),
+>;
+
+/// Système : intègre le mouvement de translation de chaque corps sur le pas fixe.
+///
+/// Les contributions constantes viennent du [`ForceAccumulator`] requis par [`Mass`] ;
+/// traînée, ressorts et potentiels sont réévalués à chaque étape du schéma avec le
+/// [`ForceModel`] construit par `accumulate_forces`.
+pub fn integrate_bodies(
+    mut bodies: BodyQuery,
+    model: Res<ForceModel>,
+    fields: Query<&Field>,
+    config: Res<IntegratorConfig>,
+    time: Res<Time>,
+) {
+    let substeps = config.substeps.max(1);
+    let h = time.delta_secs() / substeps as f32;
+    if h <= 0.0 {
+        return;
+    }
+    let fields: Vec<&Field> = fields.iter().collect();
+
+    for (entity, mut transform, mut velocity, mass, drag, accumulator, integrator) in
+        bodies.iter_mut()
+    {
+        let kind = integrator.map_or(config.kind, |i| i.0);
+        let mass = mass.map(|m| m.value);
+        let constant = accumulator.map_or(Vec3::ZERO, ForceAccumulator::constant_total);
+
+        // Sans masse, le corps dérive à vitesse constante.
+        let acceleration = |position: Vec3, velocity: Vec3| match mass {
+            Some(mass) => {
+                (constant + model.state_total(entity, position, velocity, mass, drag, &fields))
+                    / mass.max(1e-6)
+            }
+            None => Vec3::ZERO,
+        };
+
+        let mut state = BodyState {
+            position: transform.translation,
+            velocity: velocity.linear,
+        };
+        for _ in 0..substeps {
+            state = kind.step(state, h, acceleration);
+        }
+        transform.translation = state.position;
+        velocity.linear = state.velocity;
+    }
+}
+
+/// Système : applique les couples et intègre la rotation de chaque corps sur le pas fixe.
+///
+/// À chaque sous-pas, `ω += I⁻¹ τ h` (tenseur exprimé dans le repère monde), puis le
+/// corps tourne en conservant son moment cinétique (voir `rotate_rigid`).
+pub fn integrate_rotations(
+    mut query: Query<(
+        &mut Transform,
+        &mut Velocity,
+        Option<&Torque>,
+        Option<&Inertia>,
+    )>,
+    config: Res<IntegratorConfig>,
+    time: Res<Time>,
+) {
+    let substeps = config.substeps.max(1);
+    let h = time.delta_secs() / substeps as f32;
+    if h <= 0.0 {
+        return;
+    }
+
+    for (mut transform, mut velocity, torque, inertia) in query.iter_mut() {
+        for _ in 0..substeps {
+            if let Some(torque) = torque {
+                let inverse = inertia
+                    .copied()
+                    .unwrap_or_default()
+                    .world_inverse(transform.rotation);
+                // Un tenseur dégénéré (corps ponctuel) ne reçoit pas d’accélération angulaire.
+                if let Some(inverse) = inverse {
+                    velocity.angular += inverse * torque.vector * h;
+                }
+            }
+            if velocity.angular == Vec3::ZERO {
+                break;
+            }
+            transform.rotation = match inertia {
+                Some(inertia) => {
+                    rotate_rigid(inertia, transform.rotation, &mut velocity.angular, h)
+                }
+                // Rotation d’angle |ω| h autour de ω (repère monde), renormalisée contre la dérive.
+                None => {
+                    (Quat::from_scaled_axis(velocity.angular * h) * transform.rotation).normalize()
+                }
+            };
+        }
+    }
+}
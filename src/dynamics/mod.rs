@@ -8,24 +8,156 @@
 //! Il constitue la **couche L1 (dynamique quantique et systémique)** du moteur.
 
 use bevy::prelude::*;
-use std::f32::consts::PI;
+
 use tracing::{debug, info};
 
-/// Composant représentant la vélocité d'une entité (en unités/s).
+use crate::substrate::topology::{apply_topology, DomainTopology};
+
+pub mod collision;
+pub mod coupling;
+pub mod decoherence;
+pub mod emitter;
+pub mod energy;
+pub mod entanglement;
+pub mod forces;
+pub mod integrator;
+pub mod nbody;
+pub mod quantum;
+pub mod thermostat;
+
+pub use collision::{Collider, ColliderShape, CollisionConfig, CollisionEvent, CollisionStats};
+pub use coupling::{CouplingConfig, CouplingNetwork, CouplingStats, CouplingTopology};
+pub use decoherence::{
+    Decoherence, DecoherenceConfig, DecoherenceStats, DensityMatrix, DensityRegister,
+    QuantumEntropy,
+};
+pub use emitter::{
+    Emitter, Particle, ParticleConfig, ParticlePool, ParticleStats, PooledParticle,
+    SpeedDistribution,
+};
+pub use energy::{BodyEnergy, EnergyLedger};
+pub use entanglement::{
+    Correlation, Correlations, EntanglementConfig, EntanglementMeasures, EntanglementStats,
+    QuantumSubsystem, SubsystemPair,
+};
+pub use forces::{
+    Drag, ForceAccumulator, ForceModel, ForceSource, Gravity, Spring, SpringAnchor,
+};
+pub use integrator::{BodyState, Integrator, IntegratorConfig, IntegratorKind};
+pub use nbody::{Charge, NBodyConfig, NBodyMode, NBodyStats, Octree};
+pub use quantum::{
+    Gate, Hamiltonian, MeasureRequest, MeasurementOutcome, Observable, QuantumConfig,
+    QuantumError, QuantumExpectations, QuantumHamiltonian, QuantumObservables, QuantumRegister,
+    QuantumState,
+};
+pub use thermostat::{ThermostatConfig, ThermostatKind, ThermostatStats};
+
+/// Étapes de la dynamique sur le pas fixe (`FixedUpdate`), exécutées dans cet ordre.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicsSet {
+    /// Remplissage des accumulateurs de forces (voir [`forces::accumulate_forces`]).
+    Forces,
+    /// Intégration des positions, vitesses et orientations.
+    Integrate,
+    /// Détection et réponse aux collisions.
+    Collisions,
+    /// Conditions aux limites du domaine.
+    Boundaries,
+}
+
+/// Composant représentant la vélocité d'une entité.
 #[derive(Component, Default, Debug, Reflect)]
 #[reflect(Component)]
 pub struct Velocity {
+    /// Vitesse linéaire (unités/s).
     pub linear: Vec3,
+    /// Vitesse angulaire dans le repère monde (rad/s) : axe de rotation × vitesse.
     pub angular: Vec3,
 }
 
 /// Composant représentant la masse et l’inertie d’une entité.
+///
+/// Un corps massif reçoit ses forces par son [`ForceAccumulator`], inséré avec lui.
 #[derive(Component, Default, Debug, Reflect)]
 #[reflect(Component)]
+#[require(ForceAccumulator)]
 pub struct Mass {
     pub value: f32,
 }
 
+/// Composant représentant le tenseur d’inertie d’une entité, exprimé dans son repère propre.
+///
+/// Sans ce composant, un corps soumis à un couple se comporte comme si son tenseur
+/// valait l’identité.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Inertia {
+    pub tensor: Mat3,
+}
+
+impl Default for Inertia {
+    fn default() -> Self {
+        Self {
+            tensor: Mat3::IDENTITY,
+        }
+    }
+}
+
+impl Inertia {
+    /// Tenseur diagonal (moments principaux alignés sur les axes propres).
+    pub fn diagonal(moments: Vec3) -> Self {
+        Self {
+            tensor: Mat3::from_diagonal(moments),
+        }
+    }
+
+    /// Sphère pleine homogène : `I = 2/5 m r²`.
+    pub fn solid_sphere(mass: f32, radius: f32) -> Self {
+        Self::diagonal(Vec3::splat(0.4 * mass * radius * radius))
+    }
+
+    /// Pavé plein homogène de demi-côtés `half_extents`.
+    pub fn cuboid(mass: f32, half_extents: Vec3) -> Self {
+        let size = half_extents * 2.0;
+        let sq = size * size;
+        Self::diagonal(Vec3::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y) * (mass / 12.0))
+    }
+
+    /// Tenseur exprimé dans le repère monde pour l’orientation `rotation` : `R I Rᵀ`.
+    pub fn world(&self, rotation: Quat) -> Mat3 {
+        let r = Mat3::from_quat(rotation);
+        r * self.tensor * r.transpose()
+    }
+
+    /// Inverse d’un tenseur d’inertie, ou `None` s’il est dégénéré.
+    ///
+    /// Le test est relatif à l’échelle du tenseur, `det I > ε (tr I / 3)³` : un seuil absolu
+    /// sur le déterminant écarterait les petits corps (sphère de 10 cm et 1 kg :
+    /// `I = 0.004`, `det I ≈ 6.4e-8`).
+    pub fn invert(tensor: Mat3) -> Option<Mat3> {
+        let scale = (tensor.x_axis.x + tensor.y_axis.y + tensor.z_axis.z) / 3.0;
+        (scale > 0.0 && tensor.determinant() > f32::EPSILON * scale.powi(3))
+            .then(|| tensor.inverse())
+    }
+
+    /// Inverse du tenseur exprimé dans le repère monde pour l’orientation `rotation`.
+    pub fn world_inverse(&self, rotation: Quat) -> Option<Mat3> {
+        Self::invert(self.world(rotation))
+    }
+
+    /// Énergie cinétique de rotation `½ ωᵀ I ω` (ω dans le repère monde).
+    pub fn angular_kinetic_energy(&self, rotation: Quat, angular: Vec3) -> f32 {
+        0.5 * angular.dot(self.world(rotation) * angular)
+    }
+}
+
+/// Composant représentant un couple appliqué à une entité (repère monde, N·m).
+#[derive(Component, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Torque {
+    pub vector: Vec3,
+}
+
 /// Composant représentant une force appliquée à une entité.
 #[derive(Component, Default, Debug, Reflect)]
 #[reflect(Component)]
@@ -35,38 +167,98 @@ pub struct Force {
 }
 
 impl Force {
+    /// Construit une force à partir de son vecteur résultant.
+    pub fn from_vector(vector: Vec3) -> Self {
+        Self {
+            direction: vector.normalize_or_zero(),
+            magnitude: vector.length(),
+        }
+    }
+
     /// Calcule le vecteur de force résultant.
     pub fn vector(&self) -> Vec3 {
         self.direction.normalize_or_zero() * self.magnitude
     }
 }
 
-/// Système : applique les forces aux entités pour mettre à jour leurs vitesses.
-pub fn apply_forces(mut query: Query<(&mut Velocity, &Force, &Mass)>, time: Res<Time>) {
-    // Applique l'accélération issue des forces sur la vélocité linéaire.
-    for (mut velocity, force, mass) in query.iter_mut() {
-        // Calcul de l'accélération : force / masse (avec protection contre division par zéro)
-        let acceleration = force.vector() / mass.value.max(1e-6);
-        // Intégration de l'accélération dans la vitesse linéaire (changement de vitesse)
-        velocity.linear += acceleration * time.delta_secs();
-        debug!(
-            target: "dynamics",
-            ?acceleration,
-            linear = ?velocity.linear,
-            "accélération appliquée"
-        );
+/// Fait tourner un corps rigide de `dt` en conservant son moment cinétique `L = I ω`.
+///
+/// Schéma du point milieu : la rotation utilise la vitesse angulaire à mi-pas, déduite de
+/// `L` et du tenseur réorienté, puis `ω` est recalculé à partir de `L` en fin de pas.
+fn rotate_rigid(inertia: &Inertia, rotation: Quat, angular: &mut Vec3, dt: f32) -> Quat {
+    let momentum = inertia.world(rotation) * *angular;
+    let omega_at = |q: Quat| inertia.world_inverse(q).map(|inverse| inverse * momentum);
+
+    let mut mid = *angular;
+    for _ in 0..2 {
+        let half = (Quat::from_scaled_axis(mid * 0.5 * dt) * rotation).normalize();
+        mid = omega_at(half).unwrap_or(mid);
+    }
+    let rotated = (Quat::from_scaled_axis(mid * dt) * rotation).normalize();
+    *angular = omega_at(rotated).unwrap_or(*angular);
+    rotated
+}
+
+/// Système : applique les forces aux entités pour mettre à jour leurs vitesses linéaires.
+///
+/// Pas d’Euler explicite sur `Time` : la force est celle du [`ForceAccumulator`] s’il est
+/// présent, sinon le composant [`Force`].
+#[deprecated(note = "les forces sont intégrées sur le pas fixe par `DynamicsSet::Integrate`")]
+pub fn apply_forces(
+    mut query: Query<(
+        &mut Velocity,
+        Option<&Force>,
+        Option<&ForceAccumulator>,
+        &Mass,
+    )>,
+    time: Res<Time>,
+) {
+    for (mut velocity, force, accumulator, mass) in query.iter_mut() {
+        let total = match accumulator {
+            Some(accumulator) => accumulator.total(),
+            None => force.map_or(Vec3::ZERO, Force::vector),
+        };
+        velocity.linear += total / mass.value.max(1e-6) * time.delta_secs();
+    }
+}
+
+/// Système : applique les couples aux entités pour mettre à jour leurs vitesses angulaires.
+///
+/// `ω += I⁻¹ τ dt`, avec le tenseur d’inertie exprimé dans le repère monde.
+#[deprecated(note = "les couples sont intégrés sur le pas fixe par `DynamicsSet::Integrate`")]
+pub fn apply_torques(
+    mut query: Query<(&mut Velocity, &Transform, &Torque, Option<&Inertia>)>,
+    time: Res<Time>,
+) {
+    for (mut velocity, transform, torque, inertia) in query.iter_mut() {
+        let inverse = inertia
+            .copied()
+            .unwrap_or_default()
+            .world_inverse(transform.rotation);
+        if let Some(inverse) = inverse {
+            velocity.angular += inverse * torque.vector * time.delta_secs();
+        }
     }
 }
 
-/// Système : met à jour les positions à partir des vitesses.
-pub fn integrate_positions(mut query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
-    // Intègre la vélocité linéaire dans la position et la vélocité angulaire dans la rotation.
-    for (mut transform, velocity) in query.iter_mut() {
-        // Mise à jour de la position par déplacement linéaire
-        transform.translation += velocity.linear * time.delta_secs();
-        // Mise à jour de la rotation autour de l'axe Y (en radians)
-        transform.rotation = transform.rotation
-            * Quat::from_rotation_y(velocity.angular.y * time.delta_secs() * PI / 180.0);
+/// Système : déplace et fait tourner les entités selon leur vitesse sur `Time`.
+#[deprecated(note = "les positions sont intégrées sur le pas fixe par `DynamicsSet::Integrate`")]
+pub fn integrate_positions(
+    mut query: Query<(&mut Transform, &mut Velocity, Option<&Inertia>)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (mut transform, mut velocity, inertia) in query.iter_mut() {
+        transform.translation += velocity.linear * dt;
+        if velocity.angular == Vec3::ZERO {
+            continue;
+        }
+        transform.rotation = match inertia {
+            Some(inertia) => rotate_rigid(inertia, transform.rotation, &mut velocity.angular, dt),
+            None => {
+                (Quat::from_scaled_axis(velocity.angular * dt) * transform.rotation).normalize()
+            }
+        };
     }
 }
 
@@ -77,7 +269,62 @@ pub fn init(app: &mut App) {
     app.register_type::<Velocity>()
         .register_type::<Mass>()
         .register_type::<Force>()
-        .add_systems(Update, (apply_forces, integrate_positions));
+        .register_type::<Inertia>()
+        .register_type::<Torque>()
+        .register_type::<ForceSource>()
+        .register_type::<ForceAccumulator>()
+        .register_type::<Gravity>()
+        .register_type::<Drag>()
+        .register_type::<Spring>()
+        .register_type::<SpringAnchor>()
+        .register_type::<IntegratorKind>()
+        .register_type::<IntegratorConfig>()
+        .register_type::<Integrator>()
+        .init_resource::<IntegratorConfig>()
+        .init_resource::<Gravity>()
+        .init_resource::<ForceModel>()
+        .configure_sets(
+            FixedUpdate,
+            (
+                DynamicsSet::Forces,
+                DynamicsSet::Integrate,
+                DynamicsSet::Collisions,
+                DynamicsSet::Boundaries,
+            )
+                .chain(),
+        )
+        .add_systems(First, integrator::sync_fixed_timestep)
+        .add_systems(
+            FixedUpdate,
+            (forces::accumulate_forces, forces::apply_vacuum_forces)
+                .chain()
+                .in_set(DynamicsSet::Forces),
+        )
+        .add_systems(
+            FixedUpdate,
+            (integrator::integrate_bodies, integrator::integrate_rotations)
+                .in_set(DynamicsSet::Integrate),
+        )
+        // Conditions aux limites de la topologie du substrat, lorsqu’elle est présente.
+        .add_systems(
+            FixedUpdate,
+            apply_topology
+                .run_if(resource_exists::<DomainTopology>)
+                .in_set(DynamicsSet::Boundaries),
+        );
+
+    nbody::init(app);
+    collision::init(app);
+    coupling::init(app);
+    quantum::init(app);
+    decoherence::init(app);
+    entanglement::init(app);
+    thermostat::init(app);
+    emitter::init(app);
+    energy::init(app);
+
+    let timestep = app.world().resource::<IntegratorConfig>().timestep;
+    app.insert_resource(Time::<Fixed>::from_duration(timestep));
 
     info!(
         target: "dynamics",
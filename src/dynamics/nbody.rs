@@ -0,0 +1,460 @@
+//! Interactions à N corps : gravitation mutuelle et électrostatique.
+//!
+//! Chaque corps massif attire tous les autres (`F = G mᵢ mⱼ / r²`) et les corps portant
+//! une [`Charge`] interagissent selon la loi de Coulomb (`F = k qᵢ qⱼ / r²`, répulsive
+//! entre charges de même signe). Les deux lois sont adoucies par `softening` pour éviter
+//! les singularités à courte distance.
+//!
+//! En mode [`NBodyMode::BarnesHut`], les corps sont rangés dans un [`Octree`] et un nœud
+//! lointain est remplacé par sa masse (ou charge) totale placée en son centre dès que
+//! `taille / distance < theta` : coût en O(n log n). Le mode [`NBodyMode::Direct`] somme
+//! toutes les paires en O(n²) et sert de référence pour valider l’approximation.
+//!
+//! Les forces sont évaluées au début de chaque pas fixe, ajoutées au
+//! [`ForceAccumulator`](super::ForceAccumulator) et tenues constantes pendant le pas.
+
+use bevy::prelude::*;
+use rayon::prelude::*;
+use tracing::debug;
+
+use super::forces::{accumulate_forces, ForceAccumulator, ForceSource};
+use super::{DynamicsSet, Mass};
+use crate::substrate::topology::DomainTopology;
+
+/// Nombre maximal de corps dans une feuille de l’octree.
+const LEAF_CAPACITY: usize = 8;
+/// Profondeur maximale de l’octree (corps confondus).
+const MAX_DEPTH: u32 = 24;
+/// En deçà de ce nombre de corps, l’évaluation reste séquentielle.
+const PARALLEL_THRESHOLD: usize = 256;
+
+/// Composant : charge électrique d’un corps.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Charge {
+    pub value: f32,
+}
+
+/// Méthode d’évaluation des interactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum NBodyMode {
+    /// Approximation de Barnes-Hut, O(n log n).
+    #[default]
+    BarnesHut,
+    /// Somme directe sur toutes les paires, O(n²).
+    Direct,
+}
+
+/// Ressource : paramètres des interactions à N corps.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct NBodyConfig {
+    pub enabled: bool,
+    pub mode: NBodyMode,
+    /// Constante gravitationnelle `G` (0 : pas de gravitation mutuelle).
+    pub gravitational_constant: f32,
+    /// Constante de Coulomb `k` (0 : pas d’électrostatique).
+    pub coulomb_constant: f32,
+    /// Longueur d’adoucissement `ε` : `1/r²` devient `r / (r² + ε²)^{3/2}`.
+    pub softening: f32,
+    /// Angle d’ouverture de Barnes-Hut (0 : exact, plus grand : plus rapide).
+    pub theta: f32,
+    /// Répartit l’évaluation sur le pool rayon.
+    pub parallel: bool,
+}
+
+impl Default for NBodyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: NBodyMode::default(),
+            gravitational_constant: 1.0,
+            coulomb_constant: 1.0,
+            softening: 0.05,
+            theta: 0.5,
+            parallel: true,
+        }
+    }
+}
+
+/// Ressource : bilan du dernier pas d’interactions.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct NBodyStats {
+    /// Corps massifs pris en compte.
+    pub bodies: usize,
+    /// Corps chargés pris en compte.
+    pub charged: usize,
+    /// Nœuds des octrees construits (0 en mode direct).
+    pub tree_nodes: usize,
+    /// Énergie potentielle gravitationnelle totale.
+    pub gravitational_energy: f32,
+    /// Énergie potentielle électrostatique totale.
+    pub electrostatic_energy: f32,
+}
+
+/// Source ponctuelle : position et poids (masse ou charge).
+#[derive(Clone, Copy, Debug)]
+pub struct PointSource {
+    pub position: Vec3,
+    pub weight: f32,
+}
+
+/// Champ `Σ wⱼ dⱼ / (|dⱼ|² + ε²)^{3/2}` (avec `dⱼ = rⱼ - p`) et potentiel
+/// `Σ wⱼ / (|dⱼ|² + ε²)^{1/2}` en un point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FieldSample {
+    pub field: Vec3,
+    pub potential: f32,
+}
+
+impl FieldSample {
+    fn add(&mut self, offset: Vec3, weight: f32, softening2: f32) {
+        let r2 = offset.length_squared() + softening2;
+        if r2 <= f32::EPSILON {
+            return;
+        }
+        let inv = r2.sqrt().recip();
+        self.field += offset * (weight * inv * inv * inv);
+        self.potential += weight * inv;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    center: Vec3,
+    half: f32,
+    /// Poids total et centre pondéré par `|w|`.
+    weight: f32,
+    centroid: Vec3,
+    /// Index du premier des 8 enfants (0 : feuille).
+    children: u32,
+    /// Plage des corps de la feuille dans `Octree::order`.
+    start: u32,
+    len: u32,
+}
+
+/// Octree de Barnes-Hut sur un ensemble de sources ponctuelles.
+pub struct Octree {
+    nodes: Vec<Node>,
+    sources: Vec<PointSource>,
+    order: Vec<u32>,
+}
+
+impl Octree {
+    /// Construit l’octree englobant `sources`.
+    pub fn build(sources: Vec<PointSource>) -> Self {
+        let (min, max) = sources.iter().fold(
+            (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+            |(min, max), s| (min.min(s.position), max.max(s.position)),
+        );
+        let (center, half) = if sources.is_empty() {
+            (Vec3::ZERO, 1.0)
+        } else {
+            (
+                (min + max) * 0.5,
+                ((max - min).max_element() * 0.5).max(1e-3),
+            )
+        };
+
+        let mut tree = Self {
+            nodes: vec![Node {
+                center,
+                half,
+                weight: 0.0,
+                centroid: center,
+                children: 0,
+                start: 0,
+                len: 0,
+            }],
+            order: (0..sources.len() as u32).collect(),
+            sources,
+        };
+        tree.subdivide(0, 0, tree.order.len(), 0);
+        tree
+    }
+
+    /// Nombre de nœuds.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// `true` si l’octree ne contient aucune source.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    fn subdivide(&mut self, index: usize, start: usize, end: usize, depth: u32) {
+        let Node { center, half, .. } = self.nodes[index];
+
+        if end - start <= LEAF_CAPACITY || depth >= MAX_DEPTH {
+            let (weight, moment, abs) = self.order[start..end]
+                .iter()
+                .map(|&i| self.sources[i as usize])
+                .fold((0.0, Vec3::ZERO, 0.0), |(w, m, a), s| {
+                    (
+                        w + s.weight,
+                        m + s.position * s.weight.abs(),
+                        a + s.weight.abs(),
+                    )
+                });
+            let node = &mut self.nodes[index];
+            node.start = start as u32;
+            node.len = (end - start) as u32;
+            node.weight = weight;
+            node.centroid = if abs > 0.0 { moment / abs } else { center };
+            return;
+        }
+
+        let octant = |p: Vec3| {
+            (p.x >= center.x) as usize
+                | ((p.y >= center.y) as usize) << 1
+                | ((p.z >= center.z) as usize) << 2
+        };
+        let sources = &self.sources;
+        self.order[start..end].sort_unstable_by_key(|&i| octant(sources[i as usize].position));
+
+        let first = self.nodes.len();
+        self.nodes[index].children = first as u32;
+        let quarter = half * 0.5;
+        for child in 0..8 {
+            let offset = Vec3::new(
+                if child & 1 != 0 { quarter } else { -quarter },
+                if child & 2 != 0 { quarter } else { -quarter },
+                if child & 4 != 0 { quarter } else { -quarter },
+            );
+            self.nodes.push(Node {
+                center: center + offset,
+                half: quarter,
+                weight: 0.0,
+                centroid: center + offset,
+                children: 0,
+                start: 0,
+                len: 0,
+            });
+        }
+
+        let mut cursor = start;
+        for child in 0..8 {
+            let from = cursor;
+            while cursor < end
+                && octant(self.sources[self.order[cursor] as usize].position) == child
+            {
+                cursor += 1;
+            }
+            self.subdivide(first + child, from, cursor, depth + 1);
+        }
+
+        let (weight, moment, abs) =
+            self.nodes[first..first + 8]
+                .iter()
+                .fold((0.0, Vec3::ZERO, 0.0), |(w, m, a), c| {
+                    (
+                        w + c.weight,
+                        m + c.centroid * c.weight.abs(),
+                        a + c.weight.abs(),
+                    )
+                });
+        let node = &mut self.nodes[index];
+        node.weight = weight;
+        node.centroid = if abs > 0.0 { moment / abs } else { center };
+    }
+
+    /// Champ en `position`, en ignorant la source d’index `skip`.
+    ///
+    /// Un nœud est résumé par son centre si `taille < theta · distance` et si `position`
+    /// est hors de son cube. Le poids total d’un nœud de charges mêlées est placé au
+    /// barycentre des `|q|` (approximation monopolaire).
+    pub fn sample(
+        &self,
+        position: Vec3,
+        skip: Option<usize>,
+        theta: f32,
+        softening: f32,
+        topology: &DomainTopology,
+    ) -> FieldSample {
+        let mut sample = FieldSample::default();
+        if self.sources.is_empty() {
+            return sample;
+        }
+        let softening2 = softening * softening;
+        let mut stack = vec![0usize];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.children == 0 {
+                for &i in &self.order[node.start as usize..(node.start + node.len) as usize] {
+                    if Some(i as usize) == skip {
+                        continue;
+                    }
+                    let source = self.sources[i as usize];
+                    sample.add(
+                        topology.displacement(position, source.position),
+                        source.weight,
+                        softening2,
+                    );
+                }
+                continue;
+            }
+
+            let outside = (position - node.center).abs().max_element() > node.half;
+            let offset = topology.displacement(position, node.centroid);
+            if outside && 2.0 * node.half < theta * offset.length() {
+                sample.add(offset, node.weight, softening2);
+            } else {
+                let first = node.children as usize;
+                stack.extend(first..first + 8);
+            }
+        }
+        sample
+    }
+}
+
+/// Champ exact en `position` par somme directe, en ignorant la source d’index `skip`.
+pub fn sample_direct(
+    sources: &[PointSource],
+    position: Vec3,
+    skip: Option<usize>,
+    softening: f32,
+    topology: &DomainTopology,
+) -> FieldSample {
+    let softening2 = softening * softening;
+    let mut sample = FieldSample::default();
+    for (i, source) in sources.iter().enumerate() {
+        if Some(i) != skip {
+            sample.add(
+                topology.displacement(position, source.position),
+                source.weight,
+                softening2,
+            );
+        }
+    }
+    sample
+}
+
+/// Évalue le champ de `sources` en chacune d’elles (auto-interaction exclue).
+fn sample_all(
+    sources: Vec<PointSource>,
+    config: &NBodyConfig,
+    topology: &DomainTopology,
+) -> (Vec<FieldSample>, usize) {
+    let positions: Vec<Vec3> = sources.iter().map(|s| s.position).collect();
+    let parallel = config.parallel && positions.len() >= PARALLEL_THRESHOLD;
+
+    match config.mode {
+        NBodyMode::Direct => {
+            let eval = |(i, p): (usize, &Vec3)| {
+                sample_direct(&sources, *p, Some(i), config.softening, topology)
+            };
+            let samples = if parallel {
+                positions.par_iter().enumerate().map(eval).collect()
+            } else {
+                positions.iter().enumerate().map(eval).collect()
+            };
+            (samples, 0)
+        }
+        NBodyMode::BarnesHut => {
+            let tree = Octree::build(sources);
+            let eval = |(i, p): (usize, &Vec3)| {
+                tree.sample(*p, Some(i), config.theta, config.softening, topology)
+            };
+            let samples = if parallel {
+                positions.par_iter().enumerate().map(eval).collect()
+            } else {
+                positions.iter().enumerate().map(eval).collect()
+            };
+            (samples, tree.len())
+        }
+    }
+}
+
+/// Système : ajoute la gravitation mutuelle et les forces de Coulomb aux accumulateurs.
+pub fn compute_nbody_forces(
+    mut bodies: Query<(&Transform, &Mass, Option<&Charge>, &mut ForceAccumulator)>,
+    config: Res<NBodyConfig>,
+    topology: Option<Res<DomainTopology>>,
+    mut stats: ResMut<NBodyStats>,
+) {
+    if !config.enabled {
+        if stats.bodies != 0 || stats.charged != 0 {
+            *stats = NBodyStats::default();
+        }
+        return;
+    }
+    let topology = topology.as_deref().cloned().unwrap_or_default();
+    let mut total = NBodyStats::default();
+
+    if config.gravitational_constant != 0.0 {
+        let sources: Vec<PointSource> = bodies
+            .iter()
+            .map(|(transform, mass, ..)| PointSource {
+                position: transform.translation,
+                weight: mass.value,
+            })
+            .collect();
+        total.bodies = sources.len();
+        let (samples, nodes) = sample_all(sources, &config, &topology);
+        total.tree_nodes += nodes;
+
+        let g = config.gravitational_constant;
+        for ((_, mass, _, mut accumulator), sample) in bodies.iter_mut().zip(samples) {
+            accumulator.add(ForceSource::Gravitation, g * mass.value * sample.field);
+            // Chaque paire est comptée deux fois.
+            total.gravitational_energy -= 0.5 * g * mass.value * sample.potential;
+        }
+    }
+
+    if config.coulomb_constant != 0.0 {
+        let sources: Vec<PointSource> = bodies
+            .iter()
+            .filter_map(|(transform, _, charge, _)| {
+                charge.filter(|c| c.value != 0.0).map(|c| PointSource {
+                    position: transform.translation,
+                    weight: c.value,
+                })
+            })
+            .collect();
+        total.charged = sources.len();
+        if sources.len() > 1 {
+            let (samples, nodes) = sample_all(sources, &config, &topology);
+            total.tree_nodes += nodes;
+
+            let k = config.coulomb_constant;
+            let charged = bodies.iter_mut().filter_map(|(_, _, charge, accumulator)| {
+                charge
+                    .filter(|c| c.value != 0.0)
+                    .map(|c| (c.value, accumulator))
+            });
+            for ((charge, mut accumulator), sample) in charged.zip(samples) {
+                // Le champ pointe vers les sources : les charges de même signe se repoussent.
+                accumulator.add(ForceSource::Electrostatic, -k * charge * sample.field);
+                total.electrostatic_energy += 0.5 * k * charge * sample.potential;
+            }
+        }
+    }
+
+    debug!(
+        target: "dynamics",
+        bodies = total.bodies,
+        charged = total.charged,
+        nodes = total.tree_nodes,
+        "interactions à N corps évaluées"
+    );
+    *stats = total;
+}
+
+/// Enregistre les interactions à N corps.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<Charge>()
+        .register_type::<NBodyMode>()
+        .register_type::<NBodyConfig>()
+        .register_type::<NBodyStats>()
+        .init_resource::<NBodyConfig>()
+        .init_resource::<NBodyStats>()
+        .add_systems(
+            FixedUpdate,
+            compute_nbody_forces
+                .in_set(DynamicsSet::Forces)
+                .after(accumulate_forces),
+        );
+}
@@ -0,0 +1,966 @@
+//! Simulation d’états quantiques purs par vecteur d’état.
+//!
+//! Un [`QuantumRegister`] porte l’état `|ψ⟩` d’un système composite de sous-systèmes de
+//! dimensions quelconques (qubits, qutrits, …), rangé en base canonique : le premier
+//! sous-système est le chiffre de poids fort. Les amplitudes sont des complexes `f64`
+//! (nalgebra).
+//!
+//! - Les portes ([`Gate`]) sont des unitaires appliqués localement à quelques
+//!   sous-systèmes, sans construire l’opérateur sur tout l’espace.
+//! - Un [`QuantumHamiltonian`] fait évoluer l’état à chaque pas fixe par
+//!   `U(t) = exp(-i H t)` (ħ = 1), calculé par diagonalisation de `H`.
+//! - Une mesure projective est demandée par le message [`MeasureRequest`] ; son issue est
+//!   tirée du flux `dynamics::quantum` de l’[`EngineSeed`], l’état est réduit et le
+//!   résultat publié par [`MeasurementOutcome`].
+//! - Les valeurs moyennes des [`QuantumObservables`] (par défaut `⟨Z⟩` de chaque qubit et
+//!   le niveau moyen `⟨n⟩` des autres sous-systèmes) sont mises à jour dans
+//!   [`QuantumExpectations`] et enregistrées dans la mémoire, y compris pour les registres
+//!   mixtes ([`DensityRegister`]).
+
+use bevy::prelude::*;
+use nalgebra::{Complex, DMatrix, DVector, SymmetricEigen};
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde_json::{json, Map, Value};
+use tracing::{debug, info};
+
+use super::decoherence::DensityRegister;
+use super::DynamicsSet;
+use crate::core::{EngineSeed, MemoryField};
+
+/// Amplitude complexe.
+pub type C64 = Complex<f64>;
+
+/// Tolérance des contrôles d’unitarité, d’hermiticité et de normalisation.
+const TOLERANCE: f64 = 1e-9;
+
+const ZERO: C64 = C64::new(0.0, 0.0);
+const ONE: C64 = C64::new(1.0, 0.0);
+const I: C64 = C64::new(0.0, 1.0);
+
+/// Erreur de construction ou de manipulation d’un état quantique.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantumError {
+    /// Dimension d’un sous-système nulle, ou registre sans sous-système.
+    InvalidDimensions(Vec<usize>),
+    /// Le nombre d’amplitudes ne correspond pas aux dimensions.
+    DimensionMismatch { expected: usize, found: usize },
+    /// Sous-système inexistant ou cité deux fois.
+    InvalidTargets(Vec<usize>),
+    /// Vecteur d’état de norme nulle.
+    ZeroNorm,
+    /// Opérateur censé être unitaire qui ne l’est pas.
+    NotUnitary,
+    /// Opérateur censé être hermitien qui ne l’est pas.
+    NotHermitian,
+}
+
+impl std::fmt::Display for QuantumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDimensions(dims) => write!(f, "invalid subsystem dimensions {dims:?}"),
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "expected dimension {expected}, found {found}")
+            }
+            Self::InvalidTargets(targets) => write!(f, "invalid target subsystems {targets:?}"),
+            Self::ZeroNorm => write!(f, "state vector has zero norm"),
+            Self::NotUnitary => write!(f, "operator is not unitary"),
+            Self::NotHermitian => write!(f, "operator is not hermitian"),
+        }
+    }
+}
+
+impl std::error::Error for QuantumError {}
+
+/// Agencement d’un espace composite : dimensions et pas de chaque sous-système.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Layout {
+    pub dims: Vec<usize>,
+    pub strides: Vec<usize>,
+}
+
+impl Layout {
+    pub fn new(dims: &[usize]) -> Result<Self, QuantumError> {
+        if dims.is_empty() || dims.contains(&0) {
+            return Err(QuantumError::InvalidDimensions(dims.to_vec()));
+        }
+        let mut strides = vec![1; dims.len()];
+        for k in (0..dims.len() - 1).rev() {
+            strides[k] = strides[k + 1] * dims[k + 1];
+        }
+        Ok(Self {
+            dims: dims.to_vec(),
+            strides,
+        })
+    }
+
+    /// Dimension totale.
+    pub fn dimension(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Chiffre du sous-système `k` dans l’indice de base `index`.
+    pub fn digit(&self, index: usize, k: usize) -> usize {
+        (index / self.strides[k]) % self.dims[k]
+    }
+
+    /// Vérifie `targets` et renvoie la dimension du sous-espace qu’ils engendrent.
+    pub fn local_dimension(&self, targets: &[usize]) -> Result<usize, QuantumError> {
+        let mut seen = vec![false; self.dims.len()];
+        for &t in targets {
+            if t >= self.dims.len() || std::mem::replace(&mut seen[t], true) {
+                return Err(QuantumError::InvalidTargets(targets.to_vec()));
+            }
+        }
+        if targets.is_empty() {
+            return Err(QuantumError::InvalidTargets(Vec::new()));
+        }
+        Ok(targets.iter().map(|&t| self.dims[t]).product())
+    }
+
+    /// Indice local (dans l’ordre de `targets`) de l’indice de base `index`.
+    pub fn local_index(&self, index: usize, targets: &[usize]) -> usize {
+        targets
+            .iter()
+            .fold(0, |local, &t| local * self.dims[t] + self.digit(index, t))
+    }
+
+    /// Niveaux de chaque sous-système de `targets` pour l’indice local `local`.
+    pub fn levels(&self, local: usize, targets: &[usize]) -> Vec<usize> {
+        let mut levels = vec![0; targets.len()];
+        let mut rest = local;
+        for (level, &t) in levels.iter_mut().zip(targets).rev() {
+            *level = rest % self.dims[t];
+            rest /= self.dims[t];
+        }
+        levels
+    }
+
+    /// Décalages, dans l’espace total, des états de base du sous-espace de `targets`.
+    pub fn local_offsets(&self, targets: &[usize]) -> Vec<usize> {
+        let mut offsets = vec![0];
+        for &t in targets {
+            offsets = offsets
+                .iter()
+                .flat_map(|&o| (0..self.dims[t]).map(move |d| o + d * self.strides[t]))
+                .collect();
+        }
+        offsets
+    }
+
+    /// Indices de base dont les chiffres de `targets` sont nuls.
+    pub fn bases<'a>(&'a self, targets: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+        (0..self.dimension()).filter(move |&i| targets.iter().all(|&t| self.digit(i, t) == 0))
+    }
+}
+
+/// Tire une issue selon les poids `probabilities` : renvoie son indice et sa probabilité
+/// normalisée.
+pub(crate) fn draw_outcome(
+    probabilities: &[f64],
+    rng: &mut impl Rng,
+) -> Result<(usize, f64), QuantumError> {
+    let total: f64 = probabilities.iter().sum();
+    if total <= f64::EPSILON {
+        return Err(QuantumError::ZeroNorm);
+    }
+    let mut draw = rng.random::<f64>() * total;
+    let mut outcome = probabilities.len() - 1;
+    for (l, p) in probabilities.iter().enumerate() {
+        if draw < *p {
+            outcome = l;
+            break;
+        }
+        draw -= p;
+    }
+    Ok((outcome, probabilities[outcome] / total))
+}
+
+/// Applique `op` aux sous-systèmes `targets` du vecteur `amplitudes`.
+fn apply_local(
+    layout: &Layout,
+    amplitudes: &mut DVector<C64>,
+    op: &DMatrix<C64>,
+    targets: &[usize],
+) {
+    let offsets = layout.local_offsets(targets);
+    let mut local = DVector::zeros(offsets.len());
+    for base in layout.bases(targets).collect::<Vec<_>>() {
+        for (l, &o) in offsets.iter().enumerate() {
+            local[l] = amplitudes[base + o];
+        }
+        let image = op * &local;
+        for (l, &o) in offsets.iter().enumerate() {
+            amplitudes[base + o] = image[l];
+        }
+    }
+}
+
+/// Étend `op`, agissant sur `targets`, à tout l’espace décrit par `dims`.
+pub fn embed(
+    op: &DMatrix<C64>,
+    targets: &[usize],
+    dims: &[usize],
+) -> Result<DMatrix<C64>, QuantumError> {
+    let layout = Layout::new(dims)?;
+    check_operator(&layout, op, targets)?;
+    let n = layout.dimension();
+    let mut full = DMatrix::zeros(n, n);
+    for j in 0..n {
+        let mut column = DVector::zeros(n);
+        column[j] = ONE;
+        apply_local(&layout, &mut column, op, targets);
+        full.set_column(j, &column);
+    }
+    Ok(full)
+}
+
+fn check_operator(
+    layout: &Layout,
+    op: &DMatrix<C64>,
+    targets: &[usize],
+) -> Result<(), QuantumError> {
+    let expected = layout.local_dimension(targets)?;
+    if op.nrows() != expected || op.ncols() != expected {
+        return Err(QuantumError::DimensionMismatch {
+            expected,
+            found: op.nrows(),
+        });
+    }
+    Ok(())
+}
+
+fn is_hermitian(m: &DMatrix<C64>) -> bool {
+    m.is_square() && (m - m.adjoint()).norm() < TOLERANCE * m.nrows().max(1) as f64
+}
+
+/// État pur d’un système composite.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumState {
+    layout: Layout,
+    amplitudes: DVector<C64>,
+}
+
+impl Default for QuantumState {
+    /// Un qubit dans l’état `|0⟩`.
+    fn default() -> Self {
+        Self::qubits(1)
+    }
+}
+
+impl QuantumState {
+    /// État fondamental `|0…0⟩` des sous-systèmes de dimensions `dims`.
+    pub fn new(dims: &[usize]) -> Result<Self, QuantumError> {
+        let layout = Layout::new(dims)?;
+        let mut amplitudes = DVector::zeros(layout.dimension());
+        amplitudes[0] = ONE;
+        Ok(Self { layout, amplitudes })
+    }
+
+    /// Registre de `n` qubits dans l’état `|0…0⟩`.
+    pub fn qubits(n: usize) -> Self {
+        Self::new(&vec![2; n.max(1)]).expect("qubit register dimensions are valid")
+    }
+
+    /// État d’amplitudes `amplitudes`, normalisé.
+    pub fn from_amplitudes(dims: &[usize], amplitudes: DVector<C64>) -> Result<Self, QuantumError> {
+        let layout = Layout::new(dims)?;
+        if amplitudes.len() != layout.dimension() {
+            return Err(QuantumError::DimensionMismatch {
+                expected: layout.dimension(),
+                found: amplitudes.len(),
+            });
+        }
+        let mut state = Self { layout, amplitudes };
+        state.normalize()?;
+        Ok(state)
+    }
+
+    /// État de base dont le sous-système `k` est au niveau `levels[k]`.
+    pub fn basis(dims: &[usize], levels: &[usize]) -> Result<Self, QuantumError> {
+        let layout = Layout::new(dims)?;
+        if levels.len() != dims.len() || levels.iter().zip(dims).any(|(l, d)| l >= d) {
+            return Err(QuantumError::InvalidTargets(levels.to_vec()));
+        }
+        let index = levels
+            .iter()
+            .zip(&layout.strides)
+            .map(|(l, s)| l * s)
+            .sum::<usize>();
+        let mut amplitudes = DVector::zeros(layout.dimension());
+        amplitudes[index] = ONE;
+        Ok(Self { layout, amplitudes })
+    }
+
+    /// Dimensions des sous-systèmes.
+    pub fn dims(&self) -> &[usize] {
+        &self.layout.dims
+    }
+
+    /// Nombre de sous-systèmes.
+    pub fn subsystems(&self) -> usize {
+        self.layout.dims.len()
+    }
+
+    /// Dimension de l’espace de Hilbert.
+    pub fn dimension(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// Amplitudes en base canonique.
+    pub fn amplitudes(&self) -> &DVector<C64> {
+        &self.amplitudes
+    }
+
+    pub(crate) fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Norme `‖ψ‖`.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes.norm()
+    }
+
+    /// Ramène l’état à une norme unité.
+    pub fn normalize(&mut self) -> Result<(), QuantumError> {
+        let norm = self.norm();
+        if norm <= f64::EPSILON {
+            return Err(QuantumError::ZeroNorm);
+        }
+        self.amplitudes.unscale_mut(norm);
+        Ok(())
+    }
+
+    /// Probabilités des états de base.
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).collect()
+    }
+
+    /// Probabilités des issues d’une mesure des sous-systèmes `targets`.
+    pub fn marginal(&self, targets: &[usize]) -> Result<Vec<f64>, QuantumError> {
+        let mut probabilities = vec![0.0; self.layout.local_dimension(targets)?];
+        for (index, a) in self.amplitudes.iter().enumerate() {
+            probabilities[self.layout.local_index(index, targets)] += a.norm_sqr();
+        }
+        Ok(probabilities)
+    }
+
+    /// Applique la porte `gate` aux sous-systèmes `targets` (dans l’ordre de la porte).
+    pub fn apply(&mut self, gate: &Gate, targets: &[usize]) -> Result<(), QuantumError> {
+        check_operator(&self.layout, &gate.matrix, targets)?;
+        apply_local(&self.layout, &mut self.amplitudes, &gate.matrix, targets);
+        Ok(())
+    }
+
+    /// Applique un unitaire agissant sur tout l’espace.
+    pub fn apply_unitary(&mut self, unitary: &DMatrix<C64>) -> Result<(), QuantumError> {
+        if unitary.nrows() != self.dimension() || unitary.ncols() != self.dimension() {
+            return Err(QuantumError::DimensionMismatch {
+                expected: self.dimension(),
+                found: unitary.nrows(),
+            });
+        }
+        self.amplitudes = unitary * &self.amplitudes;
+        Ok(())
+    }
+
+    /// Valeur moyenne `⟨ψ|O|ψ⟩` de l’opérateur `op` agissant sur `targets`.
+    pub fn expectation(&self, op: &DMatrix<C64>, targets: &[usize]) -> Result<f64, QuantumError> {
+        check_operator(&self.layout, op, targets)?;
+        let mut image = self.amplitudes.clone();
+        apply_local(&self.layout, &mut image, op, targets);
+        Ok(self.amplitudes.dotc(&image).re)
+    }
+
+    /// Mesure projective des sous-systèmes `targets` : renvoie les niveaux obtenus (dans
+    /// l’ordre de `targets`) et leur probabilité, et réduit l’état.
+    pub fn measure(
+        &mut self,
+        targets: &[usize],
+        rng: &mut impl Rng,
+    ) -> Result<(Vec<usize>, f64), QuantumError> {
+        let (outcome, probability) = draw_outcome(&self.marginal(targets)?, rng)?;
+        for index in 0..self.dimension() {
+            if self.layout.local_index(index, targets) != outcome {
+                self.amplitudes[index] = ZERO;
+            }
+        }
+        self.normalize()?;
+        Ok((self.layout.levels(outcome, targets), probability))
+    }
+}
+
+/// Porte quantique : opérateur unitaire sur un ou plusieurs sous-systèmes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gate {
+    matrix: DMatrix<C64>,
+}
+
+impl Gate {
+    /// Porte de matrice `matrix`, dont l’unitarité est vérifiée.
+    pub fn new(matrix: DMatrix<C64>) -> Result<Self, QuantumError> {
+        let n = matrix.nrows();
+        if !matrix.is_square()
+            || (matrix.adjoint() * &matrix - DMatrix::identity(n, n)).norm() > TOLERANCE * n as f64
+        {
+            return Err(QuantumError::NotUnitary);
+        }
+        Ok(Self { matrix })
+    }
+
+    fn real(n: usize, entries: &[f64]) -> Self {
+        Self {
+            matrix: DMatrix::from_row_iterator(n, n, entries.iter().map(|&x| C64::new(x, 0.0))),
+        }
+    }
+
+    /// Matrice de la porte.
+    pub fn matrix(&self) -> &DMatrix<C64> {
+        &self.matrix
+    }
+
+    /// Identité sur un sous-système de dimension `d`.
+    pub fn identity(d: usize) -> Self {
+        Self {
+            matrix: DMatrix::identity(d, d),
+        }
+    }
+
+    /// Pauli X (NON quantique).
+    pub fn x() -> Self {
+        Self::real(2, &[0.0, 1.0, 1.0, 0.0])
+    }
+
+    /// Pauli Y.
+    pub fn y() -> Self {
+        Self {
+            matrix: DMatrix::from_row_slice(2, 2, &[ZERO, -I, I, ZERO]),
+        }
+    }
+
+    /// Pauli Z.
+    pub fn z() -> Self {
+        Self::real(2, &[1.0, 0.0, 0.0, -1.0])
+    }
+
+    /// Hadamard.
+    pub fn h() -> Self {
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        Self::real(2, &[s, s, s, -s])
+    }
+
+    /// Déphasage `diag(1, e^{iφ})`.
+    pub fn phase(phi: f64) -> Self {
+        Self {
+            matrix: DMatrix::from_diagonal(&DVector::from_vec(vec![
+                ONE,
+                C64::from_polar(1.0, phi),
+            ])),
+        }
+    }
+
+    /// Porte S (`φ = π/2`).
+    pub fn s() -> Self {
+        Self::phase(std::f64::consts::FRAC_PI_2)
+    }
+
+    /// Porte T (`φ = π/4`).
+    pub fn t() -> Self {
+        Self::phase(std::f64::consts::FRAC_PI_4)
+    }
+
+    /// Rotation `exp(-i θ X / 2)`.
+    pub fn rx(theta: f64) -> Self {
+        let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+        Self {
+            matrix: DMatrix::from_row_slice(
+                2,
+                2,
+                &[
+                    C64::new(c, 0.0),
+                    C64::new(0.0, -s),
+                    C64::new(0.0, -s),
+                    C64::new(c, 0.0),
+                ],
+            ),
+        }
+    }
+
+    /// Rotation `exp(-i θ Y / 2)`.
+    pub fn ry(theta: f64) -> Self {
+        let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+        Self::real(2, &[c, -s, s, c])
+    }
+
+    /// Rotation `exp(-i θ Z / 2)`.
+    pub fn rz(theta: f64) -> Self {
+        Self {
+            matrix: DMatrix::from_diagonal(&DVector::from_vec(vec![
+                C64::from_polar(1.0, -theta / 2.0),
+                C64::from_polar(1.0, theta / 2.0),
+            ])),
+        }
+    }
+
+    /// Version contrôlée de `gate` : le premier sous-système (qubit) contrôle les suivants.
+    pub fn controlled(gate: &Gate) -> Self {
+        let n = gate.matrix.nrows();
+        let mut matrix = DMatrix::identity(2 * n, 2 * n);
+        matrix.view_mut((n, n), (n, n)).copy_from(&gate.matrix);
+        Self { matrix }
+    }
+
+    /// NON contrôlé (contrôle, cible).
+    pub fn cnot() -> Self {
+        Self::controlled(&Self::x())
+    }
+
+    /// Z contrôlé.
+    pub fn cz() -> Self {
+        Self::controlled(&Self::z())
+    }
+
+    /// Échange de deux sous-systèmes de dimension `d`.
+    pub fn swap(d: usize) -> Self {
+        let mut matrix = DMatrix::zeros(d * d, d * d);
+        for a in 0..d {
+            for b in 0..d {
+                matrix[(b * d + a, a * d + b)] = ONE;
+            }
+        }
+        Self { matrix }
+    }
+
+    /// Décalage cyclique `|k⟩ → |k + 1 mod d⟩` (X généralisé).
+    pub fn shift(d: usize) -> Self {
+        let mut matrix = DMatrix::zeros(d, d);
+        for k in 0..d {
+            matrix[((k + 1) % d, k)] = ONE;
+        }
+        Self { matrix }
+    }
+
+    /// Horloge `|k⟩ → e^{2iπk/d} |k⟩` (Z généralisé).
+    pub fn clock(d: usize) -> Self {
+        let omega = std::f64::consts::TAU / d as f64;
+        Self {
+            matrix: DMatrix::from_diagonal(&DVector::from_fn(d, |k, _| {
+                C64::from_polar(1.0, omega * k as f64)
+            })),
+        }
+    }
+
+    /// Transformée de Fourier quantique sur un sous-système de dimension `d`.
+    pub fn fourier(d: usize) -> Self {
+        let omega = std::f64::consts::TAU / d as f64;
+        let norm = 1.0 / (d as f64).sqrt();
+        Self {
+            matrix: DMatrix::from_fn(d, d, |j, k| C64::from_polar(norm, omega * (j * k) as f64)),
+        }
+    }
+}
+
+/// Hamiltonien d’un registre, diagonalisé à la construction.
+#[derive(Clone, Debug)]
+pub struct Hamiltonian {
+    matrix: DMatrix<C64>,
+    energies: DVector<f64>,
+    eigenvectors: DMatrix<C64>,
+}
+
+impl Hamiltonian {
+    /// Hamiltonien de matrice `matrix` (hermitienne) sur tout l’espace.
+    ///
+    /// `SymmetricEigen` traite les matrices hermitiennes complexes : les vecteurs propres
+    /// forment une base unitaire, donc [`Hamiltonian::propagator`] est unitaire.
+    pub fn new(matrix: DMatrix<C64>) -> Result<Self, QuantumError> {
+        if !is_hermitian(&matrix) {
+            return Err(QuantumError::NotHermitian);
+        }
+        let eigen = SymmetricEigen::new(matrix.clone());
+        Ok(Self {
+            matrix,
+            energies: eigen.eigenvalues,
+            eigenvectors: eigen.eigenvectors,
+        })
+    }
+
+    /// Somme de termes locaux `cₖ Oₖ` agissant sur les sous-systèmes `targetsₖ` d’un
+    /// registre de dimensions `dims`.
+    pub fn from_terms(
+        dims: &[usize],
+        terms: &[(f64, DMatrix<C64>, Vec<usize>)],
+    ) -> Result<Self, QuantumError> {
+        let n = Layout::new(dims)?.dimension();
+        let mut matrix = DMatrix::zeros(n, n);
+        for (coefficient, op, targets) in terms {
+            matrix += embed(op, targets, dims)? * C64::new(*coefficient, 0.0);
+        }
+        Self::new(matrix)
+    }
+
+    /// Matrice du hamiltonien.
+    pub fn matrix(&self) -> &DMatrix<C64> {
+        &self.matrix
+    }
+
+    /// Niveaux d’énergie, par ordre croissant d’indice propre.
+    pub fn energies(&self) -> &DVector<f64> {
+        &self.energies
+    }
+
+    /// Propagateur `U(t) = exp(-i H t)`.
+    pub fn propagator(&self, t: f64) -> DMatrix<C64> {
+        let phases = self.energies.map(|e| C64::from_polar(1.0, -e * t));
+        &self.eigenvectors * DMatrix::from_diagonal(&phases) * self.eigenvectors.adjoint()
+    }
+
+    /// Énergie moyenne `⟨ψ|H|ψ⟩`.
+    pub fn energy(&self, state: &QuantumState) -> f64 {
+        state
+            .amplitudes
+            .dotc(&(&self.matrix * &state.amplitudes))
+            .re
+    }
+}
+
+/// Observable nommée : opérateur hermitien sur quelques sous-systèmes.
+#[derive(Clone, Debug)]
+pub struct Observable {
+    pub name: String,
+    operator: DMatrix<C64>,
+    targets: Vec<usize>,
+}
+
+impl Observable {
+    /// Observable `operator` (hermitien) agissant sur `targets`.
+    pub fn new(
+        name: impl Into<String>,
+        operator: DMatrix<C64>,
+        targets: Vec<usize>,
+    ) -> Result<Self, QuantumError> {
+        if !is_hermitian(&operator) {
+            return Err(QuantumError::NotHermitian);
+        }
+        Ok(Self {
+            name: name.into(),
+            operator,
+            targets,
+        })
+    }
+
+    /// `⟨Z⟩` du qubit `target`, nommée `Z<target>`.
+    pub fn pauli_z(target: usize) -> Self {
+        Self {
+            name: format!("Z{target}"),
+            operator: Gate::z().matrix,
+            targets: vec![target],
+        }
+    }
+
+    /// Niveau `n` d’un sous-système de dimension `d`, nommée `N<target>`.
+    pub fn number(target: usize, d: usize) -> Self {
+        Self {
+            name: format!("N{target}"),
+            operator: DMatrix::from_diagonal(&DVector::from_fn(d, |k, _| C64::new(k as f64, 0.0))),
+            targets: vec![target],
+        }
+    }
+
+    /// Observables par défaut d’un registre de dimensions `dims`.
+    pub fn defaults(dims: &[usize]) -> Vec<Self> {
+        dims.iter()
+            .enumerate()
+            .map(|(k, &d)| {
+                if d == 2 {
+                    Self::pauli_z(k)
+                } else {
+                    Self::number(k, d)
+                }
+            })
+            .collect()
+    }
+
+    pub fn operator(&self) -> &DMatrix<C64> {
+        &self.operator
+    }
+
+    pub fn targets(&self) -> &[usize] {
+        &self.targets
+    }
+
+    /// Valeur moyenne dans `state`.
+    pub fn expectation(&self, state: &QuantumState) -> Result<f64, QuantumError> {
+        state.expectation(&self.operator, &self.targets)
+    }
+}
+
+/// Composant : registre quantique attaché à une entité.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct QuantumRegister {
+    #[reflect(ignore)]
+    pub state: QuantumState,
+}
+
+impl QuantumRegister {
+    pub fn new(state: QuantumState) -> Self {
+        Self { state }
+    }
+
+    /// Registre de `n` qubits dans l’état `|0…0⟩`.
+    pub fn qubits(n: usize) -> Self {
+        Self::new(QuantumState::qubits(n))
+    }
+}
+
+/// Composant : hamiltonien faisant évoluer le [`QuantumRegister`] de l’entité.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct QuantumHamiltonian {
+    #[reflect(ignore)]
+    pub hamiltonian: Option<Hamiltonian>,
+    /// Propagateur du dernier pas, réutilisé tant que le pas ne change pas.
+    #[reflect(ignore)]
+    cache: Option<(f64, DMatrix<C64>)>,
+}
+
+impl QuantumHamiltonian {
+    pub fn new(hamiltonian: Hamiltonian) -> Self {
+        Self {
+            hamiltonian: Some(hamiltonian),
+            cache: None,
+        }
+    }
+
+    fn propagator(&mut self, t: f64) -> Option<&DMatrix<C64>> {
+        let hamiltonian = self.hamiltonian.as_ref()?;
+        if self.cache.as_ref().is_none_or(|(cached, _)| *cached != t) {
+            self.cache = Some((t, hamiltonian.propagator(t)));
+        }
+        self.cache.as_ref().map(|(_, u)| u)
+    }
+}
+
+/// Composant : observables suivies sur le registre (à défaut, [`Observable::defaults`]).
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct QuantumObservables {
+    #[reflect(ignore)]
+    pub observables: Vec<Observable>,
+}
+
+/// Composant : valeurs moyennes des observables au dernier pas.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct QuantumExpectations {
+    pub values: Vec<(String, f64)>,
+}
+
+impl QuantumExpectations {
+    /// Valeur moyenne de l’observable `name`.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values.iter().find(|(n, _)| n == name).map(|(_, v)| *v)
+    }
+}
+
+/// Ressource : paramètres de la simulation quantique.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct QuantumConfig {
+    /// Temps quantique écoulé par seconde simulée.
+    pub time_scale: f64,
+    /// Enregistre les valeurs moyennes dans la mémoire (échantillonnées, voir
+    /// [`MemoryField::record_sampled`]).
+    pub record_snapshots: bool,
+}
+
+impl Default for QuantumConfig {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            record_snapshots: true,
+        }
+    }
+}
+
+/// Ressource : générateur des issues de mesure.
+#[derive(Resource)]
+pub struct QuantumRng(pub StdRng);
+
+/// Message : demande de mesure projective des sous-systèmes `targets` (tous si vide).
+#[derive(Message, Clone, Debug)]
+pub struct MeasureRequest {
+    pub entity: Entity,
+    pub targets: Vec<usize>,
+}
+
+/// Message : issue d’une mesure.
+#[derive(Message, Clone, Debug)]
+pub struct MeasurementOutcome {
+    pub entity: Entity,
+    pub targets: Vec<usize>,
+    /// Niveau obtenu pour chaque sous-système mesuré.
+    pub levels: Vec<usize>,
+    /// Probabilité de cette issue avant la mesure.
+    pub probability: f64,
+}
+
+/// Système (Startup) : ensemence le générateur de mesure à partir de la graine moteur.
+pub fn seed_quantum(mut commands: Commands, seed: Option<Res<EngineSeed>>) {
+    let seed = seed.as_deref().copied().unwrap_or_default();
+    commands.insert_resource(QuantumRng(seed.rng("dynamics::quantum")));
+}
+
+/// Système : fait évoluer les registres sous leur hamiltonien pendant le pas fixe.
+pub fn evolve_quantum(
+    mut registers: Query<(&mut QuantumRegister, &mut QuantumHamiltonian)>,
+    config: Res<QuantumConfig>,
+    time: Res<Time>,
+) {
+    let t = time.delta_secs_f64() * config.time_scale;
+    if t == 0.0 {
+        return;
+    }
+    for (mut register, mut hamiltonian) in registers.iter_mut() {
+        if let Some(u) = hamiltonian.propagator(t) {
+            if let Err(err) = register.state.apply_unitary(u) {
+                debug!(target: "dynamics", %err, "hamiltonien incompatible avec le registre");
+            }
+        }
+    }
+}
+
+/// Système : effectue les mesures demandées, sur les registres purs ou mixtes, et publie leurs
+/// issues.
+pub fn measure_quantum(
+    mut requests: MessageReader<MeasureRequest>,
+    mut outcomes: MessageWriter<MeasurementOutcome>,
+    mut registers: Query<AnyOf<(&mut QuantumRegister, &mut DensityRegister)>>,
+    rng: Option<ResMut<QuantumRng>>,
+) {
+    let Some(mut rng) = rng else {
+        return;
+    };
+    for request in requests.read() {
+        let Ok((pure, mixed)) = registers.get_mut(request.entity) else {
+            continue;
+        };
+        let subsystems = match (&pure, &mixed) {
+            (Some(register), _) => register.state.subsystems(),
+            (None, Some(register)) => register.state.subsystems(),
+            (None, None) => continue,
+        };
+        let targets = if request.targets.is_empty() {
+            (0..subsystems).collect()
+        } else {
+            request.targets.clone()
+        };
+        let measured = match (pure, mixed) {
+            (Some(mut register), _) => register.state.measure(&targets, &mut rng.0),
+            (None, Some(mut register)) => register.state.measure(&targets, &mut rng.0),
+            (None, None) => continue,
+        };
+        match measured {
+            Ok((levels, probability)) => {
+                outcomes.write(MeasurementOutcome {
+                    entity: request.entity,
+                    targets,
+                    levels,
+                    probability,
+                });
+            }
+            Err(err) => debug!(target: "dynamics", %err, "mesure impossible"),
+        }
+    }
+}
+
+/// Registres dont les valeurs moyennes sont suivies, purs ou mixtes.
+type ExpectationQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        AnyOf<(&'static QuantumRegister, &'static DensityRegister)>,
+        Option<&'static QuantumObservables>,
+        Option<&'static mut QuantumExpectations>,
+    ),
+>;
+
+/// Système : met à jour les valeurs moyennes et les enregistre dans la mémoire.
+pub fn record_expectations(
+    mut commands: Commands,
+    mut registers: ExpectationQuery,
+    config: Res<QuantumConfig>,
+    mut memory: ResMut<MemoryField>,
+) {
+    let mut snapshot = Map::new();
+    for (entity, (pure, mixed), observables, expectations) in registers.iter_mut() {
+        let dims = match (pure, mixed) {
+            (Some(pure), _) => pure.state.dims(),
+            (None, Some(mixed)) => mixed.state.dims(),
+            (None, None) => continue,
+        };
+        let defaults;
+        let observables = match observables {
+            Some(observables) => &observables.observables,
+            None => {
+                defaults = Observable::defaults(dims);
+                &defaults
+            }
+        };
+        let values: Vec<(String, f64)> = observables
+            .iter()
+            .filter_map(|o| {
+                let value = match (pure, mixed) {
+                    (Some(pure), _) => o.expectation(&pure.state),
+                    (None, Some(mixed)) => mixed.state.expectation(o.operator(), o.targets()),
+                    (None, None) => return None,
+                };
+                Some((o.name.clone(), value.ok()?))
+            })
+            .collect();
+
+        if config.record_snapshots {
+            let values: Map<String, Value> =
+                values.iter().map(|(n, v)| (n.clone(), json!(v))).collect();
+            snapshot.insert(entity.index().to_string(), Value::Object(values));
+        }
+        match expectations {
+            Some(mut expectations) => expectations.values = values,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(QuantumExpectations { values });
+            }
+        }
+    }
+
+    if config.record_snapshots && !snapshot.is_empty() {
+        memory.record_sampled(json!({
+            "module": "dynamics::quantum",
+            "registers": snapshot.len(),
+            "expectations": snapshot,
+        }));
+    }
+}
+
+/// Enregistre la simulation quantique.
+pub(crate) fn init(app: &mut App) {
+    info!(target: "dynamics", "initialisation de la simulation quantique");
+    app.register_type::<QuantumRegister>()
+        .register_type::<QuantumHamiltonian>()
+        .register_type::<QuantumObservables>()
+        .register_type::<QuantumExpectations>()
+        .register_type::<QuantumConfig>()
+        .init_resource::<QuantumConfig>()
+        .add_message::<MeasureRequest>()
+        .add_message::<MeasurementOutcome>()
+        .add_systems(Startup, seed_quantum)
+        .add_systems(
+            FixedUpdate,
+            (evolve_quantum, measure_quantum, record_expectations)
+                .chain()
+                .in_set(DynamicsSet::Integrate),
+        );
+}
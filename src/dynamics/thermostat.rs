@@ -0,0 +1,220 @@
+//! Thermostat : couplage des corps à un bain thermique.
+//!
+//! Après l’intégration de chaque pas fixe, les vitesses des corps massifs sont ramenées
+//! vers la température cible `T₀` :
+//! - [`ThermostatKind::Langevin`] : frottement `γ` et force aléatoire, appliqués par la
+//!   solution exacte du processus d’Ornstein-Uhlenbeck sur le pas `h` :
+//!   `v ← c v + √((1 − c²) k_B T₀ / m) ξ`, avec `c = e^{−γh}` et `ξ` gaussien. Le bruit est
+//!   tiré du flux `dynamics::thermostat` de l’[`EngineSeed`] ; l’ensemble canonique est
+//!   échantillonné exactement.
+//! - [`ThermostatKind::Berendsen`] : remise à l’échelle déterministe des vitesses par
+//!   `λ = √(1 + γh (T₀/T − 1))` ; relaxation exponentielle de `T` vers `T₀` en `1/γ`, sans
+//!   fluctuations canoniques.
+//!
+//! La température cinétique `T = 2 E_c / (3 N k_B)` (translation seule) est mesurée à
+//! chaque pas et publiée, avec la chaleur échangée avec le bain, dans [`ThermostatStats`].
+//! Avec [`ThermostatConfig::entropy_driven`], la cible suit l’entropie globale de la
+//! [`FeedbackLoop`] : `T₀ = entropy_scale · S`.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+use tracing::debug;
+
+use super::integrator::integrate_bodies;
+use super::{DynamicsSet, Mass, Velocity};
+use crate::core::EngineSeed;
+use crate::function::FeedbackLoop;
+
+/// Bornes du facteur de Berendsen, pour éviter les chocs thermiques.
+const BERENDSEN_LIMITS: (f32, f32) = (0.8, 1.25);
+
+/// Algorithme de thermostat.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ThermostatKind {
+    /// Dynamique de Langevin (frottement et bruit), canonique.
+    #[default]
+    Langevin,
+    /// Remise à l’échelle des vitesses de Berendsen.
+    Berendsen,
+}
+
+/// Ressource : paramètres du thermostat.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ThermostatConfig {
+    pub enabled: bool,
+    pub kind: ThermostatKind,
+    /// Température cible `T₀`.
+    pub target_temperature: f32,
+    /// Taux de couplage au bain `γ` (s⁻¹) : frottement de Langevin ou inverse du temps de
+    /// relaxation de Berendsen.
+    pub damping: f32,
+    /// Constante de Boltzmann `k_B` (unités du moteur).
+    pub boltzmann: f32,
+    /// Fixe la cible d’après l’entropie globale de la boucle de rétroaction.
+    pub entropy_driven: bool,
+    /// Température par unité d’entropie globale dans ce mode.
+    pub entropy_scale: f32,
+}
+
+impl Default for ThermostatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            kind: ThermostatKind::default(),
+            target_temperature: 1.0,
+            damping: 1.0,
+            boltzmann: 1.0,
+            entropy_driven: false,
+            entropy_scale: 1.0,
+        }
+    }
+}
+
+impl ThermostatConfig {
+    /// Température cible effective.
+    pub fn target(&self, feedback: Option<&FeedbackLoop>) -> f32 {
+        match feedback {
+            Some(feedback) if self.entropy_driven => {
+                self.entropy_scale * feedback.global_entropy.max(0.0)
+            }
+            _ => self.target_temperature,
+        }
+        .max(0.0)
+    }
+}
+
+/// Ressource : mesures du dernier pas.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ThermostatStats {
+    /// Corps massifs thermalisés.
+    pub bodies: usize,
+    /// Température cinétique mesurée après le pas.
+    pub kinetic_temperature: f32,
+    /// Température cible appliquée.
+    pub target_temperature: f32,
+    /// Énergie cédée par le bain aux corps pendant le pas (négative s’il les refroidit).
+    pub heat: f32,
+}
+
+/// Ressource : générateur du bruit thermique.
+#[derive(Resource)]
+pub struct ThermostatRng(pub StdRng);
+
+/// Tirage gaussien centré réduit (Box-Muller).
+pub(crate) fn gaussian(rng: &mut impl Rng) -> f32 {
+    let u = 1.0 - rng.random::<f32>();
+    let v = rng.random::<f32>();
+    (-2.0 * u.ln()).sqrt() * (std::f32::consts::TAU * v).cos()
+}
+
+/// Température cinétique de corps d’énergie cinétique totale `kinetic`.
+pub fn kinetic_temperature(kinetic: f32, bodies: usize, boltzmann: f32) -> f32 {
+    if bodies == 0 || boltzmann <= 0.0 {
+        return 0.0;
+    }
+    2.0 * kinetic / (3.0 * bodies as f32 * boltzmann)
+}
+
+/// Système (Startup) : ensemence le bruit thermique à partir de la graine moteur.
+pub fn seed_thermostat(mut commands: Commands, seed: Option<Res<EngineSeed>>) {
+    let seed = seed.as_deref().copied().unwrap_or_default();
+    commands.insert_resource(ThermostatRng(seed.rng("dynamics::thermostat")));
+}
+
+/// Système : couple les vitesses au bain et mesure la température cinétique.
+pub fn apply_thermostat(
+    mut bodies: Query<(&mut Velocity, &Mass)>,
+    config: Res<ThermostatConfig>,
+    feedback: Option<Res<FeedbackLoop>>,
+    rng: Option<ResMut<ThermostatRng>>,
+    time: Res<Time>,
+    mut stats: ResMut<ThermostatStats>,
+) {
+    let h = time.delta_secs();
+    let target = config.target(feedback.as_deref());
+    let kinetic = |bodies: &Query<(&mut Velocity, &Mass)>| {
+        bodies.iter().filter(|(_, mass)| mass.value > 0.0).fold(
+            (0usize, 0.0f32),
+            |(n, e), (velocity, mass)| {
+                (
+                    n + 1,
+                    e + 0.5 * mass.value * velocity.linear.length_squared(),
+                )
+            },
+        )
+    };
+    let (count, before) = kinetic(&bodies);
+
+    if config.enabled && h > 0.0 && config.damping > 0.0 {
+        match config.kind {
+            ThermostatKind::Langevin => {
+                if let Some(mut rng) = rng {
+                    let c = (-config.damping * h).exp();
+                    let spread = ((1.0 - c * c) * config.boltzmann * target).max(0.0).sqrt();
+                    for (mut velocity, mass) in bodies.iter_mut() {
+                        if mass.value <= 0.0 {
+                            continue;
+                        }
+                        let sigma = spread / mass.value.sqrt();
+                        let noise = Vec3::new(
+                            gaussian(&mut rng.0),
+                            gaussian(&mut rng.0),
+                            gaussian(&mut rng.0),
+                        );
+                        velocity.linear = c * velocity.linear + sigma * noise;
+                    }
+                }
+            }
+            ThermostatKind::Berendsen => {
+                let current = kinetic_temperature(before, count, config.boltzmann);
+                if current > 0.0 {
+                    let lambda = (1.0 + config.damping * h * (target / current - 1.0))
+                        .max(0.0)
+                        .sqrt()
+                        .clamp(BERENDSEN_LIMITS.0, BERENDSEN_LIMITS.1);
+                    for (mut velocity, mass) in bodies.iter_mut() {
+                        if mass.value > 0.0 {
+                            velocity.linear *= lambda;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let (_, after) = kinetic(&bodies);
+    *stats = ThermostatStats {
+        bodies: count,
+        kinetic_temperature: kinetic_temperature(after, count, config.boltzmann),
+        target_temperature: target,
+        heat: after - before,
+    };
+    if config.enabled {
+        debug!(
+            target: "dynamics",
+            temperature = stats.kinetic_temperature,
+            target = stats.target_temperature,
+            heat = stats.heat,
+            "thermostat"
+        );
+    }
+}
+
+/// Enregistre le thermostat.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<ThermostatKind>()
+        .register_type::<ThermostatConfig>()
+        .register_type::<ThermostatStats>()
+        .init_resource::<ThermostatConfig>()
+        .init_resource::<ThermostatStats>()
+        .add_systems(Startup, seed_thermostat)
+        .add_systems(
+            FixedUpdate,
+            apply_thermostat
+                .in_set(DynamicsSet::Integrate)
+                .after(integrate_bodies),
+        );
+}
@@ -10,9 +10,10 @@
 //! Intégration ECS :
 //! - `FeedbackLoop` : ressource reflectable qui enregistre les fluctuations globales.
 //! - `Oscillator` : composant reflectable, représentant les entités vibratoires du système.
-//! - Systèmes : `update_oscillators`, `regulate_entropy` (stade Update).
+//! - Systèmes : `update_oscillators`, `absorb_measured_energy`, `regulate_entropy` (stade Update).
 
 use crate::core::MemoryField;
+use crate::dynamics::EnergyLedger;
 use bevy::prelude::*;
 use std::f32::consts::PI;
 use tracing::{debug, info};
@@ -34,6 +35,8 @@ pub struct FeedbackLoop {
     pub adaptive_decay: f32,
     /// Adaptive phase rate tuned by entropy trend.
     pub phase_rate: f32,
+    /// Total mechanical energy last measured by the dynamics layer.
+    pub measured_energy: f32,
 }
 
 /// Component representing a systemic vibratory oscillator.
@@ -81,7 +84,7 @@ fn update_oscillators(
     feedback.frame_counter = feedback.frame_counter.wrapping_add(1);
 
     // Log status every 60 frames to reduce console spam.
-    if feedback.frame_counter % 60 == 0 {
+    if feedback.frame_counter.is_multiple_of(60) {
         debug!(
             target: "function",
             entropy = feedback.global_entropy,
@@ -91,6 +94,28 @@ fn update_oscillators(
     }
 }
 
+/// Fraction of dissipated mechanical energy converted into global entropy.
+const DISSIPATION_ENTROPY: f32 = 0.01;
+
+/// System: mirrors the measured mechanical energy and turns dissipated energy into entropy.
+///
+/// Runs in `Update` while energy is measured on the fixed timestep: the dissipation of
+/// every fixed step since the previous frame is consumed, not only the last one.
+fn absorb_measured_energy(
+    ledger: Option<ResMut<EnergyLedger>>,
+    mut feedback: ResMut<FeedbackLoop>,
+) {
+    let Some(mut ledger) = ledger else {
+        return;
+    };
+    if !ledger.is_changed() {
+        return;
+    }
+    feedback.measured_energy = ledger.total;
+    let dissipated = ledger.bypass_change_detection().take_dissipated();
+    feedback.global_entropy += dissipated * DISSIPATION_ENTROPY;
+}
+
 const BASE_DECAY: f32 = 0.95;
 const BASE_PHASE_RATE: f32 = 0.01;
 
@@ -130,6 +155,7 @@ pub fn reset_feedback(mut feedback: ResMut<FeedbackLoop>) {
     feedback.frame_counter = 0;
     feedback.adaptive_decay = BASE_DECAY;
     feedback.phase_rate = BASE_PHASE_RATE;
+    feedback.measured_energy = 0.0;
     info!(
         target: "function",
         "reset_feedback | feedback loop state reset to defaults"
@@ -143,7 +169,10 @@ pub fn init(app: &mut App) {
     app.insert_resource(FeedbackLoop::default())
         .register_type::<FeedbackLoop>()
         .register_type::<Oscillator>()
-        .add_systems(Update, (update_oscillators, regulate_entropy))
+        .add_systems(
+            Update,
+            (update_oscillators, absorb_measured_energy, regulate_entropy).chain(),
+        )
         .add_systems(Startup, reset_feedback);
 
     info!(target: "function", "functional feedback loops online");
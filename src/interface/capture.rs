@@ -0,0 +1,832 @@
+//! Capture hors écran de la visualisation diagnostique.
+//!
+//! La capture ne lit pas la vue Bevy : elle rend une scène synthétique recomposée à partir
+//! de l’état de la simulation, indépendante des caméras, sprites et interfaces affichés
+//! à l’écran.
+//!
+//! Tous les `interval` ticks, [`capture_frames`] compose cette scène ([`CaptureScene`]) :
+//! le carré diagnostique (couleur dérivée de la cohérence et de l’entropie mémorisées)
+//! et une carte de chaleur par [`Field`] du substrat. La scène est rendue dans une texture
+//! hors écran via wgpu lorsqu’un GPU est disponible, sinon par un rasteriseur CPU
+//! équivalent (aux arrondis près sur les frontières de texels), puis écrite en PNG
+//! (`<prefix>_000000.png`, `<prefix>_000001.png`, …). Le rendu GPU est relu sans bloquer :
+//! l’image est écrite lors d’une frame ultérieure, dès que la copie est terminée.
+//!
+//! La capture ne dépend d’aucune fenêtre : elle fonctionne en exécution headless.
+//! Configuration par [`CaptureConfig`] ou par l’environnement (`VOID_CAPTURE_DIR`,
+//! `VOID_CAPTURE_INTERVAL`, `VOID_CAPTURE_SIZE`, `VOID_CAPTURE_BACKEND`).
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use bevy::prelude::*;
+use bevy::tasks::IoTaskPool;
+use image::ExtendedColorType;
+use tracing::{debug, info, warn};
+use wgpu::util::DeviceExt;
+
+use crate::core::MemoryField;
+use crate::substrate::{ComputeBackend, Field, FieldKind, GpuContext};
+
+const CAPTURE_SHADER: &str = include_str!("shaders/capture_quad.wgsl");
+const CAPTURE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+/// Couleur de fond par défaut (celle de `ClearColor` dans Bevy).
+const DEFAULT_BACKGROUND: [u8; 4] = [43, 44, 47, 255];
+
+/// Marge autour de chaque tuile (pixels).
+const TILE_MARGIN: u32 = 8;
+
+/// Moteur de rendu utilisé pour la capture.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum CaptureBackend {
+    /// GPU si disponible, sinon CPU.
+    #[default]
+    Auto,
+    /// GPU uniquement (repli CPU en cas d’échec du rendu).
+    Gpu,
+    /// Rasteriseur CPU.
+    Cpu,
+}
+
+/// Configuration de la capture hors écran.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CaptureConfig {
+    pub enabled: bool,
+    /// Répertoire de sortie (créé au besoin).
+    pub directory: PathBuf,
+    /// Préfixe des fichiers de la séquence.
+    pub prefix: String,
+    /// Une image tous les `interval` ticks.
+    pub interval: u32,
+    pub width: u32,
+    pub height: u32,
+    pub backend: CaptureBackend,
+    /// Ajoute une carte de chaleur par champ du substrat.
+    pub heatmaps: bool,
+    /// Nombre maximal d’images (`None` : illimité).
+    pub max_frames: Option<u32>,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: PathBuf::from("captures"),
+            prefix: "frame".into(),
+            interval: 60,
+            width: 512,
+            height: 512,
+            backend: CaptureBackend::Auto,
+            heatmaps: true,
+            max_frames: None,
+        }
+    }
+}
+
+impl CaptureConfig {
+    /// Lit la configuration de l’environnement ; `VOID_CAPTURE_DIR` active la capture.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(directory) = std::env::var("VOID_CAPTURE_DIR") {
+            config.enabled = true;
+            config.directory = directory.into();
+        }
+        if let Some(interval) = std::env::var("VOID_CAPTURE_INTERVAL")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+        {
+            config.interval = interval.max(1);
+        }
+        if let Some((width, height)) = std::env::var("VOID_CAPTURE_SIZE").ok().and_then(|v| {
+            let (w, h) = v.split_once('x')?;
+            Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+        }) {
+            config.width = width;
+            config.height = height;
+        }
+        match std::env::var("VOID_CAPTURE_BACKEND").as_deref() {
+            Ok("gpu") => config.backend = CaptureBackend::Gpu,
+            Ok("cpu") => config.backend = CaptureBackend::Cpu,
+            _ => {}
+        }
+        config
+    }
+}
+
+/// Ressource : progression de la capture.
+#[derive(Resource, Default, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CaptureState {
+    /// Ticks écoulés depuis l’activation.
+    pub ticks: u64,
+    /// Images écrites.
+    pub frames: u32,
+    pub last_file: Option<PathBuf>,
+    /// Moteur de rendu de la dernière image (`"gpu"` ou `"cpu"`).
+    pub last_backend: Option<String>,
+}
+
+/// Rectangle en pixels (origine en haut à gauche).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PixelRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Image RGBA8 plaquée sur un rectangle de la capture (échantillonnage au plus proche).
+#[derive(Clone, Debug)]
+pub struct CaptureLayer {
+    pub rect: PixelRect,
+    pub width: u32,
+    pub height: u32,
+    /// Texels RGBA8, ligne 0 en haut.
+    pub pixels: Vec<u8>,
+}
+
+impl CaptureLayer {
+    /// Couche uniforme d’une seule couleur.
+    pub fn solid(rect: PixelRect, color: [u8; 4]) -> Self {
+        Self {
+            rect,
+            width: 1,
+            height: 1,
+            pixels: color.to_vec(),
+        }
+    }
+}
+
+/// Scène à capturer : fond uni et couches dessinées dans l’ordre.
+#[derive(Clone, Debug)]
+pub struct CaptureScene {
+    pub width: u32,
+    pub height: u32,
+    pub background: [u8; 4],
+    pub layers: Vec<CaptureLayer>,
+}
+
+impl CaptureScene {
+    /// Rasterise la scène sur CPU ; retourne les pixels RGBA8, ligne 0 en haut.
+    pub fn rasterize_cpu(&self) -> Vec<u8> {
+        let mut image = self.background.repeat((self.width * self.height) as usize);
+
+        for layer in &self.layers {
+            let rect = layer.rect;
+            let x_end = (rect.x + rect.width).min(self.width);
+            let y_end = (rect.y + rect.height).min(self.height);
+            for py in rect.y..y_end {
+                // Même règle que l’échantillonneur GPU : centre du pixel, texel le plus proche.
+                let v = (py - rect.y) as f32 + 0.5;
+                let ty =
+                    ((v / rect.height as f32 * layer.height as f32) as u32).min(layer.height - 1);
+                for px in rect.x..x_end {
+                    let u = (px - rect.x) as f32 + 0.5;
+                    let tx =
+                        ((u / rect.width as f32 * layer.width as f32) as u32).min(layer.width - 1);
+                    let texel = ((ty * layer.width + tx) * 4) as usize;
+                    let pixel = ((py * self.width + px) * 4) as usize;
+                    image[pixel..pixel + 4].copy_from_slice(&layer.pixels[texel..texel + 4]);
+                }
+            }
+        }
+        image
+    }
+}
+
+/// Couleur du carré diagnostique, dérivée de la cohérence et de l’entropie récentes.
+pub(crate) fn diagnostic_color(memory: &MemoryField) -> Color {
+    let coherence = memory.average("coherence", 60).unwrap_or(0.5);
+    let entropy = memory.average("entropy", 60).unwrap_or(0.5);
+    let intensity = (1.0 - entropy).clamp(0.0, 1.0);
+
+    Color::srgb(
+        coherence.clamp(0.0, 1.0),
+        intensity,
+        (1.0 - coherence).clamp(0.0, 1.0),
+    )
+}
+
+/// Palette des cartes de chaleur : bleu nuit → sarcelle → jaune.
+fn heat_color(t: f32) -> [u8; 4] {
+    const STOPS: [[f32; 3]; 3] = [[0.05, 0.03, 0.25], [0.0, 0.6, 0.6], [1.0, 0.9, 0.2]];
+    let t = t.clamp(0.0, 1.0) * 2.0;
+    let (a, b, f) = if t < 1.0 {
+        (STOPS[0], STOPS[1], t)
+    } else {
+        (STOPS[1], STOPS[2], t - 1.0)
+    };
+    let channel = |i: usize| ((a[i] + (b[i] - a[i]) * f) * 255.0).round() as u8;
+    [channel(0), channel(1), channel(2), 255]
+}
+
+/// Carte de chaleur de la tranche médiane du champ (norme pour un champ vectoriel).
+///
+/// Les valeurs sont normalisées entre le minimum et le maximum de la tranche ; l’axe y
+/// du champ pointe vers le haut de l’image.
+fn heatmap(field: &Field, rect: PixelRect) -> CaptureLayer {
+    let (nx, ny) = (field.dims.x, field.dims.y);
+    let z = field.dims.z / 2;
+    let values: Vec<f32> = (0..ny)
+        .rev()
+        .flat_map(|y| (0..nx).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let index = field.index(x, y, z);
+            match field.kind {
+                FieldKind::Scalar => field.scalar(index),
+                FieldKind::Vector => field.vector(index).length(),
+            }
+        })
+        .collect();
+
+    let (min, max) = values
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(*v), hi.max(*v))
+        });
+    let range = max - min;
+    let pixels = values
+        .iter()
+        .flat_map(|v| {
+            heat_color(if range > f32::EPSILON {
+                (v - min) / range
+            } else {
+                0.5
+            })
+        })
+        .collect();
+
+    CaptureLayer {
+        rect,
+        width: nx,
+        height: ny,
+        pixels,
+    }
+}
+
+/// Compose la scène diagnostique : carré diagnostique puis une tuile par champ.
+pub fn compose_scene<'a>(
+    config: &CaptureConfig,
+    background: [u8; 4],
+    diagnostic: Color,
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> CaptureScene {
+    let fields: Vec<&Field> = if config.heatmaps {
+        fields.into_iter().collect()
+    } else {
+        Vec::new()
+    };
+
+    let (width, height) = (config.width.max(1), config.height.max(1));
+    let tiles = 1 + fields.len() as u32;
+    let columns = (tiles as f32).sqrt().ceil() as u32;
+    let rows = tiles.div_ceil(columns);
+    let (tile_w, tile_h) = (width / columns, height / rows);
+    let tile = |index: u32| PixelRect {
+        x: (index % columns) * tile_w,
+        y: (index / columns) * tile_h,
+        width: tile_w,
+        height: tile_h,
+    };
+
+    let mut layers = Vec::with_capacity(tiles as usize);
+
+    // Carré diagnostique : 60 % du plus petit côté de sa tuile, centré.
+    let cell = tile(0);
+    let side = (cell.width.min(cell.height) as f32 * 0.6) as u32;
+    layers.push(CaptureLayer::solid(
+        PixelRect {
+            x: cell.x + (cell.width - side) / 2,
+            y: cell.y + (cell.height - side) / 2,
+            width: side,
+            height: side,
+        },
+        diagnostic.to_srgba().to_u8_array(),
+    ));
+
+    for (index, field) in fields.into_iter().enumerate() {
+        let cell = tile(index as u32 + 1);
+        let inner_w = cell.width.saturating_sub(2 * TILE_MARGIN);
+        let inner_h = cell.height.saturating_sub(2 * TILE_MARGIN);
+        // Conserve le rapport d’aspect de la grille.
+        let scale =
+            (inner_w as f32 / field.dims.x as f32).min(inner_h as f32 / field.dims.y as f32);
+        let (w, h) = (
+            (field.dims.x as f32 * scale) as u32,
+            (field.dims.y as f32 * scale) as u32,
+        );
+        if w == 0 || h == 0 {
+            continue;
+        }
+        layers.push(heatmap(
+            field,
+            PixelRect {
+                x: cell.x + (cell.width - w) / 2,
+                y: cell.y + (cell.height - h) / 2,
+                width: w,
+                height: h,
+            },
+        ));
+    }
+
+    CaptureScene {
+        width,
+        height,
+        background,
+        layers,
+    }
+}
+
+/// Erreur du rendu GPU de la capture.
+#[derive(Debug)]
+pub enum CaptureError {
+    /// Dimensions de capture hors des limites du device.
+    TooLarge { width: u32, height: u32, limit: u32 },
+    /// Le device a refusé une ressource ou une commande.
+    Device(String),
+    /// Relecture de l’image impossible.
+    Readback(String),
+}
+
+impl std::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooLarge {
+                width,
+                height,
+                limit,
+            } => write!(f, "capture {width}x{height} exceeds texture limit {limit}"),
+            Self::Device(err) => write!(f, "capture rejected by device: {err}"),
+            Self::Readback(err) => write!(f, "capture readback failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// Pipeline de rendu hors écran compilé pour un device.
+pub struct GpuCaptureRenderer {
+    device: Arc<wgpu::Device>,
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl GpuCaptureRenderer {
+    pub fn new(gpu: &GpuContext) -> Self {
+        let device = &gpu.device;
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("interface::capture"),
+            source: wgpu::ShaderSource::Wgsl(CAPTURE_SHADER.into()),
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("interface::capture"),
+            layout: None,
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: CAPTURE_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+            cache: None,
+        });
+        let layout = pipeline.get_bind_group_layout(0);
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("interface::capture"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Self {
+            device: gpu.device.clone(),
+            pipeline,
+            layout,
+            sampler,
+        }
+    }
+
+    /// `true` si le pipeline a été compilé pour le device de `gpu`.
+    pub fn matches(&self, gpu: &GpuContext) -> bool {
+        Arc::ptr_eq(&self.device, &gpu.device)
+    }
+
+    /// Rend la scène dans une texture hors écran et attend les pixels RGBA8.
+    ///
+    /// Bloque jusqu’à la fin du rendu : [`capture_frames`] utilise [`Self::submit`].
+    pub fn render(&self, gpu: &GpuContext, scene: &CaptureScene) -> Result<Vec<u8>, CaptureError> {
+        let pending = self.submit(gpu, scene)?;
+        gpu.device
+            .poll(wgpu::PollType::Wait)
+            .map_err(|err| CaptureError::Readback(err.to_string()))?;
+        pending
+            .try_finish()
+            .unwrap_or_else(|| Err(CaptureError::Readback("image not mapped".into())))
+    }
+
+    /// Soumet le rendu de la scène et la relecture de l’image, sans attendre le GPU.
+    pub fn submit(
+        &self,
+        gpu: &GpuContext,
+        scene: &CaptureScene,
+    ) -> Result<PendingCapture, CaptureError> {
+        let device = &gpu.device;
+        let limit = device.limits().max_texture_dimension_2d;
+        if scene.width > limit || scene.height > limit {
+            return Err(CaptureError::TooLarge {
+                width: scene.width,
+                height: scene.height,
+                limit,
+            });
+        }
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let extent = |width, height| wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("interface::capture_target"),
+            size: extent(scene.width, scene.height),
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: CAPTURE_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = target.create_view(&Default::default());
+
+        let (w, h) = (scene.width as f32, scene.height as f32);
+        let bind_groups: Vec<wgpu::BindGroup> = scene
+            .layers
+            .iter()
+            .map(|layer| {
+                let texture = device.create_texture(&wgpu::TextureDescriptor {
+                    label: Some("interface::capture_layer"),
+                    size: extent(layer.width, layer.height),
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: CAPTURE_FORMAT,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                    view_formats: &[],
+                });
+                gpu.queue.write_texture(
+                    texture.as_image_copy(),
+                    &layer.pixels,
+                    wgpu::TexelCopyBufferLayout {
+                        offset: 0,
+                        bytes_per_row: Some(layer.width * 4),
+                        rows_per_image: None,
+                    },
+                    extent(layer.width, layer.height),
+                );
+
+                let r = layer.rect;
+                let rect = [
+                    r.x as f32 / w * 2.0 - 1.0,
+                    1.0 - r.y as f32 / h * 2.0,
+                    (r.x + r.width) as f32 / w * 2.0 - 1.0,
+                    1.0 - (r.y + r.height) as f32 / h * 2.0,
+                ];
+                let uniform = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("interface::capture_quad"),
+                    contents: bytemuck::cast_slice(&rect),
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("interface::capture_layer"),
+                    layout: &self.layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: uniform.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::TextureView(
+                                &texture.create_view(&Default::default()),
+                            ),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                    ],
+                })
+            })
+            .collect();
+
+        // Les lignes copiées vers un buffer doivent être alignées sur 256 octets.
+        let row_bytes = scene.width * 4;
+        let padded_row = row_bytes.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("interface::capture_staging"),
+            size: padded_row as u64 * scene.height as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("interface::capture"),
+        });
+        {
+            let [r, g, b, a] = scene.background.map(|c| c as f64 / 255.0);
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("interface::capture"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color { r, g, b, a }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            for bind_group in &bind_groups {
+                pass.set_bind_group(0, bind_group, &[]);
+                pass.draw(0..4, 0..1);
+            }
+        }
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &staging,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: None,
+                },
+            },
+            extent(scene.width, scene.height),
+        );
+        gpu.queue.submit(Some(encoder.finish()));
+
+        if let Some(err) = bevy::tasks::block_on(device.pop_error_scope()) {
+            return Err(CaptureError::Device(err.to_string()));
+        }
+
+        let done = Arc::new(OnceLock::new());
+        let signal = done.clone();
+        staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = signal.set(result.map_err(|err| err.to_string()));
+            });
+        Ok(PendingCapture {
+            staging,
+            row_bytes,
+            padded_row,
+            done,
+        })
+    }
+}
+
+/// Image rendue sur GPU dont la relecture est en cours.
+pub struct PendingCapture {
+    staging: wgpu::Buffer,
+    row_bytes: u32,
+    padded_row: u32,
+    done: Arc<OnceLock<Result<(), String>>>,
+}
+
+impl PendingCapture {
+    /// Pixels RGBA8 si la relecture est terminée (le device doit avoir été interrogé).
+    pub fn try_finish(&self) -> Option<Result<Vec<u8>, CaptureError>> {
+        let result = self.done.get()?.clone();
+        Some(result.map_err(CaptureError::Readback).map(|()| {
+            let image = self
+                .staging
+                .slice(..)
+                .get_mapped_range()
+                .chunks_exact(self.padded_row as usize)
+                .flat_map(|row| &row[..self.row_bytes as usize])
+                .copied()
+                .collect();
+            self.staging.unmap();
+            image
+        }))
+    }
+}
+
+/// Capture GPU soumise, en attente de relecture.
+struct InFlightCapture {
+    pending: PendingCapture,
+    /// Scène conservée pour un rendu CPU si la relecture échoue.
+    scene: CaptureScene,
+    path: PathBuf,
+}
+
+/// État local de [`capture_frames`] : pipeline compilé et captures GPU en vol.
+#[derive(Default)]
+pub struct CaptureRenderer {
+    renderer: Option<GpuCaptureRenderer>,
+    in_flight: VecDeque<InFlightCapture>,
+}
+
+/// Encode et écrit l’image hors du tick.
+fn write_frame(directory: PathBuf, path: PathBuf, width: u32, height: u32, pixels: Vec<u8>) {
+    IoTaskPool::get()
+        .spawn(async move {
+            let written = std::fs::create_dir_all(&directory)
+                .map_err(image::ImageError::IoError)
+                .and_then(|_| {
+                    image::save_buffer(&path, &pixels, width, height, ExtendedColorType::Rgba8)
+                });
+            if let Err(err) = written {
+                warn!(target: "interface", file = %path.display(), %err, "failed to write capture");
+            }
+        })
+        .detach();
+}
+
+/// Écrit les captures GPU dont la relecture est terminée, dans l’ordre de soumission.
+fn finish_captures(
+    config: &CaptureConfig,
+    state: &mut CaptureState,
+    gpu: Option<&GpuContext>,
+    local: &mut CaptureRenderer,
+) {
+    if local.in_flight.is_empty() {
+        return;
+    }
+    match gpu {
+        Some(gpu) => {
+            let _ = gpu.device.poll(wgpu::PollType::Poll);
+        }
+        // Le device a disparu : les captures en vol sont rendues sur CPU.
+        None => {
+            for capture in local.in_flight.drain(..) {
+                let pixels = capture.scene.rasterize_cpu();
+                let (width, height) = (capture.scene.width, capture.scene.height);
+                write_frame(
+                    config.directory.clone(),
+                    capture.path,
+                    width,
+                    height,
+                    pixels,
+                );
+            }
+            state.last_backend = Some("cpu".into());
+            return;
+        }
+    }
+
+    while let Some(result) = local.in_flight.front().and_then(|c| c.pending.try_finish()) {
+        let capture = local.in_flight.pop_front().expect("capture checked above");
+        let pixels = match result {
+            Ok(pixels) => pixels,
+            Err(err) => {
+                warn!(target: "interface", %err, "GPU capture failed; using CPU rasterizer");
+                state.last_backend = Some("cpu".into());
+                capture.scene.rasterize_cpu()
+            }
+        };
+        let (width, height) = (capture.scene.width, capture.scene.height);
+        write_frame(
+            config.directory.clone(),
+            capture.path,
+            width,
+            height,
+            pixels,
+        );
+    }
+}
+
+/// Système : capture la visualisation diagnostique tous les `interval` ticks.
+#[allow(clippy::too_many_arguments)]
+pub fn capture_frames(
+    config: Res<CaptureConfig>,
+    mut state: ResMut<CaptureState>,
+    memory: Res<MemoryField>,
+    fields: Query<&Field>,
+    backend: Option<Res<ComputeBackend>>,
+    clear_color: Option<Res<ClearColor>>,
+    mut local: Local<CaptureRenderer>,
+) {
+    let current_gpu = backend.as_ref().and_then(|b| b.gpu());
+    finish_captures(&config, &mut state, current_gpu, &mut local);
+
+    if !config.enabled || config.max_frames.is_some_and(|max| state.frames >= max) {
+        return;
+    }
+    state.ticks += 1;
+    // Première image au premier tick, puis tous les `interval` ticks.
+    if !(state.ticks - 1).is_multiple_of(config.interval.max(1) as u64) {
+        return;
+    }
+
+    let background = clear_color.map_or(DEFAULT_BACKGROUND, |c| c.0.to_srgba().to_u8_array());
+    let scene = compose_scene(
+        &config,
+        background,
+        diagnostic_color(&memory),
+        fields.iter(),
+    );
+
+    let gpu = match config.backend {
+        CaptureBackend::Cpu => None,
+        CaptureBackend::Auto | CaptureBackend::Gpu => current_gpu,
+    };
+    if config.backend == CaptureBackend::Gpu && gpu.is_none() {
+        debug!(target: "interface", "GPU capture requested but no GPU available; using CPU");
+    }
+
+    let path = config
+        .directory
+        .join(format!("{}_{:06}.png", config.prefix, state.frames));
+    state.frames += 1;
+    state.last_file = Some(path.clone());
+
+    let local = &mut *local;
+    let submitted = gpu.and_then(|gpu| {
+        if !local.renderer.as_ref().is_some_and(|r| r.matches(gpu)) {
+            local.renderer = Some(GpuCaptureRenderer::new(gpu));
+        }
+        let renderer = local
+            .renderer
+            .as_ref()
+            .expect("capture renderer built above");
+        match renderer.submit(gpu, &scene) {
+            Ok(pending) => Some(pending),
+            Err(err) => {
+                warn!(target: "interface", %err, "GPU capture failed; using CPU rasterizer");
+                None
+            }
+        }
+    });
+
+    let backend_name = match submitted {
+        Some(pending) => {
+            local.in_flight.push_back(InFlightCapture {
+                pending,
+                scene,
+                path: path.clone(),
+            });
+            "gpu"
+        }
+        None => {
+            let pixels = scene.rasterize_cpu();
+            write_frame(
+                config.directory.clone(),
+                path.clone(),
+                scene.width,
+                scene.height,
+                pixels,
+            );
+            "cpu"
+        }
+    };
+    state.last_backend = Some(backend_name.into());
+    debug!(target: "interface", file = %path.display(), backend = backend_name, "capture frame");
+}
+
+/// Enregistre la capture hors écran (inactive tant que `CaptureConfig::enabled` est faux).
+pub(crate) fn init(app: &mut App) {
+    if !app.world().contains_resource::<CaptureConfig>() {
+        app.insert_resource(CaptureConfig::from_env());
+    }
+    let config = app.world().resource::<CaptureConfig>();
+    if config.enabled {
+        info!(
+            target: "interface",
+            directory = %config.directory.display(),
+            interval = config.interval,
+            "offscreen capture enabled"
+        );
+    }
+
+    app.init_resource::<CaptureState>()
+        .register_type::<CaptureConfig>()
+        .register_type::<CaptureState>()
+        .add_systems(Last, capture_frames);
+}
@@ -17,6 +17,8 @@
 // il traduit les dynamiques internes en signaux observables et capte les stimuli externes pour les
 // réinjecter dans les couches inférieures du moteur.
 
+pub mod capture;
+
 use crate::core::MemoryField;
 use bevy::prelude::*;
 use bevy::sprite::Sprite;
@@ -145,22 +147,32 @@ fn update_visualization(
     mut query: Query<&mut Sprite, With<InterfaceDiagnostic>>,
 ) {
     if let Ok(mut sprite) = query.single_mut() {
-        let coherence = memory.average("coherence", 60).unwrap_or(0.5);
-        let entropy = memory.average("entropy", 60).unwrap_or(0.5);
-        let intensity = (1.0 - entropy).clamp(0.0, 1.0);
-
-        sprite.color = Color::srgb(
-            coherence.clamp(0.0, 1.0),
-            intensity,
-            (1.0 - coherence).clamp(0.0, 1.0),
-        );
+        sprite.color = capture::diagnostic_color(&memory);
     }
 }
 
 /// Initialise le module `interface`.
 ///
-/// Configure les ressources, enregistre les types et ajoute les systèmes nécessaires.
+/// Configure les ressources, enregistre les types et ajoute les systèmes nécessaires,
+/// y compris la visualisation diagnostique (caméra + sprite).
 pub fn init(app: &mut App) {
+    init_headless(app);
+
+    app.add_systems(Startup, setup_visualization)
+        .add_systems(Update, update_visualization);
+
+    // Log final de la phase d'initialisation
+    debug!(
+        target: "interface",
+        "module finalisé — interconnexion fluide établie"
+    );
+}
+
+/// Initialise la couche `interface` sans visualisation.
+///
+/// Seuls les liens inter-Void et les signaux d'entrée/sortie sont enregistrés :
+/// aucune caméra ni sprite n'est créé, ce qui convient aux exécutions headless et aux tests.
+pub fn init_headless(app: &mut App) {
     // Log de démarrage de l'initialisation
     info!(target: "interface", "initialisation de la couche de projection");
 
@@ -168,16 +180,10 @@ pub fn init(app: &mut App) {
         .register_type::<InputSignal>()
         .register_type::<OutputProjection>()
         .register_type::<InterfaceLink>()
-        .add_systems(Startup, setup_visualization)
-        .add_systems(
-            Update,
-            (
-                receive_inputs,
-                emit_outputs,
-                sync_links,
-                update_visualization,
-            ),
-        );
+        .add_systems(Update, (receive_inputs, emit_outputs, sync_links));
+
+    // Capture hors écran : disponible avec ou sans fenêtre.
+    capture::init(app);
 
     // Log de confirmation de mise en ligne
     info!(target: "interface", "système d’interconnexion en ligne");
@@ -187,12 +193,6 @@ pub fn init(app: &mut App) {
         target: "interface",
         "module prêt — communication et visualisation synchronisées"
     );
-
-    // Log final de la phase d'initialisation
-    debug!(
-        target: "interface",
-        "module finalisé — interconnexion fluide établie"
-    );
 }
 
 /// Fonction de debug — affiche l’état ou la progression du module.
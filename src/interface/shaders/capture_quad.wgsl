@@ -0,0 +1,37 @@
+// Capture hors écran : dessine un quadrilatère texturé (échantillonnage au plus proche).
+//
+// `rect` donne les bords gauche, haut, droit et bas du quadrilatère en coordonnées
+// normalisées (NDC). La ligne 0 de la texture est affichée en haut.
+
+struct Quad {
+    rect: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> quad: Quad;
+@group(0) @binding(1) var layer: texture_2d<f32>;
+@group(0) @binding(2) var layer_sampler: sampler;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    // Bande de deux triangles : (0,0) (1,0) (0,1) (1,1).
+    let corner = vec2<f32>(f32(index & 1u), f32((index >> 1u) & 1u));
+    var out: VertexOutput;
+    out.position = vec4<f32>(
+        mix(quad.rect.x, quad.rect.z, corner.x),
+        mix(quad.rect.y, quad.rect.w, corner.y),
+        0.0,
+        1.0,
+    );
+    out.uv = corner;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(layer, layer_sampler, in.uv);
+}
@@ -27,6 +27,67 @@ pub mod manifold;
 pub mod reflection;
 pub mod structure;
 pub mod substrate;
+pub mod testing;
+
+/// Couches du moteur, dans leur ordre canonique d’initialisation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Layer {
+    Core,
+    Substrate,
+    Dynamics,
+    Structure,
+    Function,
+    Reflection,
+    Interface,
+    Manifold,
+}
+
+impl Layer {
+    /// Toutes les couches, de la plus fondamentale à la plus globale.
+    pub const ALL: [Layer; 8] = [
+        Layer::Core,
+        Layer::Substrate,
+        Layer::Dynamics,
+        Layer::Structure,
+        Layer::Function,
+        Layer::Reflection,
+        Layer::Interface,
+        Layer::Manifold,
+    ];
+
+    /// Couches dont les ressources sont lues, ou les étapes (`DynamicsSet`) ordonnancées,
+    /// par cette couche.
+    ///
+    /// `substrate` et `dynamics` lisent l’`EngineSeed` et enregistrent dans le
+    /// `MemoryField` ; `dynamics` ne lit les ressources du substrat (potentiels, topologie,
+    /// fluctuations du vide) que lorsqu’elles sont présentes.
+    pub fn dependencies(self) -> &'static [Layer] {
+        match self {
+            Layer::Core => &[],
+            Layer::Substrate | Layer::Dynamics => &[Layer::Core],
+            Layer::Structure => &[Layer::Dynamics],
+            Layer::Function | Layer::Reflection | Layer::Interface => &[Layer::Core],
+            Layer::Manifold => &[Layer::Core, Layer::Reflection, Layer::Interface],
+        }
+    }
+
+    /// Initialise la couche dans `app`.
+    ///
+    /// `headless` désactive la visualisation de la couche `interface`.
+    pub fn init(self, app: &mut App, headless: bool) {
+        match self {
+            Layer::Core => core::init(app),
+            Layer::Substrate => substrate::init(app),
+            Layer::Dynamics => dynamics::init(app),
+            Layer::Structure => structure::init(app),
+            Layer::Function => function::init(app),
+            Layer::Reflection => reflection::init(app),
+            Layer::Interface if headless => interface::init_headless(app),
+            Layer::Interface => interface::init(app),
+            Layer::Manifold => manifold::init(app),
+        }
+    }
+}
 
 /// Structure centrale du moteur — point d’entrée de tout le système.
 pub struct VoidEngine;
@@ -40,7 +101,7 @@ impl VoidEngine {
     /// - 🌌 Phase 3 : couches réflexives et globales
     pub fn init() -> App {
         // ⚡ Initialisation explicite du pool de tâches Bevy
-        IoTaskPool::get_or_init(|| TaskPool::new());
+        IoTaskPool::get_or_init(TaskPool::new);
         info!(target: "void_engine", "Initialisation du moteur Void");
         let mut app = App::new();
 
@@ -6,6 +6,7 @@
 
 use tracing::info;
 use tracing_subscriber::EnvFilter;
+use void_engine::substrate::{AdapterReport, GpuConfig};
 use void_engine::VoidEngine;
 
 fn init_tracing() {
@@ -28,6 +29,13 @@ fn init_tracing() {
 
 fn main() {
     init_tracing();
+
+    // `--gpu-report` : énumère les adaptateurs GPU selon la configuration, puis quitte.
+    if std::env::args().any(|arg| arg == "--gpu-report") {
+        print!("{}", AdapterReport::collect(&GpuConfig::from_env()));
+        return;
+    }
+
     info!(target: "void_engine", "Lancement du Void Engine");
 
     // Initialisation complète du moteur
@@ -11,6 +11,7 @@
 // en orchestrant les échanges entre ces couches via un champ global : le **VoidField**.
 
 use crate::core::MemoryField;
+use crate::dynamics::EnergyLedger;
 use crate::{interface::*, reflection::*};
 use bevy::prelude::*;
 use bevy::time::TimePlugin;
@@ -33,6 +34,7 @@ fn unify_field(
     reflection: Res<ReflectionField>,
     interface: Res<InterfaceLink>,
     mut memory: ResMut<MemoryField>,
+    ledger: Option<Res<EnergyLedger>>,
 ) {
     // Calcule et met à jour les propriétés du champ unifié en fonction des sous-couches.
     // Log the current state of the unified field for monitoring energy flow and coherence.
@@ -45,7 +47,8 @@ fn unify_field(
         "module": "manifold",
         "coherence": field.coherence,
         "entropy": field.entropy,
-        "energy": field.energy_flow,
+        // Énergie physique mesurée si la dynamique est active, flux normalisé sinon.
+        "energy": ledger.map_or(field.energy_flow, |ledger| ledger.total),
     }));
 
     // Monitoring unified field state
@@ -85,7 +88,10 @@ pub fn init(app: &mut App) {
     // Signal the start of the manifold initialization process.
     info!(target: "manifold", "Initialisation du champ global du Void");
 
-    app.add_plugins(TimePlugin);
+    // Le temps peut déjà être fourni par l'hôte (ex. `MinimalPlugins` dans les tests).
+    if !app.is_plugin_added::<TimePlugin>() {
+        app.add_plugins(TimePlugin);
+    }
 
     app.insert_resource(VoidField::default())
         .register_type::<VoidField>()
@@ -8,7 +8,10 @@
 //! Elle agit comme un **métasystème** capable de percevoir et d’ajuster les dynamiques internes
 //! à travers un mécanisme d’observation intégrée, sans intervention externe.
 
+pub mod perception;
+
 use crate::core::MemoryField;
+use crate::dynamics::{CollisionEvent, EnergyLedger};
 use bevy::prelude::*;
 use serde_json::json;
 use std::f32::consts::PI;
@@ -31,6 +34,14 @@ pub struct ReflectionField {
     pub coherence: f32,
     pub depth: f32,
     pub recursive_level: u32,
+    /// Collisions perçues depuis la frame précédente.
+    pub collisions: u32,
+    /// Impulsion totale échangée par ces collisions.
+    pub impact: f32,
+    /// Information mutuelle moyenne (bits) entre sous-systèmes quantiques.
+    pub entanglement: f32,
+    /// Paires de sous-systèmes quantiques corrélées.
+    pub correlated_pairs: u32,
 }
 
 // ─────────────────────────────
@@ -68,7 +79,14 @@ fn perceive(query: Query<&Perception>, mut field: ResMut<ReflectionField>, time:
 
 /// Fusionne la perception avec les couches inférieures (dynamics, function)
 /// pour maintenir une stabilité systémique du champ réflexif.
-fn integrate(mut field: ResMut<ReflectionField>, mut memory: ResMut<MemoryField>) {
+///
+/// L’énergie enregistrée est l’énergie mécanique mesurée par la dynamique lorsqu’elle est
+/// active, la profondeur du champ réflexif sinon.
+fn integrate(
+    mut field: ResMut<ReflectionField>,
+    mut memory: ResMut<MemoryField>,
+    ledger: Option<Res<EnergyLedger>>,
+) {
     // Intègre et stabilise la cohérence du champ réflexif en pondérant l'ancienne valeur et une fonction de la profondeur
     field.coherence = 0.9 * field.coherence + 0.1 * (1.0 - field.depth).clamp(0.0, 1.0);
 
@@ -82,7 +100,10 @@ fn integrate(mut field: ResMut<ReflectionField>, mut memory: ResMut<MemoryField>
         "module": "reflection",
         "coherence": field.coherence,
         "entropy": (1.0 - field.coherence).clamp(0.0, 1.0),
-        "energy": field.depth,
+        "energy": ledger.map_or(field.depth, |ledger| ledger.total),
+        "collisions": field.collisions,
+        "impact": field.impact,
+        "entanglement": field.entanglement,
     }));
 }
 
@@ -112,7 +133,19 @@ pub fn init(app: &mut App) {
     app.insert_resource(ReflectionField::default())
         .register_type::<Perception>()
         .register_type::<ReflectionField>()
-        .add_systems(Update, (perceive, integrate, recursion));
+        .add_systems(
+            Update,
+            (
+                perception::perceive_entanglement.before(perceive),
+                perceive,
+                // Les collisions ne sont publiées qu’avec la couche `dynamics`.
+                perception::perceive_collisions
+                    .run_if(resource_exists::<Messages<CollisionEvent>>)
+                    .before(integrate),
+                integrate,
+                recursion,
+            ),
+        );
 
     info!(target: "reflection", "systèmes réflexifs opérationnels");
     debug!(
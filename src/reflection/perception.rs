@@ -0,0 +1,65 @@
+//! Perception des événements physiques par la couche de réflexion.
+//!
+//! Les contacts publiés par `dynamics::collision` sont des observations directes de
+//! l’état du monde : leur nombre et l’impulsion échangée nourrissent le
+//! [`ReflectionField`](super::ReflectionField).
+//!
+//! Les corrélations quantiques mesurées par `dynamics::entanglement` sont perçues de même :
+//! une entité rattachée à un registre et portant une [`Perception`] perçoit l’entropie de
+//! son sous-système (intensité) et son information mutuelle avec les autres (variance).
+
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::{Perception, ReflectionField};
+use crate::dynamics::{CollisionEvent, Correlations, EntanglementStats};
+
+/// Système : résume les collisions observées depuis la frame précédente.
+pub fn perceive_collisions(
+    mut collisions: MessageReader<CollisionEvent>,
+    mut field: ResMut<ReflectionField>,
+) {
+    let (count, impact) = collisions
+        .read()
+        .fold((0u32, 0.0f32), |(count, impact), event| {
+            (count + 1, impact + event.impulse)
+        });
+    field.collisions = count;
+    field.impact = impact;
+
+    if count > 0 {
+        debug!(
+            target: "reflection",
+            collisions = count,
+            impact,
+            "collisions perçues"
+        );
+    }
+}
+
+/// Système : perçoit les corrélations quantiques entre entités.
+pub fn perceive_entanglement(
+    mut perceptions: Query<(&Correlations, &mut Perception)>,
+    stats: Option<Res<EntanglementStats>>,
+    mut field: ResMut<ReflectionField>,
+) {
+    for (correlations, mut perception) in perceptions.iter_mut() {
+        perception.intensity = correlations.entropy as f32;
+        perception.variance = correlations.total_mutual_information() as f32;
+    }
+
+    let Some(stats) = stats else {
+        return;
+    };
+    field.entanglement = stats.mean_mutual_information as f32;
+    field.correlated_pairs = stats.correlated as u32;
+
+    if stats.pairs > 0 {
+        debug!(
+            target: "reflection",
+            entanglement = field.entanglement,
+            correlated = field.correlated_pairs,
+            "corrélations quantiques perçues"
+        );
+    }
+}
@@ -0,0 +1,616 @@
+//! Contraintes entre entités, résolues par dynamique fondée sur les positions (XPBD).
+//!
+//! Une contrainte est une entité portant un composant [`Constraint`] qui relie un point
+//! `anchor_a` du corps `body_a` (repère propre) à un point `anchor_b` du corps `body_b`, ou
+//! à un point fixe du monde si `body_b` vaut `None` :
+//! - [`ConstraintKind::Distance`] : distance imposée entre les deux points ;
+//! - [`ConstraintKind::Spring`] : distance souple, de raideur et d’amortissement donnés ;
+//! - [`ConstraintKind::Hinge`] : points confondus et axes alignés (rotation libre autour de
+//!   l’axe) ;
+//! - [`ConstraintKind::Fixed`] : points confondus, orientation relative éventuellement gelée
+//!   (soudure), ou simple ancrage à un point fixe.
+//!
+//! Après l’intégration de chaque pas fixe `h`, [`solve_constraints`] projette les positions
+//! et orientations par Gauss-Seidel sur `iterations` passes. Pour une contrainte `C` de
+//! souplesse `α` (inverse d’une raideur), chaque passe applique
+//! `Δλ = (−C − α̃ λ) / (Σ wᵢ + α̃)` avec `α̃ = α / h²` et `wᵢ` la masse inverse généralisée
+//! de chaque corps au point d’ancrage ; les corrections sont ensuite reportées sur les
+//! vitesses (`Δv = Δx / h`, `Δω = Δθ / h`). Les entités sans `Velocity`, sans masse ou de
+//! masse nulle ne sont pas déplacées.
+//!
+//! Les résidus de violation après résolution, et la force de contrainte `|λ| / h²`, sont
+//! écrits dans chaque [`Constraint`] et résumés dans [`ConstraintStats`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use tracing::{debug, warn};
+
+use crate::dynamics::{DynamicsSet, Inertia, Mass, Velocity};
+use crate::substrate::topology::DomainTopology;
+
+/// Type de contrainte.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum ConstraintKind {
+    /// Distance rigide `length` entre les points d’ancrage.
+    Distance { length: f32 },
+    /// Ressort de longueur au repos `length`, de raideur `stiffness` et d’amortissement
+    /// visqueux `damping` (force par unité de vitesse d’allongement).
+    Spring {
+        length: f32,
+        stiffness: f32,
+        damping: f32,
+    },
+    /// Charnière : points confondus, `axis_a` (repère de `body_a`) aligné sur `axis_b`
+    /// (repère de `body_b`, ou du monde).
+    Hinge { axis_a: Vec3, axis_b: Vec3 },
+    /// Points confondus ; avec `lock_rotation`, l’orientation relative est gelée à sa
+    /// valeur lors de la première résolution.
+    Fixed { lock_rotation: bool },
+}
+
+/// Composant : contrainte entre deux corps, ou entre un corps et le monde.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Constraint {
+    pub enabled: bool,
+    pub body_a: Entity,
+    /// Second corps ; `None` : point fixe du monde.
+    pub body_b: Option<Entity>,
+    /// Point d’ancrage dans le repère propre de `body_a`.
+    pub anchor_a: Vec3,
+    /// Point d’ancrage dans le repère propre de `body_b` (ou position dans le monde).
+    pub anchor_b: Vec3,
+    pub kind: ConstraintKind,
+    /// Souplesse propre ; `None` : celle de [`ConstraintConfig`]. Ignorée par les ressorts.
+    pub compliance: Option<f32>,
+    /// Violation de position après la dernière résolution.
+    pub residual: f32,
+    /// Violation angulaire (radians) après la dernière résolution.
+    pub angular_residual: f32,
+    /// Intensité de la force de contrainte sur le dernier pas.
+    pub force: f32,
+    /// Orientation relative de référence d’une soudure.
+    rest_rotation: Option<Quat>,
+}
+
+impl Constraint {
+    /// Contrainte de type `kind` entre `body_a` et `body_b`, ancrée aux centres.
+    pub fn new(body_a: Entity, body_b: Option<Entity>, kind: ConstraintKind) -> Self {
+        Self {
+            enabled: true,
+            body_a,
+            body_b,
+            anchor_a: Vec3::ZERO,
+            anchor_b: Vec3::ZERO,
+            kind,
+            compliance: None,
+            residual: 0.0,
+            angular_residual: 0.0,
+            force: 0.0,
+            rest_rotation: None,
+        }
+    }
+
+    /// Distance rigide `length` entre deux corps.
+    pub fn distance(body_a: Entity, body_b: Entity, length: f32) -> Self {
+        Self::new(body_a, Some(body_b), ConstraintKind::Distance { length })
+    }
+
+    /// Ressort entre deux corps.
+    pub fn spring(body_a: Entity, body_b: Entity, length: f32, stiffness: f32) -> Self {
+        Self::new(
+            body_a,
+            Some(body_b),
+            ConstraintKind::Spring {
+                length,
+                stiffness,
+                damping: 0.0,
+            },
+        )
+    }
+
+    /// Charnière d’axe `axis`, exprimé dans le repère propre de chacun des deux corps.
+    pub fn hinge(body_a: Entity, body_b: Entity, axis: Vec3) -> Self {
+        Self::new(
+            body_a,
+            Some(body_b),
+            ConstraintKind::Hinge {
+                axis_a: axis,
+                axis_b: axis,
+            },
+        )
+    }
+
+    /// Soudure : position et orientation relatives figées.
+    pub fn fixed(body_a: Entity, body_b: Entity) -> Self {
+        Self::new(
+            body_a,
+            Some(body_b),
+            ConstraintKind::Fixed {
+                lock_rotation: true,
+            },
+        )
+    }
+
+    /// Ancrage du centre de `body` au point `point` du monde.
+    pub fn anchor(body: Entity, point: Vec3) -> Self {
+        Self {
+            anchor_b: point,
+            ..Self::new(
+                body,
+                None,
+                ConstraintKind::Fixed {
+                    lock_rotation: false,
+                },
+            )
+        }
+    }
+
+    pub fn with_anchors(mut self, anchor_a: Vec3, anchor_b: Vec3) -> Self {
+        self.anchor_a = anchor_a;
+        self.anchor_b = anchor_b;
+        self
+    }
+
+    pub fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = Some(compliance);
+        self
+    }
+
+    /// Amortissement d’un ressort (sans effet sur les autres types).
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        if let ConstraintKind::Spring { damping: d, .. } = &mut self.kind {
+            *d = damping;
+        }
+        self
+    }
+}
+
+/// Ressource : paramètres du solveur.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ConstraintConfig {
+    pub enabled: bool,
+    /// Passes de Gauss-Seidel par pas fixe.
+    pub iterations: u32,
+    /// Souplesse par défaut `α` (inverse d’une raideur ; `0` : contrainte rigide).
+    pub compliance: f32,
+}
+
+impl Default for ConstraintConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            iterations: 8,
+            compliance: 0.0,
+        }
+    }
+}
+
+/// Ressource : bilan de la dernière résolution.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ConstraintStats {
+    /// Contraintes résolues.
+    pub constraints: usize,
+    /// Contraintes ignorées (corps introuvable).
+    pub invalid: usize,
+    pub iterations: u32,
+    /// Plus grande violation de position.
+    pub max_residual: f32,
+    /// Violation de position moyenne.
+    pub mean_residual: f32,
+    /// Plus grande violation angulaire (radians).
+    pub max_angular_residual: f32,
+}
+
+/// Corps vu par le solveur.
+#[derive(Clone, Copy)]
+struct Body {
+    position: Vec3,
+    rotation: Quat,
+    inverse_mass: f32,
+    /// Tenseur d’inertie inverse dans le repère propre.
+    inverse_inertia: Mat3,
+}
+
+impl Body {
+    /// Point fixe du monde, ou corps immobile.
+    const STATIC: Self = Self {
+        position: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        inverse_mass: 0.0,
+        inverse_inertia: Mat3::ZERO,
+    };
+
+    fn world_inverse_inertia(&self) -> Mat3 {
+        let r = Mat3::from_quat(self.rotation);
+        r * self.inverse_inertia * r.transpose()
+    }
+
+    /// Masse inverse généralisée pour une correction `normal` appliquée au bras `arm`.
+    fn positional_weight(&self, arm: Vec3, normal: Vec3) -> f32 {
+        let torque = arm.cross(normal);
+        self.inverse_mass + torque.dot(self.world_inverse_inertia() * torque)
+    }
+
+    /// Masse inverse généralisée pour une rotation d’axe `axis`.
+    fn angular_weight(&self, axis: Vec3) -> f32 {
+        axis.dot(self.world_inverse_inertia() * axis)
+    }
+
+    /// Applique l’impulsion de position `impulse` au bras `arm`.
+    fn apply_positional(&mut self, arm: Vec3, impulse: Vec3) {
+        self.position += impulse * self.inverse_mass;
+        self.rotate(self.world_inverse_inertia() * arm.cross(impulse));
+    }
+
+    /// Applique l’impulsion angulaire `impulse`.
+    fn apply_angular(&mut self, impulse: Vec3) {
+        self.rotate(self.world_inverse_inertia() * impulse);
+    }
+
+    fn rotate(&mut self, angle: Vec3) {
+        if angle != Vec3::ZERO {
+            let q = Quat::from_xyzw(angle.x, angle.y, angle.z, 0.0) * self.rotation;
+            self.rotation = (self.rotation + q * 0.5).normalize();
+        }
+    }
+}
+
+/// Multiplicateur de Lagrange d’une passe : `Δλ = (−C − α̃ λ − γ Ċh) / ((1 + γ) w + α̃)`.
+fn lagrange_step(c: f32, lambda: f32, weight: f32, alpha: f32, gamma: f32, rate: f32) -> f32 {
+    let denominator = (1.0 + gamma) * weight + alpha;
+    if denominator <= f32::EPSILON {
+        return 0.0;
+    }
+    (-c - alpha * lambda - gamma * rate) / denominator
+}
+
+/// Projette une contrainte de position `|p_a − p_b| = length` ; renvoie la violation.
+#[allow(clippy::too_many_arguments)]
+fn solve_positional(
+    a: &mut Body,
+    b: &mut Body,
+    anchors: (Vec3, Vec3),
+    length: f32,
+    lambda: &mut f32,
+    alpha: f32,
+    gamma: f32,
+    relative_velocity: Vec3,
+    topology: &DomainTopology,
+) {
+    let arm_a = a.rotation * anchors.0;
+    let arm_b = b.rotation * anchors.1;
+    let delta = topology.displacement(b.position + arm_b, a.position + arm_a);
+    let distance = delta.length();
+    let Some(normal) = delta.try_normalize() else {
+        return;
+    };
+    let c = distance - length;
+    let weight = a.positional_weight(arm_a, normal) + b.positional_weight(arm_b, normal);
+    let step = lagrange_step(
+        c,
+        *lambda,
+        weight,
+        alpha,
+        gamma,
+        normal.dot(relative_velocity),
+    );
+    *lambda += step;
+    a.apply_positional(arm_a, normal * step);
+    b.apply_positional(arm_b, -normal * step);
+}
+
+/// Fait tourner les deux corps de la rotation `error` (repère monde) au plus.
+fn solve_angular(a: &mut Body, b: &mut Body, error: Vec3, lambda: &mut f32, alpha: f32) {
+    let angle = error.length();
+    let Some(axis) = error.try_normalize() else {
+        return;
+    };
+    let weight = a.angular_weight(axis) + b.angular_weight(axis);
+    let step = lagrange_step(angle, *lambda, weight, alpha, 0.0, 0.0);
+    *lambda += step;
+    a.apply_angular(-axis * step);
+    b.apply_angular(axis * step);
+}
+
+/// Rotation (repère monde) amenant `body_a` à l’orientation relative `rest` par rapport à `b`.
+fn orientation_error(a: &Body, b: &Body, rest: Quat) -> Vec3 {
+    let mut error = b.rotation * rest * a.rotation.inverse();
+    if error.w < 0.0 {
+        error = -error;
+    }
+    error.to_scaled_axis()
+}
+
+/// Violations de position et d’orientation d’une contrainte.
+fn violation(constraint: &Constraint, a: &Body, b: &Body, topology: &DomainTopology) -> (f32, f32) {
+    let delta = topology.displacement(
+        b.position + b.rotation * constraint.anchor_b,
+        a.position + a.rotation * constraint.anchor_a,
+    );
+    match constraint.kind {
+        ConstraintKind::Distance { length } | ConstraintKind::Spring { length, .. } => {
+            ((delta.length() - length).abs(), 0.0)
+        }
+        ConstraintKind::Hinge { axis_a, axis_b } => {
+            let axis_a = (a.rotation * axis_a).normalize_or_zero();
+            let axis_b = (b.rotation * axis_b).normalize_or_zero();
+            (delta.length(), axis_a.dot(axis_b).clamp(-1.0, 1.0).acos())
+        }
+        ConstraintKind::Fixed { .. } => (
+            delta.length(),
+            constraint
+                .rest_rotation
+                .map_or(0.0, |rest| orientation_error(a, b, rest).length()),
+        ),
+    }
+}
+
+type BodyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Transform,
+        Option<&'static mut Velocity>,
+        Option<&'static Mass>,
+        Option<&'static Inertia>,
+    ),
+>;
+
+/// Système : projette les contraintes après l’intégration du pas fixe.
+pub fn solve_constraints(
+    mut constraints: Query<&mut Constraint>,
+    mut bodies: BodyQuery,
+    config: Res<ConstraintConfig>,
+    topology: Option<Res<DomainTopology>>,
+    time: Res<Time>,
+    mut stats: ResMut<ConstraintStats>,
+) {
+    let h = time.delta_secs();
+    if !config.enabled || h <= 0.0 || constraints.is_empty() {
+        *stats = ConstraintStats::default();
+        return;
+    }
+    let topology = topology.as_deref().cloned().unwrap_or_default();
+
+    let mut states: HashMap<Entity, (Body, Vec3)> = HashMap::new();
+    let mut body = |entity: Entity| -> Option<(Body, Vec3)> {
+        if let Some(state) = states.get(&entity) {
+            return Some(*state);
+        }
+        let (_, transform, velocity, mass, inertia) = bodies.get(entity).ok()?;
+        let inverse_mass = match mass {
+            Some(mass) if velocity.is_some() && mass.value > 0.0 => 1.0 / mass.value,
+            _ => 0.0,
+        };
+        let tensor = inertia.copied().unwrap_or_default().tensor;
+        let inverse_inertia = if inverse_mass > 0.0 {
+            Inertia::invert(tensor)
+        } else {
+            None
+        };
+        let state = (
+            Body {
+                position: transform.translation,
+                rotation: transform.rotation,
+                inverse_mass,
+                inverse_inertia: inverse_inertia.unwrap_or(Mat3::ZERO),
+            },
+            velocity.map_or(Vec3::ZERO, |v| v.linear),
+        );
+        states.insert(entity, state);
+        Some(state)
+    };
+
+    // Contraintes actives, avec leurs corps résolus.
+    let mut active = Vec::new();
+    let mut invalid = 0;
+    for (index, constraint) in constraints.iter().enumerate() {
+        if !constraint.enabled {
+            continue;
+        }
+        let a = body(constraint.body_a);
+        let b = constraint.body_b.map(&mut body);
+        match (a, b) {
+            (Some(_), None) if constraint.body_b.is_some() => invalid += 1,
+            (Some(_), _) => active.push(index),
+            (None, _) => invalid += 1,
+        }
+    }
+    if invalid > 0 {
+        warn!(target: "structure", invalid, "contraintes vers des entités introuvables");
+    }
+    let initial: HashMap<Entity, Body> = states.iter().map(|(e, (s, _))| (*e, *s)).collect();
+
+    let mut items: Vec<Mut<Constraint>> = constraints.iter_mut().collect();
+    for &index in &active {
+        let constraint = &mut items[index];
+        if let ConstraintKind::Fixed {
+            lock_rotation: true,
+        } = constraint.kind
+        {
+            if constraint.rest_rotation.is_none() {
+                let a = states[&constraint.body_a].0.rotation;
+                let b = constraint
+                    .body_b
+                    .map_or(Quat::IDENTITY, |b| states[&b].0.rotation);
+                constraint.rest_rotation = Some(b.inverse() * a);
+            }
+        }
+    }
+
+    let h2 = h * h;
+    let mut lambdas = vec![(0.0f32, 0.0f32); items.len()];
+    let iterations = config.iterations.max(1);
+    for _ in 0..iterations {
+        for &index in &active {
+            let constraint = &items[index];
+            let (mut a, velocity_a) = states[&constraint.body_a];
+            let (mut b, velocity_b) = match constraint.body_b {
+                Some(entity) => states[&entity],
+                None => (Body::STATIC, Vec3::ZERO),
+            };
+            let anchors = (constraint.anchor_a, constraint.anchor_b);
+            let alpha = constraint.compliance.unwrap_or(config.compliance).max(0.0) / h2;
+            let (linear, angular) = &mut lambdas[index];
+
+            match constraint.kind {
+                ConstraintKind::Distance { length } => solve_positional(
+                    &mut a,
+                    &mut b,
+                    anchors,
+                    length,
+                    linear,
+                    alpha,
+                    0.0,
+                    Vec3::ZERO,
+                    &topology,
+                ),
+                ConstraintKind::Spring {
+                    length,
+                    stiffness,
+                    damping,
+                } => {
+                    let alpha = if stiffness > 0.0 {
+                        1.0 / (stiffness * h2)
+                    } else {
+                        f32::INFINITY
+                    };
+                    if alpha.is_finite() {
+                        solve_positional(
+                            &mut a,
+                            &mut b,
+                            anchors,
+                            length,
+                            linear,
+                            alpha,
+                            alpha * damping.max(0.0) * h,
+                            (velocity_a - velocity_b) * h,
+                            &topology,
+                        );
+                    }
+                }
+                ConstraintKind::Hinge { axis_a, axis_b } => {
+                    let world_a = (a.rotation * axis_a).normalize_or_zero();
+                    let world_b = (b.rotation * axis_b).normalize_or_zero();
+                    solve_angular(&mut a, &mut b, world_a.cross(world_b), angular, alpha);
+                    solve_positional(
+                        &mut a,
+                        &mut b,
+                        anchors,
+                        0.0,
+                        linear,
+                        alpha,
+                        0.0,
+                        Vec3::ZERO,
+                        &topology,
+                    );
+                }
+                ConstraintKind::Fixed { .. } => {
+                    if let Some(rest) = constraint.rest_rotation {
+                        let error = orientation_error(&a, &b, rest);
+                        solve_angular(&mut a, &mut b, error, angular, alpha);
+                    }
+                    solve_positional(
+                        &mut a,
+                        &mut b,
+                        anchors,
+                        0.0,
+                        linear,
+                        alpha,
+                        0.0,
+                        Vec3::ZERO,
+                        &topology,
+                    );
+                }
+            }
+
+            states.insert(constraint.body_a, (a, velocity_a));
+            if let Some(entity) = constraint.body_b {
+                states.insert(entity, (b, velocity_b));
+            }
+        }
+    }
+
+    // Résidus et forces de contrainte.
+    let mut total = 0.0;
+    let mut summary = ConstraintStats {
+        constraints: active.len(),
+        invalid,
+        iterations,
+        ..default()
+    };
+    for &index in &active {
+        let constraint = &mut items[index];
+        let a = states[&constraint.body_a].0;
+        let b = match constraint.body_b {
+            Some(entity) => states[&entity].0,
+            None => Body::STATIC,
+        };
+        let (residual, angular_residual) = violation(constraint, &a, &b, &topology);
+        let (linear, angular) = lambdas[index];
+        constraint.residual = residual;
+        constraint.angular_residual = angular_residual;
+        constraint.force = linear.abs().max(angular.abs()) / h2;
+        total += residual;
+        summary.max_residual = summary.max_residual.max(residual);
+        summary.max_angular_residual = summary.max_angular_residual.max(angular_residual);
+    }
+    summary.mean_residual = if active.is_empty() {
+        0.0
+    } else {
+        total / active.len() as f32
+    };
+    *stats = summary;
+
+    // Report des corrections sur les corps et leurs vitesses.
+    for (entity, (state, _)) in &states {
+        if state.inverse_mass <= 0.0 {
+            continue;
+        }
+        let Ok((_, mut transform, velocity, ..)) = bodies.get_mut(*entity) else {
+            continue;
+        };
+        let correction = state.position - initial[entity].position;
+        let rotation = state.rotation * initial[entity].rotation.inverse();
+        transform.translation = state.position;
+        transform.rotation = state.rotation;
+        if let Some(mut velocity) = velocity {
+            velocity.linear += correction / h;
+            let rotation = if rotation.w < 0.0 {
+                -rotation
+            } else {
+                rotation
+            };
+            velocity.angular += rotation.to_scaled_axis() / h;
+        }
+    }
+
+    debug!(
+        target: "structure",
+        constraints = stats.constraints,
+        max_residual = stats.max_residual,
+        max_angular_residual = stats.max_angular_residual,
+        "contraintes résolues"
+    );
+}
+
+/// Enregistre le solveur de contraintes.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<ConstraintKind>()
+        .register_type::<Constraint>()
+        .register_type::<ConstraintConfig>()
+        .register_type::<ConstraintStats>()
+        .init_resource::<ConstraintConfig>()
+        .init_resource::<ConstraintStats>()
+        .add_systems(
+            FixedUpdate,
+            solve_constraints
+                .after(DynamicsSet::Integrate)
+                .before(DynamicsSet::Collisions),
+        );
+}
@@ -0,0 +1,410 @@
+//! Index spatial des entités : grille uniforme de cellules.
+//!
+//! [`SpatialIndex`] range la position de chaque entité indexée dans une cellule cubique de
+//! côté `cell_size`. Il est reconstruit dès qu’une position indexée change (après les
+//! conditions aux limites du pas fixe, puis en début de frame) et répond aux requêtes de
+//! voisinage via le paramètre système [`SpatialQuery`] :
+//! - voisins dans un rayon ([`SpatialIndex::within_radius`]),
+//! - k plus proches voisins ([`SpatialIndex::nearest`]),
+//! - entités dans une boîte ([`SpatialIndex::in_box`]).
+//!
+//! Les distances suivent la [`DomainTopology`] : en domaine périodique, la grille se
+//! referme sur elle-même et les distances utilisent l’image minimale.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::EntityTag;
+use crate::dynamics::{Collider, DynamicsSet, Velocity};
+use crate::substrate::topology::DomainTopology;
+
+/// Composant marqueur : indexe une entité qui ne serait pas retenue autrement.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct SpatialIndexed;
+
+/// Entités indexées : corps dynamiques, colliders, entités structurelles et entités marquées.
+type IndexedFilter = Or<(
+    With<Velocity>,
+    With<Collider>,
+    With<EntityTag>,
+    With<SpatialIndexed>,
+)>;
+
+/// Ressource : paramètres de l’index spatial.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct SpatialIndexConfig {
+    pub enabled: bool,
+    /// Côté d’une cellule ; idéalement de l’ordre du rayon de requête typique.
+    pub cell_size: f32,
+}
+
+impl Default for SpatialIndexConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cell_size: 1.0,
+        }
+    }
+}
+
+/// Ressource : grille uniforme des positions indexées.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SpatialIndex {
+    /// Côtés d’une cellule (égaux hors domaine périodique).
+    cell: Vec3,
+    topology: DomainTopology,
+    /// Nombre de cellules par axe en domaine périodique.
+    periodic: Option<(Vec3, IVec3)>,
+    entries: Vec<(Entity, Vec3)>,
+    cells: HashMap<IVec3, Vec<u32>>,
+    /// Cellules extrêmes occupées.
+    bounds: Option<(IVec3, IVec3)>,
+}
+
+impl SpatialIndex {
+    /// Construit l’index de `entries` avec des cellules de côté `cell_size`.
+    pub fn build(
+        entries: impl IntoIterator<Item = (Entity, Vec3)>,
+        cell_size: f32,
+        topology: &DomainTopology,
+    ) -> Self {
+        let mut index = Self {
+            cell: Vec3::splat(cell_size.max(1e-3)),
+            topology: topology.clone(),
+            ..default()
+        };
+        let periodic = topology.periodic_axes();
+        if let DomainTopology::Periodic { min, max } = *topology {
+            if periodic.any() {
+                let size = max - min;
+                let dims = (size / index.cell).floor().as_ivec3().max(IVec3::ONE);
+                // Les axes non périodiques (taille nulle) gardent `dims = 0`.
+                let dims = IVec3::select(periodic, dims, IVec3::ZERO);
+                index.periodic = Some((min, dims));
+                // Cellules ajustées pour paver exactement le tore.
+                index.cell = Vec3::select(periodic, size / dims.as_vec3(), index.cell);
+            }
+        }
+
+        for (entity, position) in entries {
+            let position = index.topology.wrap(position);
+            let key = index.key(position);
+            index
+                .cells
+                .entry(key)
+                .or_default()
+                .push(index.entries.len() as u32);
+            index.entries.push((entity, position));
+            index.bounds = Some(match index.bounds {
+                Some((lo, hi)) => (lo.min(key), hi.max(key)),
+                None => (key, key),
+            });
+        }
+        index
+    }
+
+    /// Nombre d’entités indexées.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `true` si aucune entité n’est indexée.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Nombre de cellules occupées.
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Côtés effectifs d’une cellule.
+    pub fn cell_size(&self) -> Vec3 {
+        self.cell
+    }
+
+    /// Position indexée de `entity` (recherche linéaire).
+    pub fn position(&self, entity: Entity) -> Option<Vec3> {
+        self.entries
+            .iter()
+            .find(|(e, _)| *e == entity)
+            .map(|(_, p)| *p)
+    }
+
+    fn key(&self, position: Vec3) -> IVec3 {
+        match self.periodic {
+            Some((min, dims)) => wrap_key(((position - min) / self.cell).floor().as_ivec3(), dims),
+            None => (position / self.cell).floor().as_ivec3(),
+        }
+    }
+
+    /// Décalages extrêmes, en cellules, des cellules atteignables depuis `center` : jusqu’aux
+    /// cellules occupées extrêmes sur les axes ouverts, un tour complet sur les axes
+    /// périodiques (chaque cellule une seule fois).
+    fn offset_range(&self, center: IVec3) -> Option<(IVec3, IVec3)> {
+        let (min, max) = self.bounds?;
+        let (mut lo, mut hi) = (min - center, max - center);
+        if let Some((_, dims)) = self.periodic {
+            let wrapped = dims.cmpgt(IVec3::ZERO);
+            lo = IVec3::select(wrapped, -(dims - IVec3::ONE) / 2, lo);
+            hi = IVec3::select(wrapped, dims / 2, hi);
+        }
+        Some((lo, hi))
+    }
+
+    /// Décalage de `key` par rapport à `center`, ramené dans `[lo, hi]` sur les axes
+    /// périodiques.
+    fn offset(&self, center: IVec3, key: IVec3, lo: IVec3) -> IVec3 {
+        let offset = key - center;
+        match self.periodic {
+            Some((_, dims)) => IVec3::select(
+                dims.cmpgt(IVec3::ZERO),
+                (offset - lo).rem_euclid(dims.max(IVec3::ONE)) + lo,
+                offset,
+            ),
+            None => offset,
+        }
+    }
+
+    /// Cellule de décalage `offset` par rapport à `center`.
+    fn cell_at(&self, center: IVec3, offset: IVec3) -> IVec3 {
+        match self.periodic {
+            Some((_, dims)) => wrap_key(center + offset, dims),
+            None => center + offset,
+        }
+    }
+
+    /// Cellules distinctes à au plus `reach` cellules (norme infinie) de `center`.
+    fn cells_around(&self, center: IVec3, reach: IVec3) -> Vec<IVec3> {
+        let Some((range_lo, range_hi)) = self.offset_range(center) else {
+            return Vec::new();
+        };
+        let (lo, hi) = (range_lo.max(-reach), range_hi.min(reach));
+        if volume(lo, hi) > self.cells.len() {
+            // Cube plus vaste que la grille occupée : les cellules occupées sont filtrées.
+            return self
+                .cells
+                .keys()
+                .copied()
+                .filter(|&key| {
+                    let offset = self.offset(center, key, range_lo);
+                    offset.cmpge(lo).all() && offset.cmple(hi).all()
+                })
+                .collect();
+        }
+
+        let mut keys = Vec::with_capacity(volume(lo, hi));
+        for x in lo.x..=hi.x {
+            for y in lo.y..=hi.y {
+                for z in lo.z..=hi.z {
+                    keys.push(self.cell_at(center, IVec3::new(x, y, z)));
+                }
+            }
+        }
+        keys
+    }
+
+    /// Cellules à exactement `ring` cellules (norme infinie) de `center`.
+    fn shell(&self, center: IVec3, ring: i32, lo: IVec3, hi: IVec3) -> Vec<IVec3> {
+        let (lo, hi) = (lo.max(IVec3::splat(-ring)), hi.min(IVec3::splat(ring)));
+        let mut keys = Vec::new();
+        for x in lo.x..=hi.x {
+            for y in lo.y..=hi.y {
+                if x.abs() == ring || y.abs() == ring {
+                    keys.extend((lo.z..=hi.z).map(|z| self.cell_at(center, IVec3::new(x, y, z))));
+                } else {
+                    // Intérieur de la colonne : seules les faces `z = ±ring` (`ring > 0` ici).
+                    keys.extend(
+                        [-ring, ring]
+                            .into_iter()
+                            .filter(|z| (lo.z..=hi.z).contains(z))
+                            .map(|z| self.cell_at(center, IVec3::new(x, y, z))),
+                    );
+                }
+            }
+        }
+        keys
+    }
+
+    fn members<'a>(&'a self, keys: &'a [IVec3]) -> impl Iterator<Item = (Entity, Vec3)> + 'a {
+        keys.iter()
+            .filter_map(|key| self.cells.get(key))
+            .flatten()
+            .map(|&i| self.entries[i as usize])
+    }
+
+    /// Entités à une distance au plus `radius` de `center`, avec leur distance.
+    pub fn within_radius(&self, center: Vec3, radius: f32) -> Vec<(Entity, f32)> {
+        let center = self.topology.wrap(center);
+        let reach = (radius / self.cell).ceil().as_ivec3();
+        let keys = self.cells_around(self.key(center), reach);
+        self.members(&keys)
+            .filter_map(|(entity, position)| {
+                let distance = self.topology.distance(center, position);
+                (distance <= radius).then_some((entity, distance))
+            })
+            .collect()
+    }
+
+    /// Les `k` entités les plus proches de `center`, triées par distance croissante.
+    pub fn nearest(&self, center: Vec3, k: usize) -> Vec<(Entity, f32)> {
+        let k = k.min(self.entries.len());
+        if k == 0 {
+            return Vec::new();
+        }
+        let center = self.topology.wrap(center);
+        let origin = self.key(center);
+        let Some((lo, hi)) = self.offset_range(origin) else {
+            return Vec::new();
+        };
+        let limit = lo.abs().max(hi.abs()).max_element();
+
+        let mut best: Vec<(Entity, f32)> = Vec::with_capacity(k + 1);
+        let visit = |best: &mut Vec<(Entity, f32)>, keys: &[IVec3]| {
+            for (entity, position) in self.members(keys) {
+                let distance = self.topology.distance(center, position);
+                if best.len() == k && distance >= best[k - 1].1 {
+                    continue;
+                }
+                let at = best.partition_point(|(_, d)| *d <= distance);
+                best.insert(at, (entity, distance));
+                best.truncate(k);
+            }
+        };
+        // Un point à `ring` cellules (norme infinie) est à au moins `(ring - 1) · cell_size`.
+        let done = |best: &Vec<(Entity, f32)>, ring: i32| {
+            best.len() == k && best[k - 1].1 <= (ring as f32 - 1.0) * self.cell.min_element()
+        };
+
+        for ring in 0..=limit {
+            if done(&best, ring) {
+                break;
+            }
+            let (inner_lo, inner_hi) = (
+                lo.max(IVec3::splat(1 - ring)),
+                hi.min(IVec3::splat(ring - 1)),
+            );
+            let shell = volume(lo.max(IVec3::splat(-ring)), hi.min(IVec3::splat(ring)))
+                - volume(inner_lo, inner_hi);
+            if shell > self.cells.len() {
+                // Anneaux plus vastes que la grille occupée : les cellules occupées restantes
+                // sont parcourues par anneau croissant.
+                let mut rest: Vec<(i32, IVec3)> = self
+                    .cells
+                    .keys()
+                    .map(|&key| (self.offset(origin, key, lo).abs().max_element(), key))
+                    .filter(|(r, _)| *r >= ring)
+                    .collect();
+                rest.sort_unstable_by_key(|(r, _)| *r);
+                for group in rest.chunk_by(|a, b| a.0 == b.0) {
+                    if done(&best, group[0].0) {
+                        break;
+                    }
+                    let keys: Vec<IVec3> = group.iter().map(|(_, key)| *key).collect();
+                    visit(&mut best, &keys);
+                }
+                break;
+            }
+            visit(&mut best, &self.shell(origin, ring, lo, hi));
+        }
+        best
+    }
+
+    /// Entités dont la position est dans la boîte de centre `center` et de demi-côtés `half_extents`.
+    pub fn in_box(&self, center: Vec3, half_extents: Vec3) -> Vec<Entity> {
+        let center = self.topology.wrap(center);
+        let half_extents = half_extents.abs();
+        let reach = (half_extents / self.cell).ceil().as_ivec3();
+        let keys = self.cells_around(self.key(center), reach);
+        self.members(&keys)
+            .filter(|(_, position)| {
+                self.topology
+                    .displacement(center, *position)
+                    .abs()
+                    .cmple(half_extents)
+                    .all()
+            })
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+}
+
+/// Nombre de cellules du pavé `[lo, hi]`.
+fn volume(lo: IVec3, hi: IVec3) -> usize {
+    let size = (hi - lo + IVec3::ONE).max(IVec3::ZERO);
+    size.x as usize * size.y as usize * size.z as usize
+}
+
+/// Replie `key` sur les axes périodiques (`dims > 0`).
+fn wrap_key(key: IVec3, dims: IVec3) -> IVec3 {
+    IVec3::select(
+        dims.cmpgt(IVec3::ZERO),
+        key.rem_euclid(dims.max(IVec3::ONE)),
+        key,
+    )
+}
+
+/// Paramètre système : requêtes de voisinage sur l’[`SpatialIndex`] courant.
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w> {
+    index: Res<'w, SpatialIndex>,
+}
+
+impl Deref for SpatialQuery<'_> {
+    type Target = SpatialIndex;
+
+    fn deref(&self) -> &SpatialIndex {
+        &self.index
+    }
+}
+
+/// Système : reconstruit l’index lorsque les positions, la topologie ou la configuration changent.
+pub fn update_spatial_index(
+    query: Query<(Entity, &Transform), IndexedFilter>,
+    changed: Query<(), (Changed<Transform>, IndexedFilter)>,
+    mut removed: RemovedComponents<Transform>,
+    config: Res<SpatialIndexConfig>,
+    topology: Option<Res<DomainTopology>>,
+    mut index: ResMut<SpatialIndex>,
+) {
+    if !config.enabled {
+        return;
+    }
+    let topology_changed = topology.as_ref().is_some_and(|t| t.is_changed());
+    let removed = removed.read().count() > 0;
+    let stale = index.len() != query.iter().len();
+    if !(config.is_changed() || topology_changed || removed || stale || !changed.is_empty()) {
+        return;
+    }
+
+    *index = SpatialIndex::build(
+        query.iter().map(|(entity, t)| (entity, t.translation)),
+        config.cell_size,
+        &topology.as_deref().cloned().unwrap_or_default(),
+    );
+    debug!(
+        target: "structure",
+        entities = index.len(),
+        cells = index.cell_count(),
+        "index spatial reconstruit"
+    );
+}
+
+/// Enregistre l’index spatial.
+pub(crate) fn init(app: &mut App) {
+    app.register_type::<SpatialIndexed>()
+        .register_type::<SpatialIndexConfig>()
+        .init_resource::<SpatialIndexConfig>()
+        .init_resource::<SpatialIndex>()
+        .add_systems(PreUpdate, update_spatial_index)
+        .add_systems(
+            FixedUpdate,
+            update_spatial_index.after(DynamicsSet::Boundaries),
+        );
+}
@@ -8,6 +8,12 @@
 use bevy::prelude::*;
 use tracing::{debug, info};
 
+pub mod constraint;
+pub mod lattice;
+
+pub use constraint::{Constraint, ConstraintConfig, ConstraintKind, ConstraintStats};
+pub use lattice::{SpatialIndex, SpatialIndexConfig, SpatialIndexed, SpatialQuery};
+
 /// Composant de base : identifie une entité dans le moteur.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -39,6 +45,8 @@ pub fn init(app: &mut App) {
 
     app.register_type::<EntityTag>()
         .add_systems(Update, update_positions);
+    lattice::init(app);
+    constraint::init(app);
 
     info!(
         target: "structure",
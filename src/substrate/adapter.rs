@@ -0,0 +1,381 @@
+//! Sélection configurable de l’adaptateur GPU et rapport de capacités.
+//!
+//! [`GpuConfig`] décrit les contraintes (backends, préférence d’énergie, filtre de nom,
+//! fonctionnalités et limites requises). [`AdapterReport`] énumère tous les adaptateurs
+//! visibles, avec leurs informations, leurs limites et la raison précise d’un éventuel rejet.
+
+use std::fmt;
+
+use bevy::prelude::*;
+use tracing::warn;
+use wgpu::{
+    Adapter, AdapterInfo, Backends, DeviceType, Features, Instance, Limits, PowerPreference,
+};
+
+/// Configuration de la sélection d’adaptateur GPU.
+#[derive(Resource, Clone, Debug)]
+pub struct GpuConfig {
+    /// Backends autorisés.
+    pub backends: Backends,
+    /// Préférence d’énergie, utilisée pour classer les candidats acceptés.
+    pub power_preference: PowerPreference,
+    /// Sous-chaîne (insensible à la casse) que le nom de l’adaptateur doit contenir.
+    pub adapter_name: Option<String>,
+    /// Fonctionnalités que l’adaptateur doit supporter.
+    pub required_features: Features,
+    /// Limites minimales que l’adaptateur doit offrir.
+    pub required_limits: Limits,
+    /// Autorise les adaptateurs logiciels (`DeviceType::Cpu`, ex. llvmpipe).
+    pub allow_fallback: bool,
+}
+
+impl Default for GpuConfig {
+    fn default() -> Self {
+        Self {
+            backends: Backends::all(),
+            power_preference: PowerPreference::HighPerformance,
+            adapter_name: None,
+            required_features: Features::empty(),
+            required_limits: Limits::default(),
+            allow_fallback: true,
+        }
+    }
+}
+
+impl GpuConfig {
+    /// Configuration par défaut, surchargée par l’environnement :
+    ///
+    /// - `VOID_GPU_BACKENDS` : liste séparée par des virgules (`vulkan,gl,…`)
+    /// - `VOID_GPU_POWER` : `high`, `low` ou `none`
+    /// - `VOID_GPU_ADAPTER` : filtre sur le nom de l’adaptateur
+    /// - `VOID_GPU_FEATURES` : noms de fonctionnalités wgpu requises (`SHADER_F64,…`)
+    /// - `VOID_GPU_LIMITS` : limites requises, voir [`parse_limits`]
+    /// - `VOID_GPU_ALLOW_FALLBACK` : `0`/`false` pour refuser les adaptateurs logiciels
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Ok(backends) = std::env::var("VOID_GPU_BACKENDS") {
+            config.backends = Backends::from_comma_list(&backends);
+        }
+        if let Ok(power) = std::env::var("VOID_GPU_POWER") {
+            config.power_preference = match power.to_lowercase().as_str() {
+                "low" => PowerPreference::LowPower,
+                "none" => PowerPreference::None,
+                _ => PowerPreference::HighPerformance,
+            };
+        }
+        if let Ok(name) = std::env::var("VOID_GPU_ADAPTER") {
+            if !name.trim().is_empty() {
+                config.adapter_name = Some(name.trim().to_string());
+            }
+        }
+        if let Ok(features) = std::env::var("VOID_GPU_FEATURES") {
+            for name in features.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                match Features::from_name(name) {
+                    Some(feature) => config.required_features.insert(feature),
+                    None => {
+                        warn!(target: "substrate", feature = name, "unknown wgpu feature ignored")
+                    }
+                }
+            }
+        }
+        if let Ok(limits) = std::env::var("VOID_GPU_LIMITS") {
+            config.required_limits = parse_limits(&limits);
+        }
+        if let Ok(fallback) = std::env::var("VOID_GPU_ALLOW_FALLBACK") {
+            config.allow_fallback = !matches!(fallback.as_str(), "0" | "false" | "no");
+        }
+
+        config
+    }
+}
+
+/// Limites requises décrites par `spec` : un préréglage facultatif (`default`, `downlevel`
+/// ou `webgl2`) suivi de surcharges `nom=valeur`, séparés par des virgules, par exemple
+/// `downlevel,max_storage_buffer_binding_size=268435456`.
+///
+/// Les entrées inconnues ou invalides sont ignorées avec un avertissement.
+pub fn parse_limits(spec: &str) -> Limits {
+    let mut limits = Limits::default();
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let Some((name, value)) = entry.split_once('=') else {
+            match entry.to_lowercase().as_str() {
+                "default" => limits = Limits::default(),
+                "downlevel" => limits = Limits::downlevel_defaults(),
+                "webgl2" => limits = Limits::downlevel_webgl2_defaults(),
+                _ => {
+                    warn!(target: "substrate", preset = entry, "unknown wgpu limits preset ignored")
+                }
+            }
+            continue;
+        };
+        let (name, value) = (name.trim(), value.trim());
+        let Ok(value) = value.parse::<u64>() else {
+            warn!(target: "substrate", limit = name, value, "invalid wgpu limit value ignored");
+            continue;
+        };
+        if !set_limit(&mut limits, name, value) {
+            warn!(target: "substrate", limit = name, "unknown wgpu limit ignored");
+        }
+    }
+    limits
+}
+
+/// Fixe la limite `name` ; `false` si elle n’est pas reconnue.
+fn set_limit(limits: &mut Limits, name: &str, value: u64) -> bool {
+    let narrow = u32::try_from(value).unwrap_or(u32::MAX);
+    match name {
+        "max_texture_dimension_1d" => limits.max_texture_dimension_1d = narrow,
+        "max_texture_dimension_2d" => limits.max_texture_dimension_2d = narrow,
+        "max_texture_dimension_3d" => limits.max_texture_dimension_3d = narrow,
+        "max_bind_groups" => limits.max_bind_groups = narrow,
+        "max_storage_buffers_per_shader_stage" => {
+            limits.max_storage_buffers_per_shader_stage = narrow
+        }
+        "max_uniform_buffer_binding_size" => limits.max_uniform_buffer_binding_size = narrow,
+        "max_storage_buffer_binding_size" => limits.max_storage_buffer_binding_size = narrow,
+        "max_buffer_size" => limits.max_buffer_size = value,
+        "max_compute_workgroup_storage_size" => limits.max_compute_workgroup_storage_size = narrow,
+        "max_compute_invocations_per_workgroup" => {
+            limits.max_compute_invocations_per_workgroup = narrow
+        }
+        "max_compute_workgroup_size_x" => limits.max_compute_workgroup_size_x = narrow,
+        "max_compute_workgroup_size_y" => limits.max_compute_workgroup_size_y = narrow,
+        "max_compute_workgroup_size_z" => limits.max_compute_workgroup_size_z = narrow,
+        "max_compute_workgroups_per_dimension" => {
+            limits.max_compute_workgroups_per_dimension = narrow
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Limite insuffisante d’un adaptateur.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LimitShortfall {
+    pub name: &'static str,
+    pub required: u64,
+    pub available: u64,
+}
+
+/// Raison du rejet d’un adaptateur candidat.
+#[derive(Clone, Debug)]
+pub enum AdapterRejection {
+    /// Le nom ne contient pas le filtre configuré.
+    NameMismatch { filter: String },
+    /// Fonctionnalités requises non supportées.
+    MissingFeatures(Features),
+    /// Limites requises non atteintes.
+    InsufficientLimits(Vec<LimitShortfall>),
+    /// Adaptateur logiciel alors que `allow_fallback` est désactivé.
+    FallbackDisallowed,
+}
+
+impl fmt::Display for AdapterRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameMismatch { filter } => write!(f, "name does not match filter `{filter}`"),
+            Self::MissingFeatures(features) => {
+                let names: Vec<_> = features.iter_names().map(|(name, _)| name).collect();
+                write!(f, "missing features: {}", names.join(", "))
+            }
+            Self::InsufficientLimits(shortfalls) => {
+                write!(f, "insufficient limits:")?;
+                for shortfall in shortfalls {
+                    write!(
+                        f,
+                        " {} (required {}, available {})",
+                        shortfall.name, shortfall.required, shortfall.available
+                    )?;
+                }
+                Ok(())
+            }
+            Self::FallbackDisallowed => write!(f, "software adapter while fallback is disallowed"),
+        }
+    }
+}
+
+/// Adaptateur énuméré, avec ses capacités et son éventuel rejet.
+#[derive(Clone, Debug)]
+pub struct AdapterCandidate {
+    pub info: AdapterInfo,
+    pub features: Features,
+    pub limits: Limits,
+    pub rejection: Option<AdapterRejection>,
+}
+
+impl AdapterCandidate {
+    /// `true` pour un adaptateur logiciel.
+    pub fn is_fallback(&self) -> bool {
+        self.info.device_type == DeviceType::Cpu
+    }
+}
+
+/// Rapport d’énumération des adaptateurs GPU.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct AdapterReport {
+    pub candidates: Vec<AdapterCandidate>,
+    /// Index du candidat retenu dans `candidates`.
+    pub selected: Option<usize>,
+}
+
+impl AdapterReport {
+    /// Énumère les adaptateurs visibles avec une instance dédiée (rapport CLI).
+    pub fn collect(config: &GpuConfig) -> Self {
+        let instance = super::create_instance(config);
+        Self::evaluate(&instance, config).0
+    }
+
+    /// Énumère et évalue les adaptateurs de `instance`, retourne le rapport et l’adaptateur retenu.
+    pub(crate) fn evaluate(instance: &Instance, config: &GpuConfig) -> (Self, Option<Adapter>) {
+        let mut adapters = instance.enumerate_adapters(config.backends);
+        let candidates: Vec<AdapterCandidate> = adapters
+            .iter()
+            .map(|adapter| {
+                let mut candidate = AdapterCandidate {
+                    info: adapter.get_info(),
+                    features: adapter.features(),
+                    limits: adapter.limits(),
+                    rejection: None,
+                };
+                candidate.rejection = reject(&candidate, config);
+                candidate
+            })
+            .collect();
+
+        let selected = select(&candidates, config.power_preference);
+
+        let adapter = selected.map(|index| adapters.swap_remove(index));
+        (
+            Self {
+                candidates,
+                selected,
+            },
+            adapter,
+        )
+    }
+
+    /// Candidat retenu.
+    pub fn selected(&self) -> Option<&AdapterCandidate> {
+        self.selected.and_then(|index| self.candidates.get(index))
+    }
+
+    /// Candidats rejetés, avec leur raison.
+    pub fn rejected(&self) -> impl Iterator<Item = (&AdapterCandidate, &AdapterRejection)> {
+        self.candidates
+            .iter()
+            .filter_map(|candidate| candidate.rejection.as_ref().map(|r| (candidate, r)))
+    }
+}
+
+impl fmt::Display for AdapterReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.candidates.is_empty() {
+            return writeln!(f, "no GPU adapter enumerated");
+        }
+
+        for (index, candidate) in self.candidates.iter().enumerate() {
+            let info = &candidate.info;
+            let status = match (&candidate.rejection, self.selected == Some(index)) {
+                (_, true) => "selected".to_string(),
+                (Some(rejection), _) => format!("rejected: {rejection}"),
+                (None, false) => "eligible".to_string(),
+            };
+            writeln!(
+                f,
+                "[{index}] {} ({:?}, {:?}) — {status}",
+                info.name, info.backend, info.device_type
+            )?;
+            writeln!(f, "    driver: {} {}", info.driver, info.driver_info)?;
+            writeln!(
+                f,
+                "    limits: texture2d={} storage_buffer={} workgroup={}x{}x{} invocations={}",
+                candidate.limits.max_texture_dimension_2d,
+                candidate.limits.max_storage_buffer_binding_size,
+                candidate.limits.max_compute_workgroup_size_x,
+                candidate.limits.max_compute_workgroup_size_y,
+                candidate.limits.max_compute_workgroup_size_z,
+                candidate.limits.max_compute_invocations_per_workgroup,
+            )?;
+            let features: Vec<_> = candidate
+                .features
+                .iter_names()
+                .map(|(name, _)| name)
+                .collect();
+            writeln!(f, "    features: {}", features.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Index du candidat accepté le mieux classé par [`rank`] (le premier énuméré à rang égal).
+pub fn select(candidates: &[AdapterCandidate], preference: PowerPreference) -> Option<usize> {
+    candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| candidate.rejection.is_none())
+        .min_by_key(|(index, candidate)| (rank(candidate.info.device_type, preference), *index))
+        .map(|(index, _)| index)
+}
+
+/// Raison du rejet de `candidate` par `config`, ou `None` s’il est acceptable.
+///
+/// Les critères sont évalués dans l’ordre : nom, adaptateur logiciel, fonctionnalités,
+/// limites ; seule la première raison est retenue.
+pub fn reject(candidate: &AdapterCandidate, config: &GpuConfig) -> Option<AdapterRejection> {
+    if let Some(filter) = &config.adapter_name {
+        if !candidate
+            .info
+            .name
+            .to_lowercase()
+            .contains(&filter.to_lowercase())
+        {
+            return Some(AdapterRejection::NameMismatch {
+                filter: filter.clone(),
+            });
+        }
+    }
+
+    if candidate.is_fallback() && !config.allow_fallback {
+        return Some(AdapterRejection::FallbackDisallowed);
+    }
+
+    let mut missing = config.required_features;
+    missing.remove(candidate.features);
+    if !missing.is_empty() {
+        return Some(AdapterRejection::MissingFeatures(missing));
+    }
+
+    let mut shortfalls = Vec::new();
+    config.required_limits.check_limits_with_fail_fn(
+        &candidate.limits,
+        false,
+        |name, required, available| {
+            shortfalls.push(LimitShortfall {
+                name,
+                required,
+                available,
+            })
+        },
+    );
+    if !shortfalls.is_empty() {
+        return Some(AdapterRejection::InsufficientLimits(shortfalls));
+    }
+
+    None
+}
+
+/// Rang d’un type d’adaptateur selon la préférence d’énergie (plus petit = préféré).
+/// Les adaptateurs logiciels passent toujours en dernier.
+pub fn rank(device_type: DeviceType, preference: PowerPreference) -> u8 {
+    match (device_type, preference) {
+        (DeviceType::DiscreteGpu, PowerPreference::HighPerformance) => 0,
+        (DeviceType::IntegratedGpu, PowerPreference::HighPerformance) => 1,
+        (DeviceType::IntegratedGpu, PowerPreference::LowPower) => 0,
+        (DeviceType::DiscreteGpu, PowerPreference::LowPower) => 1,
+        (DeviceType::DiscreteGpu | DeviceType::IntegratedGpu, PowerPreference::None) => 0,
+        (DeviceType::VirtualGpu, _) => 2,
+        (DeviceType::Other, _) => 3,
+        (DeviceType::Cpu, _) => 4,
+    }
+}
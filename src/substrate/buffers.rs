@@ -0,0 +1,569 @@
+//! Gestion des buffers GPU du substrat.
+//!
+//! [`GpuBuffers`] associe des jeux de données nommés du moteur (positions des corps,
+//! grilles de champ, phases des oscillateurs) à des buffers de stockage GPU. Il gère :
+//! - le téléversement et le redimensionnement (capacité en puissance de deux), avec pour
+//!   les jeux de données par entité la correspondance indice ↔ [`Entity`],
+//! - la relecture asynchrone via des buffers de staging, sans attendre le GPU : le
+//!   résultat est publié dans [`GpuReadbacks`] lors d’un tick ultérieur,
+//! - le suivi de la mémoire GPU par jeu de données ([`GpuMemoryUsage`]).
+//!
+//! Les buffers sont liés au device courant : après une perte ou un retour sur CPU,
+//! ils sont libérés puis recréés au prochain téléversement.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use bevy::prelude::*;
+use bytemuck::Pod;
+use tracing::{debug, warn};
+
+use super::field::Field;
+use super::{ComputeBackend, GpuContext};
+use crate::dynamics::Mass;
+use crate::function::Oscillator;
+
+/// Jeu de données des positions des corps dynamiques (`vec4<f32>`, `w = 0`).
+pub const DATASET_POSITIONS: &str = "positions";
+/// Jeu de données des phases des oscillateurs (`f32`).
+pub const DATASET_OSCILLATOR_PHASES: &str = "oscillator_phases";
+/// Préfixe des jeux de données de grilles de champ.
+pub const FIELD_DATASET_PREFIX: &str = "field::";
+/// Préfixe des jeux de données du pas GPU des champs (gérés par `step_fields`).
+pub const FIELD_STEP_PREFIX: &str = "field_step::";
+
+/// Capacité minimale d’un buffer (octets).
+const MIN_CAPACITY: u64 = 256;
+
+/// Nom du jeu de données associé au champ `name`.
+pub fn field_dataset(name: &str) -> String {
+    format!("{FIELD_DATASET_PREFIX}{name}")
+}
+
+/// Erreur de gestion des buffers GPU.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GpuBufferError {
+    /// Le jeu de données dépasse la taille maximale d’un buffer de stockage.
+    TooLarge {
+        name: String,
+        bytes: u64,
+        limit: u64,
+    },
+    /// Aucun jeu de données ne porte ce nom.
+    Unknown(String),
+}
+
+impl std::fmt::Display for GpuBufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooLarge { name, bytes, limit } => {
+                write!(f, "dataset `{name}` needs {bytes} bytes (limit {limit})")
+            }
+            Self::Unknown(name) => write!(f, "unknown GPU dataset `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for GpuBufferError {}
+
+/// Relecture en cours : buffer de staging en cours de mappage.
+struct PendingReadback {
+    bytes: u64,
+    /// Entités des éléments copiés, figées à la demande de relecture.
+    entities: Vec<Entity>,
+    done: Arc<OnceLock<Result<(), String>>>,
+}
+
+/// Un jeu de données résidant sur le GPU.
+pub struct GpuDataset {
+    buffer: wgpu::Buffer,
+    staging: Option<wgpu::Buffer>,
+    /// Octets utilisés.
+    len: u64,
+    /// Octets alloués.
+    capacity: u64,
+    /// Nombre de téléversements depuis la création.
+    pub uploads: u64,
+    /// Entité de chaque élément, pour les jeux de données par entité.
+    entities: Vec<Entity>,
+    pending: Option<PendingReadback>,
+}
+
+impl GpuDataset {
+    /// Buffer de stockage (`STORAGE | COPY_DST | COPY_SRC`).
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+
+    /// Octets utilisés.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// `true` si le jeu de données est vide.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Octets alloués sur le GPU (stockage et staging).
+    pub fn allocated(&self) -> u64 {
+        self.capacity + self.staging.as_ref().map_or(0, |staging| staging.size())
+    }
+
+    /// `true` si une relecture est en cours.
+    pub fn readback_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Entité de chaque élément (vide si le jeu de données n’est pas indexé par entité).
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Indice de l’élément de `entity`.
+    pub fn index_of(&self, entity: Entity) -> Option<usize> {
+        self.entities.iter().position(|e| *e == entity)
+    }
+}
+
+/// Ressource : jeux de données GPU nommés.
+#[derive(Resource, Default)]
+pub struct GpuBuffers {
+    device: Option<Arc<wgpu::Device>>,
+    datasets: HashMap<String, GpuDataset>,
+}
+
+impl GpuBuffers {
+    /// Libère tous les buffers si le device de `gpu` n’est pas celui qui les a créés.
+    fn bind(&mut self, gpu: &GpuContext) {
+        if !self
+            .device
+            .as_ref()
+            .is_some_and(|device| Arc::ptr_eq(device, &gpu.device))
+        {
+            if !self.datasets.is_empty() {
+                debug!(target: "substrate", count = self.datasets.len(), "GPU device changed; dropping datasets");
+            }
+            self.datasets.clear();
+            self.device = Some(gpu.device.clone());
+        }
+    }
+
+    /// Téléverse `data` dans le jeu de données `name`, en le créant ou l’agrandissant si besoin.
+    pub fn upload<T: Pod>(
+        &mut self,
+        gpu: &GpuContext,
+        name: &str,
+        data: &[T],
+    ) -> Result<&GpuDataset, GpuBufferError> {
+        let mut bytes: Vec<u8> = bytemuck::cast_slice(data).to_vec();
+        // `write_buffer` exige une taille multiple de `COPY_BUFFER_ALIGNMENT`.
+        let aligned = (bytes.len() as u64).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        bytes.resize(aligned as usize, 0);
+
+        let dataset = self.allocate(gpu, name, aligned)?;
+        gpu.queue.write_buffer(&dataset.buffer, 0, &bytes);
+        dataset.uploads += 1;
+        dataset.entities.clear();
+        Ok(dataset)
+    }
+
+    /// Téléverse un élément par entité : `values[i]` appartient à `entities[i]`.
+    ///
+    /// La correspondance est conservée avec le jeu de données ([`GpuDataset::entities`]) et
+    /// jointe à ses relectures ([`GpuReadbacks::entity_values`]).
+    pub fn upload_entities<T: Pod>(
+        &mut self,
+        gpu: &GpuContext,
+        name: &str,
+        entities: &[Entity],
+        values: &[T],
+    ) -> Result<&GpuDataset, GpuBufferError> {
+        debug_assert_eq!(entities.len(), values.len());
+        self.upload(gpu, name, values)?;
+        let dataset = self.datasets.get_mut(name).expect("dataset uploaded above");
+        dataset.entities.extend_from_slice(entities);
+        Ok(dataset)
+    }
+
+    /// Réserve `bytes` octets dans le jeu de données `name` sans les téléverser (sortie
+    /// d’un noyau de calcul par exemple).
+    pub fn reserve(
+        &mut self,
+        gpu: &GpuContext,
+        name: &str,
+        bytes: u64,
+    ) -> Result<&GpuDataset, GpuBufferError> {
+        let aligned = bytes.next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        self.allocate(gpu, name, aligned).map(|dataset| &*dataset)
+    }
+
+    /// Crée ou agrandit le jeu de données `name` pour `aligned` octets utilisés.
+    fn allocate(
+        &mut self,
+        gpu: &GpuContext,
+        name: &str,
+        aligned: u64,
+    ) -> Result<&mut GpuDataset, GpuBufferError> {
+        self.bind(gpu);
+
+        let limits = gpu.device.limits();
+        let limit = (limits.max_storage_buffer_binding_size as u64).min(limits.max_buffer_size);
+        if aligned > limit {
+            return Err(GpuBufferError::TooLarge {
+                name: name.to_owned(),
+                bytes: aligned,
+                limit,
+            });
+        }
+
+        let needs_alloc = self
+            .datasets
+            .get(name)
+            .is_none_or(|dataset| dataset.capacity < aligned);
+        if needs_alloc {
+            let capacity = aligned
+                .next_power_of_two()
+                .clamp(MIN_CAPACITY, limit.max(MIN_CAPACITY));
+            debug!(target: "substrate", dataset = name, capacity, "allocating GPU dataset");
+            let buffer = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(name),
+                size: capacity,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_DST
+                    | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            });
+            // Une relecture en cours garde son buffer de staging : la copie est déjà soumise.
+            let previous = self.datasets.remove(name);
+            let (uploads, staging, pending) = previous.map_or((0, None, None), |dataset| {
+                (dataset.uploads, dataset.staging, dataset.pending)
+            });
+            self.datasets.insert(
+                name.to_owned(),
+                GpuDataset {
+                    buffer,
+                    staging,
+                    len: 0,
+                    capacity,
+                    uploads,
+                    entities: Vec::new(),
+                    pending,
+                },
+            );
+        }
+
+        let dataset = self
+            .datasets
+            .get_mut(name)
+            .expect("dataset allocated above");
+        dataset.len = aligned;
+        Ok(dataset)
+    }
+
+    /// Jeu de données `name`.
+    pub fn get(&self, name: &str) -> Option<&GpuDataset> {
+        self.datasets.get(name)
+    }
+
+    /// Buffer de stockage du jeu de données `name`.
+    pub fn buffer(&self, name: &str) -> Option<&wgpu::Buffer> {
+        self.datasets.get(name).map(GpuDataset::buffer)
+    }
+
+    /// Noms des jeux de données présents.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.datasets.keys().map(String::as_str)
+    }
+
+    /// Supprime le jeu de données `name` (ses buffers sont libérés).
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.datasets.remove(name).is_some()
+    }
+
+    /// Libère tous les buffers.
+    pub fn release(&mut self) {
+        self.datasets.clear();
+        self.device = None;
+    }
+
+    /// Mémoire GPU allouée par jeu de données (octets).
+    pub fn memory_usage(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.datasets
+            .iter()
+            .map(|(name, dataset)| (name.as_str(), dataset.allocated()))
+    }
+
+    /// Mémoire GPU totale allouée (octets).
+    pub fn total_bytes(&self) -> u64 {
+        self.datasets.values().map(GpuDataset::allocated).sum()
+    }
+
+    /// Lance la copie de `name` vers son buffer de staging, sans attendre le GPU.
+    ///
+    /// Sans effet si une relecture de ce jeu de données est déjà en cours.
+    pub fn request_readback(&mut self, gpu: &GpuContext, name: &str) -> Result<(), GpuBufferError> {
+        let dataset = self
+            .datasets
+            .get_mut(name)
+            .ok_or_else(|| GpuBufferError::Unknown(name.to_owned()))?;
+        if dataset.pending.is_some() || dataset.len == 0 {
+            return Ok(());
+        }
+
+        if dataset
+            .staging
+            .as_ref()
+            .is_none_or(|staging| staging.size() < dataset.capacity)
+        {
+            dataset.staging = Some(gpu.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("substrate::staging"),
+                size: dataset.capacity,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+        }
+        let staging = dataset.staging.as_ref().expect("staging allocated above");
+
+        let mut encoder = gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("substrate::readback"),
+            });
+        encoder.copy_buffer_to_buffer(&dataset.buffer, 0, staging, 0, dataset.len);
+        gpu.queue.submit(Some(encoder.finish()));
+
+        let done = Arc::new(OnceLock::new());
+        let signal = done.clone();
+        staging
+            .slice(..dataset.len)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = signal.set(result.map_err(|err| err.to_string()));
+            });
+        dataset.pending = Some(PendingReadback {
+            bytes: dataset.len,
+            entities: dataset.entities.clone(),
+            done,
+        });
+        Ok(())
+    }
+
+    /// Fait progresser le GPU sans bloquer et retourne les relectures terminées.
+    pub fn poll_readbacks(&mut self, gpu: &GpuContext) -> Vec<(String, Readback)> {
+        if !self
+            .device
+            .as_ref()
+            .is_some_and(|device| Arc::ptr_eq(device, &gpu.device))
+        {
+            return Vec::new();
+        }
+        let _ = gpu.device.poll(wgpu::PollType::Poll);
+
+        let mut completed = Vec::new();
+        for (name, dataset) in &mut self.datasets {
+            if dataset
+                .pending
+                .as_ref()
+                .is_none_or(|pending| pending.done.get().is_none())
+            {
+                continue;
+            }
+            let pending = dataset
+                .pending
+                .take()
+                .expect("pending readback checked above");
+            let result = pending.done.get().cloned().expect("readback completed");
+            let Some(staging) = &dataset.staging else {
+                continue;
+            };
+            match result {
+                Ok(()) => {
+                    let bytes = staging.slice(..pending.bytes).get_mapped_range().to_vec();
+                    staging.unmap();
+                    completed.push((
+                        name.clone(),
+                        Readback {
+                            bytes,
+                            entities: pending.entities,
+                            generation: 0,
+                        },
+                    ));
+                }
+                Err(err) => {
+                    warn!(target: "substrate", dataset = %name, %err, "GPU readback failed");
+                }
+            }
+        }
+        completed
+    }
+}
+
+/// Une relecture publiée : octets bruts et numéro de relecture.
+#[derive(Clone, Debug)]
+pub struct Readback {
+    pub bytes: Vec<u8>,
+    /// Entité de chaque élément relu (vide si le jeu de données n’est pas indexé par entité).
+    pub entities: Vec<Entity>,
+    /// Nombre de relectures reçues pour ce jeu de données.
+    pub generation: u64,
+}
+
+/// Ressource : dernières données relues depuis le GPU, par jeu de données.
+#[derive(Resource, Default, Debug)]
+pub struct GpuReadbacks {
+    data: HashMap<String, Readback>,
+}
+
+impl GpuReadbacks {
+    /// Dernière relecture de `name`.
+    pub fn get(&self, name: &str) -> Option<&Readback> {
+        self.data.get(name)
+    }
+
+    /// Dernière relecture de `name`, interprétée comme un tableau de `T`.
+    pub fn values<T: Pod>(&self, name: &str) -> Option<Vec<T>> {
+        let readback = self.data.get(name)?;
+        let usable = readback.bytes.len() - readback.bytes.len() % std::mem::size_of::<T>();
+        Some(bytemuck::pod_collect_to_vec(&readback.bytes[..usable]))
+    }
+
+    /// Dernière relecture de `name`, associée élément par élément à son entité.
+    pub fn entity_values<T: Pod>(&self, name: &str) -> Option<Vec<(Entity, T)>> {
+        let entities = &self.data.get(name)?.entities;
+        let values = self.values::<T>(name)?;
+        Some(entities.iter().copied().zip(values).collect())
+    }
+
+    fn publish(&mut self, name: String, mut readback: Readback) {
+        readback.generation = self.data.get(&name).map_or(0, |r| r.generation) + 1;
+        self.data.insert(name, readback);
+    }
+}
+
+/// Ressource : mémoire GPU allouée par jeu de données (octets).
+#[derive(Resource, Default, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct GpuMemoryUsage {
+    pub datasets: HashMap<String, u64>,
+    pub total_bytes: u64,
+}
+
+/// Jeux de données du moteur synchronisés automatiquement vers le GPU.
+///
+/// Rien n’est synchronisé par défaut : chaque jeu de données coûte un téléversement par
+/// frame et n’est utile qu’aux noyaux qui le consomment.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct GpuSyncConfig {
+    /// Positions des corps dynamiques ([`DATASET_POSITIONS`]).
+    pub positions: bool,
+    /// Grilles de chaque [`Field`] ([`field_dataset`]).
+    pub fields: bool,
+    /// Phases des oscillateurs ([`DATASET_OSCILLATOR_PHASES`]).
+    pub oscillator_phases: bool,
+    /// Relit chaque tick les jeux synchronisés dans [`GpuReadbacks`].
+    pub readback: bool,
+}
+
+/// Système : téléverse les jeux de données du moteur lorsque le backend est GPU.
+pub fn sync_gpu_datasets(
+    backend: Res<ComputeBackend>,
+    config: Res<GpuSyncConfig>,
+    mut buffers: ResMut<GpuBuffers>,
+    bodies: Query<(Entity, &Transform), With<Mass>>,
+    fields: Query<&Field>,
+    oscillators: Query<(Entity, &Oscillator)>,
+) {
+    let Some(gpu) = backend.gpu() else {
+        return;
+    };
+
+    let mut synced = Vec::new();
+    let mut record = |name: &str, result: Result<(), GpuBufferError>| match result {
+        Ok(()) => synced.push(name.to_owned()),
+        Err(err) => warn!(target: "substrate", %err, "GPU dataset upload failed"),
+    };
+
+    if config.positions {
+        let (entities, positions): (Vec<Entity>, Vec<[f32; 4]>) = bodies
+            .iter()
+            .map(|(entity, transform)| (entity, transform.translation.extend(0.0).to_array()))
+            .unzip();
+        let result = buffers
+            .upload_entities(gpu, DATASET_POSITIONS, &entities, &positions)
+            .map(|_| ());
+        record(DATASET_POSITIONS, result);
+    }
+
+    if config.oscillator_phases {
+        let (entities, phases): (Vec<Entity>, Vec<f32>) = oscillators
+            .iter()
+            .map(|(entity, osc)| (entity, osc.phase))
+            .unzip();
+        let result = buffers
+            .upload_entities(gpu, DATASET_OSCILLATOR_PHASES, &entities, &phases)
+            .map(|_| ());
+        record(DATASET_OSCILLATOR_PHASES, result);
+    }
+
+    if config.fields {
+        let mut live = Vec::new();
+        for field in fields.iter() {
+            let name = field_dataset(&field.name);
+            let result = buffers.upload(gpu, &name, field.data()).map(|_| ());
+            record(&name, result);
+            live.push(name);
+        }
+        // Les grilles des champs disparus sont libérées.
+        let stale: Vec<String> = buffers
+            .names()
+            .filter(|name| {
+                name.starts_with(FIELD_DATASET_PREFIX) && !live.contains(&name.to_string())
+            })
+            .map(str::to_owned)
+            .collect();
+        for name in stale {
+            buffers.remove(&name);
+        }
+    }
+
+    if config.readback {
+        for name in &synced {
+            if let Err(err) = buffers.request_readback(gpu, name) {
+                warn!(target: "substrate", %err, "GPU readback request failed");
+            }
+        }
+    }
+}
+
+/// Système : publie les relectures terminées et met à jour l’usage mémoire.
+///
+/// Sur backend CPU, les buffers GPU sont libérés.
+pub fn collect_gpu_readbacks(
+    backend: Res<ComputeBackend>,
+    mut buffers: ResMut<GpuBuffers>,
+    mut readbacks: ResMut<GpuReadbacks>,
+    mut usage: ResMut<GpuMemoryUsage>,
+) {
+    match backend.gpu() {
+        Some(gpu) => {
+            for (name, readback) in buffers.poll_readbacks(gpu) {
+                readbacks.publish(name, readback);
+            }
+        }
+        None if buffers.total_bytes() > 0 => {
+            debug!(target: "substrate", "CPU backend active; releasing GPU datasets");
+            buffers.release();
+        }
+        None => {}
+    }
+
+    let total = buffers.total_bytes();
+    if usage.total_bytes != total || usage.datasets.len() != buffers.datasets.len() {
+        usage.datasets = buffers
+            .memory_usage()
+            .map(|(name, bytes)| (name.to_owned(), bytes))
+            .collect();
+        usage.total_bytes = total;
+    }
+}
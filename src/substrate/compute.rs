@@ -0,0 +1,155 @@
+//! Backends de calcul du substrat.
+//!
+//! Toute computation du substrat passe par [`ComputeBackend`] : le GPU lorsqu’un
+//! [`GpuContext`] est disponible, sinon un backend CPU (pool rayon dédié). Le backend actif
+//! est exposé comme ressource Bevy afin que les couches supérieures sachent où s’exécutent
+//! les calculs. Le pool CPU est créé une seule fois (ressource [`CpuCompute`]) et partagé
+//! par toutes les bascules vers le CPU.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use tracing::{info, warn};
+
+use super::GpuContext;
+
+/// Nature du backend de calcul actif.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ComputeBackendKind {
+    /// Calcul sur GPU via wgpu.
+    Gpu,
+    /// Calcul sur CPU via rayon.
+    Cpu,
+}
+
+/// Backend CPU : un pool rayon dédié au substrat.
+///
+/// Les clones partagent le même pool ; deux handles sont égaux s’ils désignent le même pool.
+#[derive(Resource, Clone)]
+pub struct CpuCompute {
+    pool: Arc<ThreadPool>,
+}
+
+impl CpuCompute {
+    /// Crée le backend CPU.
+    ///
+    /// Le nombre de threads est lu dans `VOID_CPU_THREADS` (0 ou absent : automatique).
+    pub fn new() -> Self {
+        let threads = std::env::var("VOID_CPU_THREADS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .thread_name(|index| format!("void-substrate-{index}"))
+            .build()
+            .unwrap_or_else(|err| {
+                warn!(target: "substrate", ?err, "failed to build CPU pool; using a single thread");
+                ThreadPoolBuilder::new()
+                    .num_threads(1)
+                    .build()
+                    .expect("single-threaded rayon pool")
+            });
+
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+
+    /// Nombre de threads du pool.
+    pub fn threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Exécute `op` dans le pool du substrat (les itérateurs parallèles y sont confinés).
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        self.pool.install(op)
+    }
+}
+
+impl Default for CpuCompute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for CpuCompute {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.pool, &other.pool)
+    }
+}
+
+impl Eq for CpuCompute {}
+
+impl std::fmt::Debug for CpuCompute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CpuCompute")
+            .field("threads", &self.threads())
+            .finish()
+    }
+}
+
+/// Backend de calcul actif du substrat.
+///
+/// Inséré en CPU au démarrage, puis remplacé par le GPU dès que l’initialisation
+/// asynchrone aboutit.
+#[derive(Resource, Clone)]
+pub enum ComputeBackend {
+    Gpu(GpuContext),
+    Cpu(CpuCompute),
+}
+
+impl ComputeBackend {
+    /// Nature du backend.
+    pub fn kind(&self) -> ComputeBackendKind {
+        match self {
+            Self::Gpu(_) => ComputeBackendKind::Gpu,
+            Self::Cpu(_) => ComputeBackendKind::Cpu,
+        }
+    }
+
+    /// Contexte GPU, si le backend est GPU.
+    pub fn gpu(&self) -> Option<&GpuContext> {
+        match self {
+            Self::Gpu(context) => Some(context),
+            Self::Cpu(_) => None,
+        }
+    }
+
+    /// Backend CPU, si le backend est CPU.
+    pub fn cpu(&self) -> Option<&CpuCompute> {
+        match self {
+            Self::Gpu(_) => None,
+            Self::Cpu(cpu) => Some(cpu),
+        }
+    }
+
+    /// Description courte du backend, pour les logs et rapports.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Gpu(context) if context.fallback_adapter => {
+                format!("gpu (fallback adapter: {})", context.adapter_name)
+            }
+            Self::Gpu(context) => format!("gpu ({})", context.adapter_name),
+            Self::Cpu(cpu) => format!("cpu ({} threads)", cpu.threads()),
+        }
+    }
+}
+
+impl std::fmt::Debug for ComputeBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Installe le backend CPU (échec ou perte du GPU) en réutilisant le pool [`CpuCompute`].
+pub(crate) fn use_cpu_backend(commands: &mut Commands, reason: &'static str) {
+    commands.queue(move |world: &mut World| {
+        let cpu = world.get_resource_or_init::<CpuCompute>().clone();
+        let backend = ComputeBackend::Cpu(cpu);
+        info!(target: "substrate", backend = %backend.describe(), reason, "compute backend selected");
+        world.insert_resource(backend);
+    });
+}
@@ -0,0 +1,854 @@
+//! Champs du substrat — grilles 2D/3D de valeurs scalaires ou vectorielles.
+//!
+//! Chaque entité [`Field`] porte une grille régulière et une règle d’évolution
+//! ([`FieldRule`]) : réaction-diffusion (Fisher-KPP) ou équation d’onde amortie.
+//! Le pas est calculé sur le [`ComputeBackend`] actif : noyau WGSL sur GPU, ou
+//! implémentation rayon sur CPU. Les deux implémentations suivent le même schéma
+//! explicite et doivent concorder à [`GPU_CPU_TOLERANCE`] près.
+//!
+//! Sur GPU, les grilles résident dans des jeux de données [`GpuBuffers`] réutilisés d’un
+//! pas à l’autre, et le résultat est relu de façon asynchrone (publié dans
+//! [`GpuReadbacks`]) : un champ avance lorsque son pas précédent a été relu, sans que la
+//! frame n’attende le GPU.
+//!
+//! Les statistiques (moyenne, variance, énergie totale) de tous les champs sont réunies
+//! en un instantané par pas, échantillonné dans le [`MemoryField`]
+//! ([`MemoryField::record_sampled`]).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bytemuck::{Pod, Zeroable};
+use rayon::prelude::*;
+use serde_json::json;
+use tracing::{debug, warn};
+
+use super::buffers::{GpuBufferError, GpuBuffers, GpuReadbacks, FIELD_STEP_PREFIX};
+use super::kernel::{KernelBinding, KernelError, KernelRegistry, FIELD_KERNEL};
+use super::topology::DomainTopology;
+use super::{ComputeBackend, CpuCompute, GpuContext};
+use crate::core::MemoryField;
+
+/// Écart maximal toléré entre les implémentations GPU et CPU après un pas.
+pub const GPU_CPU_TOLERANCE: f32 = 1e-4;
+
+/// Nature des valeurs portées par les cellules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum FieldKind {
+    Scalar,
+    Vector,
+}
+
+impl FieldKind {
+    /// Nombre de composantes par cellule.
+    pub fn components(self) -> usize {
+        match self {
+            Self::Scalar => 1,
+            Self::Vector => 3,
+        }
+    }
+}
+
+/// Règle d’évolution d’un champ (appliquée composante par composante).
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum FieldRule {
+    /// `∂u/∂t = D ∇²u + r u (1 - u)`
+    ReactionDiffusion { diffusion: f32, reaction: f32 },
+    /// `∂²u/∂t² = c² ∇²u - γ ∂u/∂t`
+    Wave { speed: f32, damping: f32 },
+}
+
+impl FieldRule {
+    fn gpu_params(self) -> (u32, f32, f32) {
+        match self {
+            Self::ReactionDiffusion {
+                diffusion,
+                reaction,
+            } => (0, diffusion, reaction),
+            Self::Wave { speed, damping } => (1, speed, damping),
+        }
+    }
+}
+
+/// Condition aux bords d’une grille (dérivée de la topologie du domaine).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FieldBoundary {
+    /// Flux nul : la cellule de bord se prolonge (indices bornés).
+    #[default]
+    Neumann,
+    /// Les bords opposés se rejoignent.
+    Periodic,
+    /// Valeur nulle hors de la grille.
+    Dirichlet,
+}
+
+impl FieldBoundary {
+    fn gpu_code(self) -> u32 {
+        match self {
+            Self::Neumann => 0,
+            Self::Periodic => 1,
+            Self::Dirichlet => 2,
+        }
+    }
+}
+
+/// Statistiques d’un champ après un pas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct FieldStats {
+    /// Moyenne de la valeur (norme pour un champ vectoriel).
+    pub mean: f32,
+    /// Variance de la valeur (norme pour un champ vectoriel).
+    pub variance: f32,
+    /// Énergie totale intégrée sur le volume.
+    pub energy: f32,
+}
+
+/// Grille de champ du substrat.
+#[derive(Component, Clone, Debug)]
+pub struct Field {
+    pub name: String,
+    pub kind: FieldKind,
+    pub rule: FieldRule,
+    /// Nombre de cellules par axe (`z = 1` pour une grille 2D).
+    pub dims: UVec3,
+    /// Pas spatial entre deux cellules.
+    pub spacing: f32,
+    /// Position monde de la cellule `(0, 0, 0)`.
+    pub origin: Vec3,
+    /// Pas de temps d’intégration (indépendant de la cadence d’affichage).
+    pub time_step: f32,
+    /// Condition aux bords (synchronisée sur la `DomainTopology` à chaque pas).
+    pub boundary: FieldBoundary,
+    /// Dernières statistiques calculées.
+    pub stats: FieldStats,
+    data: Vec<f32>,
+    previous: Vec<f32>,
+}
+
+impl Field {
+    /// Crée un champ nul de dimensions `dims`.
+    pub fn new(name: impl Into<String>, kind: FieldKind, rule: FieldRule, dims: UVec3) -> Self {
+        let dims = dims.max(UVec3::ONE);
+        let len = (dims.x * dims.y * dims.z) as usize * kind.components();
+        Self {
+            name: name.into(),
+            kind,
+            rule,
+            dims,
+            spacing: 1.0,
+            origin: Vec3::ZERO,
+            time_step: 0.1,
+            boundary: FieldBoundary::default(),
+            stats: FieldStats::default(),
+            data: vec![0.0; len],
+            previous: vec![0.0; len],
+        }
+    }
+
+    /// Champ scalaire 2D.
+    pub fn scalar_2d(name: impl Into<String>, rule: FieldRule, nx: u32, ny: u32) -> Self {
+        Self::new(name, FieldKind::Scalar, rule, UVec3::new(nx, ny, 1))
+    }
+
+    /// Champ scalaire 3D.
+    pub fn scalar_3d(name: impl Into<String>, rule: FieldRule, dims: UVec3) -> Self {
+        Self::new(name, FieldKind::Scalar, rule, dims)
+    }
+
+    /// Champ vectoriel 2D.
+    pub fn vector_2d(name: impl Into<String>, rule: FieldRule, nx: u32, ny: u32) -> Self {
+        Self::new(name, FieldKind::Vector, rule, UVec3::new(nx, ny, 1))
+    }
+
+    /// Champ vectoriel 3D.
+    pub fn vector_3d(name: impl Into<String>, rule: FieldRule, dims: UVec3) -> Self {
+        Self::new(name, FieldKind::Vector, rule, dims)
+    }
+
+    /// Définit le pas spatial.
+    pub fn with_spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing.max(f32::EPSILON);
+        self
+    }
+
+    /// Définit l’origine monde de la grille.
+    pub fn with_origin(mut self, origin: Vec3) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Définit le pas de temps d’intégration.
+    pub fn with_time_step(mut self, time_step: f32) -> Self {
+        self.time_step = time_step;
+        self
+    }
+
+    /// Nombre de cellules.
+    pub fn cell_count(&self) -> usize {
+        (self.dims.x * self.dims.y * self.dims.z) as usize
+    }
+
+    /// `true` pour une grille 3D.
+    pub fn is_3d(&self) -> bool {
+        self.dims.z > 1
+    }
+
+    /// Index linéaire de la cellule `(x, y, z)`.
+    pub fn index(&self, x: u32, y: u32, z: u32) -> usize {
+        ((z * self.dims.y + y) * self.dims.x + x) as usize
+    }
+
+    /// Position monde du centre de la cellule `(x, y, z)`.
+    pub fn position(&self, x: u32, y: u32, z: u32) -> Vec3 {
+        self.origin + UVec3::new(x, y, z).as_vec3() * self.spacing
+    }
+
+    /// Valeurs brutes (composantes contiguës par cellule).
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Valeurs brutes mutables.
+    pub fn data_mut(&mut self) -> &mut [f32] {
+        &mut self.data
+    }
+
+    /// Valeur scalaire de la cellule `index` (première composante).
+    pub fn scalar(&self, index: usize) -> f32 {
+        self.data[index * self.kind.components()]
+    }
+
+    /// Valeur vectorielle de la cellule `index` (`Vec3::X * valeur` pour un champ scalaire).
+    pub fn vector(&self, index: usize) -> Vec3 {
+        match self.kind {
+            FieldKind::Scalar => Vec3::X * self.data[index],
+            FieldKind::Vector => Vec3::from_slice(&self.data[index * 3..index * 3 + 3]),
+        }
+    }
+
+    /// Fixe la valeur scalaire de la cellule `index` (état précédent inclus, vitesse nulle).
+    pub fn set_scalar(&mut self, index: usize, value: f32) {
+        let offset = index * self.kind.components();
+        self.data[offset] = value;
+        self.previous[offset] = value;
+    }
+
+    /// Fixe la valeur vectorielle de la cellule `index` (état précédent inclus, vitesse nulle).
+    pub fn set_vector(&mut self, index: usize, value: Vec3) {
+        match self.kind {
+            FieldKind::Scalar => self.set_scalar(index, value.x),
+            FieldKind::Vector => {
+                let offset = index * 3;
+                value.write_to_slice(&mut self.data[offset..offset + 3]);
+                value.write_to_slice(&mut self.previous[offset..offset + 3]);
+            }
+        }
+    }
+
+    /// Échantillonne la première composante en `position` (interpolation trilinéaire).
+    ///
+    /// Retourne `None` hors de la grille. Les axes dégénérés (une seule cellule, `z` d’une
+    /// grille 2D) sont ignorés : la grille s’étend uniformément le long de ces axes.
+    pub fn sample_scalar(&self, position: Vec3) -> Option<f32> {
+        let degenerate = self.dims.cmpeq(UVec3::ONE);
+        let local = Vec3::select(
+            degenerate,
+            Vec3::ZERO,
+            (position - self.origin) / self.spacing,
+        );
+        let max = (self.dims - UVec3::ONE).as_vec3();
+        if local.cmplt(Vec3::ZERO).any() || local.cmpgt(max).any() {
+            return None;
+        }
+
+        let base = local.floor().as_uvec3().min(self.dims - UVec3::ONE);
+        let t = local - base.as_vec3();
+        let next = (base + UVec3::ONE).min(self.dims - UVec3::ONE);
+        let v = |x: u32, y: u32, z: u32| self.scalar(self.index(x, y, z));
+
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+        let c00 = lerp(v(base.x, base.y, base.z), v(next.x, base.y, base.z), t.x);
+        let c10 = lerp(v(base.x, next.y, base.z), v(next.x, next.y, base.z), t.x);
+        let c01 = lerp(v(base.x, base.y, next.z), v(next.x, base.y, next.z), t.x);
+        let c11 = lerp(v(base.x, next.y, next.z), v(next.x, next.y, next.z), t.x);
+        Some(lerp(lerp(c00, c10, t.y), lerp(c01, c11, t.y), t.z))
+    }
+
+    /// Écart maximal absolu avec un autre champ de même forme.
+    pub fn max_deviation(&self, other: &Field) -> f32 {
+        self.data
+            .iter()
+            .zip(&other.data)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max)
+    }
+
+    /// Avance d’un pas sur CPU (rayon).
+    pub fn step_cpu(&mut self, cpu: &CpuCompute) {
+        let mut next = vec![0.0; self.data.len()];
+        let row_len = self.dims.x as usize * self.kind.components();
+        let this = &*self;
+
+        cpu.install(|| {
+            next.par_chunks_mut(row_len)
+                .enumerate()
+                .for_each(|(row, out)| this.step_row(row, out));
+        });
+
+        self.previous = std::mem::replace(&mut self.data, next);
+    }
+
+    /// Avance d’un pas sur GPU avec `kernel`, en attendant le résultat.
+    ///
+    /// Bloque jusqu’à la fin du pas : la simulation utilise la relecture asynchrone de
+    /// [`step_fields`].
+    pub fn step_gpu(
+        &mut self,
+        gpu: &GpuContext,
+        kernel: &FieldKernel,
+        kernels: &mut KernelRegistry,
+        buffers: &mut GpuBuffers,
+    ) -> Result<(), FieldGpuError> {
+        let datasets = FieldDatasets::for_entity(Entity::PLACEHOLDER);
+        kernel.submit(gpu, kernels, buffers, &datasets, self)?;
+        gpu.device
+            .poll(wgpu::PollType::Wait)
+            .map_err(|err| FieldGpuError::Readback(err.to_string()))?;
+
+        let readback = buffers
+            .poll_readbacks(gpu)
+            .into_iter()
+            .find_map(|(name, readback)| (name == datasets.next()).then_some(readback))
+            .ok_or_else(|| FieldGpuError::Readback("result not mapped".to_owned()))?;
+        let mut next: Vec<f32> = bytemuck::pod_collect_to_vec(&readback.bytes);
+        next.truncate(self.data.len());
+        self.previous = std::mem::replace(&mut self.data, next);
+        Ok(())
+    }
+
+    /// Calcule et mémorise les statistiques courantes.
+    pub fn compute_stats(&mut self) -> FieldStats {
+        let components = self.kind.components();
+        let cells = self.cell_count().max(1) as f32;
+        let magnitude = |values: &[f32], cell: usize| -> f32 {
+            let slice = &values[cell * components..(cell + 1) * components];
+            slice.iter().map(|v| v * v).sum::<f32>().sqrt()
+        };
+
+        let mean = (0..self.cell_count())
+            .map(|cell| magnitude(&self.data, cell))
+            .sum::<f32>()
+            / cells;
+        let variance = (0..self.cell_count())
+            .map(|cell| (magnitude(&self.data, cell) - mean).powi(2))
+            .sum::<f32>()
+            / cells;
+
+        let volume = self.spacing.powi(if self.is_3d() { 3 } else { 2 });
+        let energy = match self.rule {
+            // Énergie L² : ½ Σ u² dV
+            FieldRule::ReactionDiffusion { .. } => {
+                0.5 * self.data.iter().map(|u| u * u).sum::<f32>() * volume
+            }
+            // Énergie d’onde : ½ Σ (∂u/∂t)² + c² |∇u|² dV
+            FieldRule::Wave { speed, .. } => {
+                let dt = self.time_step.max(f32::EPSILON);
+                let kinetic: f32 = self
+                    .data
+                    .iter()
+                    .zip(&self.previous)
+                    .map(|(u, p)| ((u - p) / dt).powi(2))
+                    .sum();
+                let potential: f32 = (0..self.data.len()).map(|i| self.gradient_squared(i)).sum();
+                0.5 * (kinetic + speed * speed * potential) * volume
+            }
+        };
+
+        self.stats = FieldStats {
+            mean,
+            variance,
+            energy,
+        };
+        self.stats
+    }
+
+    fn coords(&self, cell: usize) -> (i64, i64, i64) {
+        let nx = self.dims.x as usize;
+        let ny = self.dims.y as usize;
+        (
+            (cell % nx) as i64,
+            ((cell / nx) % ny) as i64,
+            (cell / (nx * ny)) as i64,
+        )
+    }
+
+    /// Valeur de la composante `c` en `(x, y, z)`, selon la condition aux bords.
+    fn value_at(&self, values: &[f32], x: i64, y: i64, z: i64, c: usize) -> f32 {
+        let resolve = |v: i64, n: u32| -> Option<u32> {
+            let n = n as i64;
+            match self.boundary {
+                FieldBoundary::Neumann => Some(v.clamp(0, n - 1) as u32),
+                FieldBoundary::Periodic => Some(v.rem_euclid(n) as u32),
+                FieldBoundary::Dirichlet => (0..n).contains(&v).then_some(v as u32),
+            }
+        };
+        match (
+            resolve(x, self.dims.x),
+            resolve(y, self.dims.y),
+            resolve(z, self.dims.z),
+        ) {
+            (Some(x), Some(y), Some(z)) => values[self.index(x, y, z) * self.kind.components() + c],
+            _ => 0.0,
+        }
+    }
+
+    fn laplacian(&self, index: usize) -> f32 {
+        let components = self.kind.components();
+        let c = index % components;
+        let (x, y, z) = self.coords(index / components);
+        let u = self.data[index];
+        let v = |dx, dy, dz| self.value_at(&self.data, x + dx, y + dy, z + dz, c);
+
+        let mut lap = v(-1, 0, 0) + v(1, 0, 0) + v(0, -1, 0) + v(0, 1, 0) - 4.0 * u;
+        if self.is_3d() {
+            lap += v(0, 0, -1) + v(0, 0, 1) - 2.0 * u;
+        }
+        lap / (self.spacing * self.spacing)
+    }
+
+    fn gradient_squared(&self, index: usize) -> f32 {
+        let components = self.kind.components();
+        let c = index % components;
+        let (x, y, z) = self.coords(index / components);
+        let v = |dx, dy, dz| self.value_at(&self.data, x + dx, y + dy, z + dz, c);
+        let h = 2.0 * self.spacing;
+
+        let gx = (v(1, 0, 0) - v(-1, 0, 0)) / h;
+        let gy = (v(0, 1, 0) - v(0, -1, 0)) / h;
+        let gz = if self.is_3d() {
+            (v(0, 0, 1) - v(0, 0, -1)) / h
+        } else {
+            0.0
+        };
+        gx * gx + gy * gy + gz * gz
+    }
+
+    fn step_row(&self, row: usize, out: &mut [f32]) {
+        let start = row * out.len();
+        let dt = self.time_step;
+
+        for (offset, slot) in out.iter_mut().enumerate() {
+            let index = start + offset;
+            let u = self.data[index];
+            let lap = self.laplacian(index);
+
+            *slot = match self.rule {
+                FieldRule::ReactionDiffusion {
+                    diffusion,
+                    reaction,
+                } => u + dt * (diffusion * lap + reaction * u * (1.0 - u)),
+                FieldRule::Wave { speed, damping } => {
+                    let prev = self.previous[index];
+                    let courant = speed * dt;
+                    2.0 * u - prev + courant * courant * lap - damping * dt * (u - prev)
+                }
+            };
+        }
+    }
+}
+
+/// Erreur du chemin GPU des champs.
+#[derive(Debug)]
+pub enum FieldGpuError {
+    /// La grille dépasse la taille maximale d’un buffer de stockage.
+    TooLarge { bytes: u64, limit: u64 },
+    /// Les buffers de la grille n’ont pas pu être alloués ou relus.
+    Buffers(GpuBufferError),
+    /// Le noyau [`FIELD_KERNEL`] n’a pas pu être compilé ou exécuté.
+    Kernel(KernelError),
+    /// La relecture du résultat a échoué.
+    Readback(String),
+}
+
+impl std::fmt::Display for FieldGpuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooLarge { bytes, limit } => {
+                write!(
+                    f,
+                    "field grid of {bytes} bytes exceeds storage limit {limit}"
+                )
+            }
+            Self::Buffers(err) => write!(f, "field buffers unavailable: {err}"),
+            Self::Kernel(err) => write!(f, "field kernel unavailable: {err}"),
+            Self::Readback(err) => write!(f, "failed to read field back from GPU: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FieldGpuError {}
+
+impl From<KernelError> for FieldGpuError {
+    fn from(err: KernelError) -> Self {
+        Self::Kernel(err)
+    }
+}
+
+impl From<GpuBufferError> for FieldGpuError {
+    fn from(err: GpuBufferError) -> Self {
+        match err {
+            GpuBufferError::TooLarge { bytes, limit, .. } => Self::TooLarge { bytes, limit },
+            err => Self::Buffers(err),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct FieldParams {
+    nx: u32,
+    ny: u32,
+    nz: u32,
+    components: u32,
+    rule: u32,
+    boundary: u32,
+    _pad: [u32; 2],
+    spacing: f32,
+    dt: f32,
+    p0: f32,
+    p1: f32,
+}
+
+/// Jeux de données [`GpuBuffers`] d’une grille pas à pas : état courant, précédent et
+/// résultat du pas.
+#[derive(Clone, Debug)]
+pub struct FieldDatasets {
+    current: String,
+    previous: String,
+    next: String,
+}
+
+impl FieldDatasets {
+    /// Jeux de données de la grille portée par `entity`.
+    pub fn for_entity(entity: Entity) -> Self {
+        let name = |role: &str| format!("{FIELD_STEP_PREFIX}{}::{role}", entity.to_bits());
+        Self {
+            current: name("current"),
+            previous: name("previous"),
+            next: name("next"),
+        }
+    }
+
+    /// Jeu de données qui reçoit le résultat du pas (relu dans [`GpuReadbacks`]).
+    pub fn next(&self) -> &str {
+        &self.next
+    }
+
+    fn remove(&self, buffers: &mut GpuBuffers) {
+        for name in [&self.current, &self.previous, &self.next] {
+            buffers.remove(name);
+        }
+    }
+}
+
+/// Ressources persistantes du pas GPU des champs pour un device donné.
+///
+/// Le noyau lui-même est compilé et mis en cache par le [`KernelRegistry`]
+/// ([`FIELD_KERNEL`]).
+pub struct FieldKernel {
+    device: Arc<wgpu::Device>,
+    /// Paramètres du pas, réécrits avant chaque soumission.
+    params: wgpu::Buffer,
+}
+
+impl FieldKernel {
+    /// Alloue les ressources du pas pour le device de `gpu`.
+    pub fn new(gpu: &GpuContext) -> Self {
+        let params = gpu.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("field_params"),
+            size: std::mem::size_of::<FieldParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Self {
+            device: gpu.device.clone(),
+            params,
+        }
+    }
+
+    /// `true` si les ressources ont été allouées pour le device de `gpu`.
+    pub fn matches(&self, gpu: &GpuContext) -> bool {
+        Arc::ptr_eq(&self.device, &gpu.device)
+    }
+
+    /// Téléverse la grille dans ses jeux de données, exécute [`FIELD_KERNEL`] et demande la
+    /// relecture du résultat, sans attendre le GPU.
+    pub fn submit(
+        &self,
+        gpu: &GpuContext,
+        kernels: &mut KernelRegistry,
+        buffers: &mut GpuBuffers,
+        datasets: &FieldDatasets,
+        field: &Field,
+    ) -> Result<(), FieldGpuError> {
+        let (rule, p0, p1) = field.rule.gpu_params();
+        let params = FieldParams {
+            nx: field.dims.x,
+            ny: field.dims.y,
+            nz: field.dims.z,
+            components: field.kind.components() as u32,
+            rule,
+            boundary: field.boundary.gpu_code(),
+            _pad: [0; 2],
+            spacing: field.spacing,
+            dt: field.time_step,
+            p0,
+            p1,
+        };
+        gpu.queue
+            .write_buffer(&self.params, 0, bytemuck::bytes_of(&params));
+
+        buffers.upload(gpu, &datasets.current, &field.data)?;
+        buffers.upload(gpu, &datasets.previous, &field.previous)?;
+        let bytes = std::mem::size_of_val(field.data.as_slice()) as u64;
+        buffers.reserve(gpu, &datasets.next, bytes)?;
+
+        kernels.dispatch(
+            gpu,
+            buffers,
+            FIELD_KERNEL,
+            &[
+                KernelBinding::Buffer(&self.params),
+                KernelBinding::Dataset(&datasets.current),
+                KernelBinding::Dataset(&datasets.previous),
+                KernelBinding::Dataset(&datasets.next),
+            ],
+            field.data.len() as u32,
+        )?;
+
+        buffers.request_readback(gpu, &datasets.next)?;
+        Ok(())
+    }
+}
+
+/// Pas GPU soumis dont le résultat n’a pas encore été relu.
+struct InFlight {
+    datasets: FieldDatasets,
+    /// Grille téléversée ; les modifications faites depuis sont reportées sur le résultat.
+    submitted: Vec<f32>,
+    /// Génération de la relecture de `next` au moment de la soumission.
+    generation: u64,
+    /// Frames écoulées depuis la soumission.
+    frames: u32,
+}
+
+/// État local de [`step_fields`] : noyau compilé et pas GPU en vol.
+#[derive(Default)]
+pub struct FieldStepper {
+    kernel: Option<FieldKernel>,
+    in_flight: HashMap<Entity, InFlight>,
+}
+
+impl FieldStepper {
+    /// Ressources du pas pour `gpu`, réallouées au changement de device.
+    fn kernel(&mut self, gpu: &GpuContext) -> &FieldKernel {
+        if !self.kernel.as_ref().is_some_and(|k| k.matches(gpu)) {
+            self.in_flight.clear();
+            self.kernel = Some(FieldKernel::new(gpu));
+        }
+        self.kernel.as_ref().expect("field kernel allocated above")
+    }
+}
+
+/// Système : avance chaque champ d’un pas sur le backend actif et enregistre ses statistiques.
+///
+/// Sur GPU, le pas est soumis puis relu de façon asynchrone : le résultat est adopté lors
+/// d’une frame ultérieure (un pas en vol par champ), sans bloquer sur le GPU. Les
+/// modifications de la grille faites pendant le vol sont ajoutées au résultat.
+#[allow(clippy::too_many_arguments)]
+pub fn step_fields(
+    mut fields: Query<(Entity, &mut Field)>,
+    backend: Res<ComputeBackend>,
+    cpu: Res<CpuCompute>,
+    topology: Res<DomainTopology>,
+    mut memory: ResMut<MemoryField>,
+    mut kernels: ResMut<KernelRegistry>,
+    mut buffers: ResMut<GpuBuffers>,
+    readbacks: Res<GpuReadbacks>,
+    mut stepper: Local<FieldStepper>,
+) {
+    let stepper = &mut *stepper;
+    let gpu = backend.gpu();
+    if gpu.is_none() {
+        stepper.in_flight.clear();
+    }
+
+    let mut snapshot = serde_json::Map::new();
+    for (entity, mut field) in fields.iter_mut() {
+        field.boundary = topology.field_boundary();
+        let backend_name = match &*backend {
+            ComputeBackend::Gpu(gpu) => {
+                match step_field_async(
+                    stepper,
+                    gpu,
+                    &mut kernels,
+                    &mut buffers,
+                    &readbacks,
+                    entity,
+                    &mut field,
+                ) {
+                    Ok(true) => "gpu",
+                    // Pas encore relu : la grille avancera à une frame ultérieure.
+                    Ok(false) => continue,
+                    Err(err) => {
+                        warn!(target: "substrate", field = %field.name, %err, "GPU field step failed; using CPU");
+                        if let Some(flight) = stepper.in_flight.remove(&entity) {
+                            flight.datasets.remove(&mut buffers);
+                        }
+                        field.step_cpu(&cpu);
+                        "cpu"
+                    }
+                }
+            }
+            ComputeBackend::Cpu(cpu) => {
+                field.step_cpu(cpu);
+                "cpu"
+            }
+        };
+
+        let stats = field.compute_stats();
+        debug!(
+            target: "substrate",
+            field = %field.name,
+            mean = stats.mean,
+            variance = stats.variance,
+            energy = stats.energy,
+            backend = backend_name,
+            "field step"
+        );
+        snapshot.insert(
+            field.name.clone(),
+            json!({
+                "mean": stats.mean,
+                "variance": stats.variance,
+                "energy": stats.energy,
+                "backend": backend_name,
+            }),
+        );
+    }
+    if !snapshot.is_empty() {
+        memory.record_sampled(json!({
+            "module": "substrate::field",
+            "fields": snapshot,
+        }));
+    }
+
+    // Les buffers des champs disparus sont libérés.
+    let stale: Vec<Entity> = stepper
+        .in_flight
+        .keys()
+        .copied()
+        .filter(|entity| !fields.contains(*entity))
+        .collect();
+    for entity in stale {
+        if let Some(flight) = stepper.in_flight.remove(&entity) {
+            flight.datasets.remove(&mut buffers);
+        }
+    }
+}
+
+/// Frames au-delà desquelles un pas GPU non relu est abandonné.
+const MAX_FLIGHT_FRAMES: u32 = 8;
+
+/// Adopte le pas GPU relu de `field` s’il est disponible, puis soumet le suivant.
+///
+/// Retourne `true` si la grille a avancé d’un pas.
+fn step_field_async(
+    stepper: &mut FieldStepper,
+    gpu: &GpuContext,
+    kernels: &mut KernelRegistry,
+    buffers: &mut GpuBuffers,
+    readbacks: &GpuReadbacks,
+    entity: Entity,
+    field: &mut Field,
+) -> Result<bool, FieldGpuError> {
+    stepper.kernel(gpu);
+    let mut stepped = false;
+
+    if let Some(flight) = stepper.in_flight.get_mut(&entity) {
+        let readback = readbacks
+            .get(flight.datasets.next())
+            .filter(|readback| readback.generation > flight.generation);
+        let Some(readback) = readback else {
+            flight.frames += 1;
+            if flight.frames > MAX_FLIGHT_FRAMES {
+                return Err(FieldGpuError::Readback(format!(
+                    "no result after {MAX_FLIGHT_FRAMES} frames"
+                )));
+            }
+            return Ok(false);
+        };
+
+        let mut next: Vec<f32> = bytemuck::pod_collect_to_vec(&readback.bytes);
+        if next.len() < field.data.len() {
+            return Err(FieldGpuError::Readback(format!(
+                "expected {} values, got {}",
+                field.data.len(),
+                next.len()
+            )));
+        }
+        next.truncate(field.data.len());
+        for ((value, now), before) in next.iter_mut().zip(&field.data).zip(&flight.submitted) {
+            *value += now - before;
+        }
+        field.previous = std::mem::replace(&mut field.data, next);
+        stepper.in_flight.remove(&entity);
+        stepped = true;
+    }
+
+    let datasets = FieldDatasets::for_entity(entity);
+    let kernel = stepper
+        .kernel
+        .as_ref()
+        .expect("field kernel allocated above");
+    kernel.submit(gpu, kernels, buffers, &datasets, field)?;
+    let generation = readbacks
+        .get(datasets.next())
+        .map_or(0, |readback| readback.generation);
+    stepper.in_flight.insert(
+        entity,
+        InFlight {
+            datasets,
+            submitted: field.data.clone(),
+            generation,
+            frames: 0,
+        },
+    );
+    Ok(stepped)
+}
+
+/// Vérifie la concordance GPU/CPU sur `steps` pas ; retourne l’écart maximal observé.
+///
+/// Chaque pas GPU est attendu : réservé aux tests et aux diagnostics.
+pub fn verify_backends(
+    field: &Field,
+    gpu: &GpuContext,
+    steps: usize,
+) -> Result<f32, FieldGpuError> {
+    let kernel = FieldKernel::new(gpu);
+    let mut kernels = KernelRegistry::default();
+    let cpu = CpuCompute::new();
+    let mut buffers = GpuBuffers::default();
+    let mut on_gpu = field.clone();
+    let mut on_cpu = field.clone();
+    let mut deviation = 0.0f32;
+
+    for _ in 0..steps {
+        on_gpu.step_gpu(gpu, &kernel, &mut kernels, &mut buffers)?;
+        on_cpu.step_cpu(&cpu);
+        deviation = deviation.max(on_gpu.max_deviation(&on_cpu));
+    }
+
+    Ok(deviation)
+}
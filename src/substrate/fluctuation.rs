@@ -0,0 +1,124 @@
+//! Générateurs de fluctuations procédurales (bruit cohérent évoluant dans le temps).
+//!
+//! Un [`FluctuationGenerator`] échantillonne un bruit 4D `(x, y, z, t)` — Perlin, Simplex
+//! ou fBm — dont le spectre (fréquence spatiale, échelle temporelle, octaves) et
+//! l’amplitude sont configurables. À graine égale, la séquence est identique.
+
+use bevy::prelude::*;
+use noise::{Fbm, MultiFractal, NoiseFn, Perlin, Simplex};
+
+/// Famille de bruit utilisée par un générateur.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum NoiseKind {
+    Perlin,
+    Simplex,
+    /// Mouvement brownien fractionnaire sur une base Perlin.
+    Fbm {
+        octaves: usize,
+        lacunarity: f64,
+        persistence: f64,
+    },
+}
+
+/// Spectre d’une fluctuation.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct FluctuationSpectrum {
+    pub kind: NoiseKind,
+    /// Fréquence spatiale (cycles par unité de longueur).
+    pub frequency: f64,
+    /// Fréquence temporelle (cycles par seconde simulée).
+    pub time_scale: f64,
+}
+
+impl Default for FluctuationSpectrum {
+    fn default() -> Self {
+        Self {
+            kind: NoiseKind::Fbm {
+                octaves: 4,
+                lacunarity: 2.0,
+                persistence: 0.5,
+            },
+            frequency: 0.1,
+            time_scale: 0.25,
+        }
+    }
+}
+
+enum NoiseSource {
+    Perlin(Perlin),
+    Simplex(Simplex),
+    Fbm(Fbm<Perlin>),
+}
+
+impl NoiseSource {
+    fn new(kind: NoiseKind, seed: u32) -> Self {
+        match kind {
+            NoiseKind::Perlin => Self::Perlin(Perlin::new(seed)),
+            NoiseKind::Simplex => Self::Simplex(Simplex::new(seed)),
+            NoiseKind::Fbm {
+                octaves,
+                lacunarity,
+                persistence,
+            } => Self::Fbm(
+                Fbm::<Perlin>::new(seed)
+                    .set_octaves(octaves.max(1))
+                    .set_lacunarity(lacunarity)
+                    .set_persistence(persistence),
+            ),
+        }
+    }
+
+    fn get(&self, point: [f64; 4]) -> f64 {
+        match self {
+            Self::Perlin(noise) => noise.get(point),
+            Self::Simplex(noise) => noise.get(point),
+            Self::Fbm(noise) => noise.get(point),
+        }
+    }
+}
+
+/// Générateur de fluctuations déterministe.
+pub struct FluctuationGenerator {
+    source: NoiseSource,
+    pub spectrum: FluctuationSpectrum,
+    pub amplitude: f32,
+}
+
+impl FluctuationGenerator {
+    /// Crée un générateur à partir d’une graine.
+    pub fn new(seed: u64, spectrum: FluctuationSpectrum, amplitude: f32) -> Self {
+        // Le bruit n’accepte qu’une graine 32 bits : on replie les deux moitiés.
+        let seed = (seed ^ (seed >> 32)) as u32;
+        Self {
+            source: NoiseSource::new(spectrum.kind, seed),
+            spectrum,
+            amplitude,
+        }
+    }
+
+    /// Valeur scalaire en `position` à l’instant `time`, dans `[-amplitude, amplitude]`.
+    pub fn sample(&self, position: Vec3, time: f64) -> f32 {
+        self.sample_raw(position, time, 0.0)
+    }
+
+    /// Valeur vectorielle (trois canaux décorrélés) en `position` à l’instant `time`.
+    pub fn sample_vec3(&self, position: Vec3, time: f64) -> Vec3 {
+        // Décalages arbitraires de l’espace d’échantillonnage pour décorréler les axes.
+        Vec3::new(
+            self.sample_raw(position, time, 0.0),
+            self.sample_raw(position, time, 131.7),
+            self.sample_raw(position, time, 271.3),
+        )
+    }
+
+    fn sample_raw(&self, position: Vec3, time: f64, offset: f64) -> f32 {
+        let f = self.spectrum.frequency;
+        let point = [
+            position.x as f64 * f + offset,
+            position.y as f64 * f + offset,
+            position.z as f64 * f + offset,
+            time * self.spectrum.time_scale,
+        ];
+        (self.source.get(point).clamp(-1.0, 1.0) as f32) * self.amplitude
+    }
+}
@@ -0,0 +1,587 @@
+//! Registre des noyaux de calcul WGSL.
+//!
+//! [`KernelRegistry`] charge des noyaux WGSL (embarqués ou depuis un fichier), les valide
+//! avec naga dès le chargement, puis met en cache leur pipeline et le layout de leur
+//! groupe de liaison 0 pour le device courant. En développement, les noyaux chargés depuis
+//! un fichier sont rechargés à chaque modification ([`hot_reload_kernels`]) ; un noyau
+//! invalide n’écrase jamais la dernière version valide.
+//!
+//! [`KernelRegistry::dispatch`] exécute un noyau nommé en un appel, sur des jeux de
+//! données de [`GpuBuffers`] ou des buffers arbitraires.
+//!
+//! Au chargement, aucun device n’est encore connu : seules les règles indépendantes du
+//! matériel sont vérifiées. À la compilation, le module est revalidé avec les
+//! capacités déduites des fonctionnalités du device ([`device_capabilities`]).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use bevy::prelude::*;
+use bevy::tasks::block_on;
+use tracing::{debug, error, info};
+use wgpu::util::DeviceExt;
+
+use super::buffers::GpuBuffers;
+use super::GpuContext;
+
+/// Noyau intégré : pas de temps des champs.
+pub const FIELD_KERNEL: &str = "field_step";
+
+pub(crate) const FIELD_SHADER: &str = include_str!("shaders/field_step.wgsl");
+const FIELD_SHADER_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/substrate/shaders/field_step.wgsl"
+);
+
+/// Nombre maximal de groupes de travail sur un axe.
+const MAX_WORKGROUPS_X: u32 = 65_535;
+
+/// Intervalle minimal entre deux vérifications des fichiers sources.
+const HOT_RELOAD_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Erreur du registre de noyaux.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KernelError {
+    /// Aucun noyau ne porte ce nom.
+    Unknown(String),
+    /// Lecture du fichier source impossible.
+    Io { path: PathBuf, message: String },
+    /// Erreur de syntaxe WGSL (diagnostic naga complet).
+    Parse { name: String, message: String },
+    /// Module WGSL syntaxiquement correct mais invalide (types, liaisons, …).
+    Validation { name: String, message: String },
+    /// Le point d’entrée demandé n’existe pas ou n’est pas un compute shader.
+    EntryPoint { name: String, entry_point: String },
+    /// Le device a refusé le module ou le pipeline.
+    Compile { name: String, message: String },
+    /// Une liaison de la dispatch est invalide.
+    Binding { name: String, message: String },
+}
+
+impl std::fmt::Display for KernelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "unknown kernel `{name}`"),
+            Self::Io { path, message } => {
+                write!(f, "cannot read kernel {}: {message}", path.display())
+            }
+            Self::Parse { name, message } => {
+                write!(f, "kernel `{name}` failed to parse:\n{message}")
+            }
+            Self::Validation { name, message } => {
+                write!(f, "kernel `{name}` failed validation:\n{message}")
+            }
+            Self::EntryPoint { name, entry_point } => {
+                write!(
+                    f,
+                    "kernel `{name}` has no compute entry point `{entry_point}`"
+                )
+            }
+            Self::Compile { name, message } => {
+                write!(f, "kernel `{name}` rejected by the device: {message}")
+            }
+            Self::Binding { name, message } => {
+                write!(f, "kernel `{name}` binding error: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KernelError {}
+
+/// Provenance du code d’un noyau.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KernelSource {
+    /// Code fourni directement (par exemple via `include_str!`).
+    Inline,
+    /// Fichier WGSL, surveillé pour le rechargement à chaud.
+    File(PathBuf),
+}
+
+/// Pipeline compilé d’un noyau pour un device.
+pub struct CompiledKernel {
+    pub pipeline: wgpu::ComputePipeline,
+    /// Layout du groupe de liaison 0 (déduit du shader).
+    pub layout: wgpu::BindGroupLayout,
+}
+
+struct KernelEntry {
+    source: KernelSource,
+    entry_point: String,
+    code: String,
+    modified: Option<SystemTime>,
+    workgroup_size: [u32; 3],
+    generation: u64,
+    compiled: Option<CompiledKernel>,
+    last_error: Option<KernelError>,
+}
+
+/// Liaison d’une dispatch ; l’indice de liaison est la position dans la liste.
+pub enum KernelBinding<'a> {
+    /// Jeu de données de [`GpuBuffers`] (buffer de stockage).
+    Dataset(&'a str),
+    /// Buffer quelconque.
+    Buffer(&'a wgpu::Buffer),
+    /// Octets copiés dans un buffer uniforme temporaire.
+    Uniform(&'a [u8]),
+}
+
+/// Ressource : noyaux WGSL chargés et pipelines en cache.
+#[derive(Resource)]
+pub struct KernelRegistry {
+    kernels: HashMap<String, KernelEntry>,
+    device: Option<Arc<wgpu::Device>>,
+    /// Capacités de validation du device courant (toutes tant qu’aucun n’est lié).
+    capabilities: naga::valid::Capabilities,
+    /// Recharge les noyaux fichiers modifiés (`VOID_SHADER_HOT_RELOAD=1`).
+    pub hot_reload: bool,
+}
+
+impl Default for KernelRegistry {
+    fn default() -> Self {
+        let hot_reload = std::env::var("VOID_SHADER_HOT_RELOAD")
+            .map(|value| matches!(value.as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        let mut registry = Self {
+            kernels: HashMap::new(),
+            device: None,
+            capabilities: naga::valid::Capabilities::all(),
+            hot_reload,
+        };
+
+        // En rechargement à chaud, le noyau intégré est lu depuis l’arborescence source.
+        let builtin = if hot_reload && Path::new(FIELD_SHADER_PATH).exists() {
+            registry.register_file(FIELD_KERNEL, FIELD_SHADER_PATH, "main")
+        } else {
+            registry.register_source(FIELD_KERNEL, FIELD_SHADER, "main")
+        };
+        if let Err(err) = builtin {
+            error!(target: "substrate", %err, "built-in kernel failed to load");
+        }
+        registry
+    }
+}
+
+/// Capacités de validation naga offertes par les fonctionnalités d’un device.
+///
+/// Reprend, pour les fonctionnalités utiles au calcul, la correspondance appliquée par
+/// wgpu à la création d’un module.
+pub fn device_capabilities(features: wgpu::Features) -> naga::valid::Capabilities {
+    use naga::valid::Capabilities as Caps;
+    use wgpu::Features;
+
+    let mapping = [
+        (Caps::PUSH_CONSTANT, Features::PUSH_CONSTANTS),
+        (Caps::FLOAT64, Features::SHADER_F64),
+        (Caps::SHADER_FLOAT16, Features::SHADER_F16),
+        (Caps::SHADER_INT64, Features::SHADER_INT64),
+        (
+            Caps::SHADER_INT64_ATOMIC_MIN_MAX,
+            Features::SHADER_INT64_ATOMIC_MIN_MAX,
+        ),
+        (
+            Caps::SHADER_INT64_ATOMIC_ALL_OPS,
+            Features::SHADER_INT64_ATOMIC_ALL_OPS,
+        ),
+        (Caps::SHADER_FLOAT32_ATOMIC, Features::SHADER_FLOAT32_ATOMIC),
+        (
+            Caps::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
+            Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
+        ),
+        (
+            Caps::STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING,
+            Features::STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING,
+        ),
+        (
+            Caps::UNIFORM_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
+            Features::UNIFORM_BUFFER_BINDING_ARRAYS,
+        ),
+        (
+            Caps::STORAGE_TEXTURE_16BIT_NORM_FORMATS,
+            Features::TEXTURE_FORMAT_16BIT_NORM,
+        ),
+        (Caps::TEXTURE_ATOMIC, Features::TEXTURE_ATOMIC),
+        (Caps::TEXTURE_INT64_ATOMIC, Features::TEXTURE_INT64_ATOMIC),
+        (Caps::SUBGROUP, Features::SUBGROUP),
+        (Caps::SUBGROUP_BARRIER, Features::SUBGROUP_BARRIER),
+        (Caps::RAY_QUERY, Features::EXPERIMENTAL_RAY_QUERY),
+    ];
+
+    // Capacités de base de WebGPU, toujours présentes.
+    let mut capabilities = Caps::CUBE_ARRAY_TEXTURES;
+    for (capability, feature) in mapping {
+        capabilities.set(capability, features.intersects(feature));
+    }
+    // Les opérations atomiques 64 bits complètes incluent min/max.
+    if features.contains(Features::SHADER_INT64_ATOMIC_ALL_OPS) {
+        capabilities |= Caps::SHADER_INT64_ATOMIC_MIN_MAX;
+    }
+    capabilities
+}
+
+/// Analyse et valide `code` avec `capabilities` ; retourne la taille de groupe du point
+/// d’entrée.
+fn validate(
+    name: &str,
+    code: &str,
+    entry_point: &str,
+    capabilities: naga::valid::Capabilities,
+) -> Result<[u32; 3], KernelError> {
+    let module = naga::front::wgsl::parse_str(code).map_err(|err| KernelError::Parse {
+        name: name.to_owned(),
+        message: err.emit_to_string(code),
+    })?;
+
+    let mut validator =
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), capabilities);
+    if capabilities.contains(naga::valid::Capabilities::SUBGROUP) {
+        validator
+            .subgroup_stages(naga::valid::ShaderStages::COMPUTE)
+            .subgroup_operations(naga::valid::SubgroupOperationSet::all());
+    }
+    validator
+        .validate(&module)
+        .map_err(|err| KernelError::Validation {
+            name: name.to_owned(),
+            message: err.emit_to_string(code),
+        })?;
+
+    module
+        .entry_points
+        .iter()
+        .find(|ep| ep.name == entry_point && ep.stage == naga::ShaderStage::Compute)
+        .map(|ep| ep.workgroup_size)
+        .ok_or_else(|| KernelError::EntryPoint {
+            name: name.to_owned(),
+            entry_point: entry_point.to_owned(),
+        })
+}
+
+impl KernelRegistry {
+    /// Enregistre (ou remplace) un noyau à partir de son code WGSL.
+    pub fn register_source(
+        &mut self,
+        name: &str,
+        code: &str,
+        entry_point: &str,
+    ) -> Result<(), KernelError> {
+        self.insert(
+            name,
+            KernelSource::Inline,
+            code.to_owned(),
+            None,
+            entry_point,
+        )
+    }
+
+    /// Enregistre (ou remplace) un noyau à partir d’un fichier WGSL.
+    pub fn register_file(
+        &mut self,
+        name: &str,
+        path: impl Into<PathBuf>,
+        entry_point: &str,
+    ) -> Result<(), KernelError> {
+        let path = path.into();
+        let (code, modified) = read_source(&path)?;
+        self.insert(name, KernelSource::File(path), code, modified, entry_point)
+    }
+
+    fn insert(
+        &mut self,
+        name: &str,
+        source: KernelSource,
+        code: String,
+        modified: Option<SystemTime>,
+        entry_point: &str,
+    ) -> Result<(), KernelError> {
+        let workgroup_size = validate(name, &code, entry_point, self.capabilities)?;
+        let generation = self
+            .kernels
+            .get(name)
+            .map_or(0, |entry| entry.generation + 1);
+        debug!(target: "substrate", kernel = name, ?workgroup_size, "kernel registered");
+        self.kernels.insert(
+            name.to_owned(),
+            KernelEntry {
+                source,
+                entry_point: entry_point.to_owned(),
+                code,
+                modified,
+                workgroup_size,
+                generation,
+                compiled: None,
+                last_error: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// `true` si un noyau porte ce nom.
+    pub fn contains(&self, name: &str) -> bool {
+        self.kernels.contains_key(name)
+    }
+
+    /// Noms des noyaux enregistrés.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.kernels.keys().map(String::as_str)
+    }
+
+    /// Version du code d’un noyau, incrémentée à chaque remplacement ou rechargement.
+    pub fn generation(&self, name: &str) -> Option<u64> {
+        self.kernels.get(name).map(|entry| entry.generation)
+    }
+
+    /// Taille de groupe de travail déclarée par le noyau.
+    pub fn workgroup_size(&self, name: &str) -> Option<[u32; 3]> {
+        self.kernels.get(name).map(|entry| entry.workgroup_size)
+    }
+
+    /// Dernière erreur de rechargement ou de compilation du noyau.
+    pub fn last_error(&self, name: &str) -> Option<&KernelError> {
+        self.kernels
+            .get(name)
+            .and_then(|entry| entry.last_error.as_ref())
+    }
+
+    /// Pipeline du noyau pour le device de `gpu`, compilé au premier appel.
+    pub fn compiled(
+        &mut self,
+        gpu: &GpuContext,
+        name: &str,
+    ) -> Result<&CompiledKernel, KernelError> {
+        if !self
+            .device
+            .as_ref()
+            .is_some_and(|device| Arc::ptr_eq(device, &gpu.device))
+        {
+            for entry in self.kernels.values_mut() {
+                entry.compiled = None;
+            }
+            self.device = Some(gpu.device.clone());
+            self.capabilities = device_capabilities(gpu.device.features());
+        }
+
+        let capabilities = self.capabilities;
+        let entry = self
+            .kernels
+            .get_mut(name)
+            .ok_or_else(|| KernelError::Unknown(name.to_owned()))?;
+        if entry.compiled.is_none() {
+            let compiled = validate(name, &entry.code, &entry.entry_point, capabilities)
+                .and_then(|_| compile(gpu, name, entry));
+            match compiled {
+                Ok(compiled) => entry.compiled = Some(compiled),
+                Err(err) => {
+                    entry.last_error = Some(err.clone());
+                    return Err(err);
+                }
+            }
+        }
+        Ok(entry.compiled.as_ref().expect("kernel compiled above"))
+    }
+
+    /// Recharge les noyaux dont le fichier source a changé.
+    ///
+    /// Retourne le résultat de chaque rechargement tenté ; en cas d’échec, la version
+    /// précédente reste en service.
+    pub fn reload_changed(&mut self) -> Vec<(String, Result<(), KernelError>)> {
+        let mut results = Vec::new();
+        for (name, entry) in &mut self.kernels {
+            let KernelSource::File(path) = &entry.source else {
+                continue;
+            };
+            let modified = std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok();
+            if modified.is_none() || modified == entry.modified {
+                continue;
+            }
+
+            let capabilities = self.capabilities;
+            let result = read_source(path).and_then(|(code, modified)| {
+                let workgroup_size = validate(name, &code, &entry.entry_point, capabilities)?;
+                entry.code = code;
+                entry.workgroup_size = workgroup_size;
+                entry.modified = modified;
+                entry.generation += 1;
+                entry.compiled = None;
+                entry.last_error = None;
+                Ok(())
+            });
+            if let Err(err) = &result {
+                // On retient la date pour ne pas réessayer tant que le fichier n’a pas changé.
+                entry.modified = modified;
+                entry.last_error = Some(err.clone());
+            }
+            results.push((name.clone(), result));
+        }
+        results
+    }
+
+    /// Exécute le noyau `name` sur `invocations` invocations.
+    ///
+    /// Au-delà de 65 535 groupes de travail, la dispatch est découpée en 2D
+    /// (`[65 535, ⌈groupes / 65 535⌉, 1]`). Le noyau doit alors linéariser son indice avec
+    /// `num_workgroups` et ignorer les invocations au-delà du total :
+    ///
+    /// ```wgsl
+    /// let index = id.y * groups.x * WORKGROUP_SIZE_X + id.x;
+    /// if (index >= total) { return; }
+    /// ```
+    pub fn dispatch(
+        &mut self,
+        gpu: &GpuContext,
+        buffers: &GpuBuffers,
+        name: &str,
+        bindings: &[KernelBinding],
+        invocations: u32,
+    ) -> Result<(), KernelError> {
+        let size = self
+            .workgroup_size(name)
+            .ok_or_else(|| KernelError::Unknown(name.to_owned()))?;
+        let groups = invocations.div_ceil(size[0].max(1)).max(1);
+        let count = if groups <= MAX_WORKGROUPS_X {
+            [groups, 1, 1]
+        } else {
+            [MAX_WORKGROUPS_X, groups.div_ceil(MAX_WORKGROUPS_X), 1]
+        };
+        self.dispatch_workgroups(gpu, buffers, name, bindings, count)
+    }
+
+    /// Exécute le noyau `name` avec un nombre explicite de groupes de travail.
+    pub fn dispatch_workgroups(
+        &mut self,
+        gpu: &GpuContext,
+        buffers: &GpuBuffers,
+        name: &str,
+        bindings: &[KernelBinding],
+        workgroups: [u32; 3],
+    ) -> Result<(), KernelError> {
+        let binding_error = |message: String| KernelError::Binding {
+            name: name.to_owned(),
+            message,
+        };
+
+        // Les uniformes temporaires doivent vivre jusqu’à la création du groupe.
+        let uniforms: Vec<Option<wgpu::Buffer>> = bindings
+            .iter()
+            .map(|binding| match binding {
+                KernelBinding::Uniform(bytes) => Some(gpu.device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("substrate::kernel_uniform"),
+                        contents: bytes,
+                        usage: wgpu::BufferUsages::UNIFORM,
+                    },
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let mut resources = Vec::with_capacity(bindings.len());
+        for (index, (binding, uniform)) in bindings.iter().zip(&uniforms).enumerate() {
+            let buffer = match binding {
+                KernelBinding::Dataset(dataset) => buffers
+                    .buffer(dataset)
+                    .ok_or_else(|| binding_error(format!("unknown dataset `{dataset}`")))?,
+                KernelBinding::Buffer(buffer) => buffer,
+                KernelBinding::Uniform(_) => uniform.as_ref().expect("uniform created above"),
+            };
+            resources.push(wgpu::BindGroupEntry {
+                binding: index as u32,
+                resource: buffer.as_entire_binding(),
+            });
+        }
+
+        let compiled = self.compiled(gpu, name)?;
+        gpu.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let bind_group = gpu.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(name),
+            layout: &compiled.layout,
+            entries: &resources,
+        });
+        if let Some(err) = block_on(gpu.device.pop_error_scope()) {
+            return Err(binding_error(err.to_string()));
+        }
+
+        let mut encoder = gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(name) });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some(name),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&compiled.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(workgroups[0], workgroups[1], workgroups[2]);
+        }
+        gpu.queue.submit(Some(encoder.finish()));
+        Ok(())
+    }
+}
+
+fn read_source(path: &Path) -> Result<(String, Option<SystemTime>), KernelError> {
+    let io_error = |err: std::io::Error| KernelError::Io {
+        path: path.to_owned(),
+        message: err.to_string(),
+    };
+    let code = std::fs::read_to_string(path).map_err(io_error)?;
+    let modified = std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok();
+    Ok((code, modified))
+}
+
+fn compile(
+    gpu: &GpuContext,
+    name: &str,
+    entry: &KernelEntry,
+) -> Result<CompiledKernel, KernelError> {
+    let device = &gpu.device;
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some(name),
+        source: wgpu::ShaderSource::Wgsl(entry.code.as_str().into()),
+    });
+    let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some(name),
+        layout: None,
+        module: &module,
+        entry_point: Some(&entry.entry_point),
+        compilation_options: Default::default(),
+        cache: None,
+    });
+    if let Some(err) = block_on(device.pop_error_scope()) {
+        return Err(KernelError::Compile {
+            name: name.to_owned(),
+            message: err.to_string(),
+        });
+    }
+
+    let layout = pipeline.get_bind_group_layout(0);
+    debug!(target: "substrate", kernel = name, generation = entry.generation, "kernel compiled");
+    Ok(CompiledKernel { pipeline, layout })
+}
+
+/// Système : recharge les noyaux modifiés sur disque (si le rechargement à chaud est actif).
+pub fn hot_reload_kernels(
+    mut registry: ResMut<KernelRegistry>,
+    time: Res<Time<Real>>,
+    mut last_check: Local<Duration>,
+) {
+    if !registry.hot_reload || time.elapsed() < *last_check + HOT_RELOAD_INTERVAL {
+        return;
+    }
+    *last_check = time.elapsed();
+
+    for (name, result) in registry.reload_changed() {
+        match result {
+            Ok(()) => info!(target: "substrate", kernel = %name, "kernel reloaded"),
+            Err(err) => {
+                error!(target: "substrate", kernel = %name, %err, "kernel reload failed; keeping previous version")
+            }
+        }
+    }
+}
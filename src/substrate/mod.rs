@@ -4,30 +4,62 @@
 //! Ce module constitue la base matérielle sur laquelle reposent la simulation, la physique, et les couches supérieures.
 
 use std::sync::Arc;
+use std::time::Duration;
+
+pub mod adapter;
+pub mod buffers;
+pub mod compute;
+pub mod field;
+pub mod fluctuation;
+pub mod kernel;
+pub mod potential;
+pub mod recovery;
+pub mod topology;
+pub mod vacuum;
+
+pub use adapter::{AdapterCandidate, AdapterRejection, AdapterReport, GpuConfig, LimitShortfall};
+pub use buffers::{
+    GpuBufferError, GpuBuffers, GpuDataset, GpuMemoryUsage, GpuReadbacks, GpuSyncConfig,
+};
+pub use compute::{ComputeBackend, ComputeBackendKind, CpuCompute};
+pub use field::{Field, FieldBoundary, FieldKind, FieldRule, FieldStats};
+pub use fluctuation::{FluctuationGenerator, FluctuationSpectrum, NoiseKind};
+pub use kernel::{CompiledKernel, KernelBinding, KernelError, KernelRegistry, KernelSource};
+pub use potential::{GlobalPotentials, Potential, PotentialEnergy, PotentialForce, PotentialKind};
+pub use recovery::{GpuEvent, GpuHealth, GpuRecoveryConfig, GpuSignals, GpuStatus};
+pub use topology::{BoundaryOutcome, DomainTopology, WallBehavior};
+pub use vacuum::{VacuumConfig, VacuumFluctuations};
 
 use bevy::prelude::*;
 use bevy::tasks::IoTaskPool;
+
+use crate::core::EngineSeed;
 use tokio::sync::oneshot;
 use tracing::{debug, error, info, warn};
 use wgpu::{
-    Backends, Device, Instance, InstanceDescriptor, InstanceFlags, PowerPreference,
-    Queue, RequestAdapterOptions, Surface,
-    TextureFormat,
+    Adapter, Device, Instance, InstanceDescriptor, InstanceFlags, Queue, Surface, TextureFormat,
 };
 
 /// Structure représentant le contexte GPU global du Void Engine.
 #[derive(Resource, Clone)]
 pub struct GpuContext {
     pub adapter_name: String,
+    /// `true` si l’adaptateur retenu est logiciel (rendu sur CPU, ex. llvmpipe).
+    pub fallback_adapter: bool,
     pub device: Arc<Device>,
     pub queue: Arc<Queue>,
     pub surface_format: Option<TextureFormat>,
     pub surface: Option<Arc<Surface<'static>>>,
 }
 
+/// Raison de l’échec de l’initialisation GPU.
 #[derive(Debug)]
 pub enum GpuInitError {
+    /// Aucun adaptateur n’est visible pour les backends configurés.
     NoAdapter,
+    /// Des adaptateurs existent mais tous ont été rejetés (nom de l’adaptateur, raison).
+    NoSuitableAdapter(Vec<(String, AdapterRejection)>),
+    /// L’adaptateur retenu a refusé la création du device.
     RequestDevice(String),
 }
 
@@ -35,6 +67,13 @@ impl std::fmt::Display for GpuInitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NoAdapter => write!(f, "no compatible GPU adapter available"),
+            Self::NoSuitableAdapter(rejected) => {
+                write!(f, "no suitable GPU adapter among {} candidates", rejected.len())?;
+                for (name, rejection) in rejected {
+                    write!(f, "; {name}: {rejection}")?;
+                }
+                Ok(())
+            }
             Self::RequestDevice(err) => write!(f, "failed to request device: {err}"),
         }
     }
@@ -48,29 +87,65 @@ impl From<wgpu::RequestDeviceError> for GpuInitError {
     }
 }
 
+/// Crée une instance wgpu limitée aux backends configurés.
+pub(crate) fn create_instance(config: &GpuConfig) -> Instance {
+    Instance::new(&InstanceDescriptor {
+        flags: InstanceFlags::default(),
+        backends: config.backends,
+        ..Default::default()
+    })
+}
+
 impl GpuContext {
-    /// Initialise le contexte GPU (backend auto-détecté).
-    pub async fn initialize() -> Result<Self, GpuInitError> {
-        info!(target: "substrate", "starting GPU initialization");
-
-        let instance = Instance::new(&InstanceDescriptor {
-            flags: InstanceFlags::default(),
-            backends: Backends::all(),
-            ..Default::default()
-        });
-
-        let adapter = instance
-            .request_adapter(&RequestAdapterOptions {
-                power_preference: PowerPreference::HighPerformance,
-                force_fallback_adapter: false,
-                compatible_surface: None,
-            })
-            .await
-            .map_err(|_| GpuInitError::NoAdapter)?;
+    /// Initialise le contexte GPU selon `config`.
+    pub async fn initialize(config: &GpuConfig) -> Result<Self, GpuInitError> {
+        Self::initialize_with_report(config).await.1
+    }
+
+    /// Initialise le contexte GPU et retourne le rapport d’énumération des adaptateurs.
+    pub async fn initialize_with_report(
+        config: &GpuConfig,
+    ) -> (AdapterReport, Result<Self, GpuInitError>) {
+        info!(target: "substrate", backends = ?config.backends, "starting GPU initialization");
+
+        let instance = create_instance(config);
+        let (report, selected) = AdapterReport::evaluate(&instance, config);
+        for (candidate, rejection) in report.rejected() {
+            debug!(target: "substrate", adapter = %candidate.info.name, %rejection, "adapter rejected");
+        }
+
+        let result = match selected {
+            Some(adapter) => {
+                let fallback = report.selected().is_some_and(AdapterCandidate::is_fallback);
+                Self::from_adapter(adapter, config, fallback).await
+            }
+            None if !report.candidates.is_empty() => Err(GpuInitError::NoSuitableAdapter(
+                report
+                    .rejected()
+                    .map(|(candidate, rejection)| (candidate.info.name.clone(), rejection.clone()))
+                    .collect(),
+            )),
+            // Les adaptateurs logiciels sont énumérés comme les autres (classés en dernier) :
+            // une liste vide signifie qu’aucun adaptateur n’est disponible.
+            None => Err(GpuInitError::NoAdapter),
+        };
+
+        (report, result)
+    }
 
+    async fn from_adapter(
+        adapter: Adapter,
+        config: &GpuConfig,
+        fallback_adapter: bool,
+    ) -> Result<Self, GpuInitError> {
         let adapter_info = adapter.get_info();
         let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor::default())
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("void_engine::substrate"),
+                required_features: config.required_features,
+                required_limits: config.required_limits.clone(),
+                ..Default::default()
+            })
             .await?;
 
         let surface: Option<Arc<Surface<'static>>> = None;
@@ -87,6 +162,7 @@ impl GpuContext {
 
         Ok(Self {
             adapter_name: adapter_info.name,
+            fallback_adapter,
             device: Arc::new(device),
             queue: Arc::new(queue),
             surface_format,
@@ -100,41 +176,125 @@ fn configure_surface(_surface: &Surface<'static>, _device: &Device, _format: Tex
     // No longer used due to removed surface creation
 }
 
+/// Initialisation GPU en cours : canal du résultat et instant (temps réel) du lancement.
 #[derive(Resource)]
-struct PendingGpuInit(oneshot::Receiver<Result<GpuContext, GpuInitError>>);
+struct PendingGpuInit {
+    receiver: oneshot::Receiver<(AdapterReport, Result<GpuContext, GpuInitError>)>,
+    started: Duration,
+}
 
-fn start_gpu_initialization(mut commands: Commands) {
+fn spawn_gpu_initialization(commands: &mut Commands, config: &GpuConfig, now: Duration) {
     let (sender, receiver) = oneshot::channel();
+    let config = config.clone();
 
     IoTaskPool::get().spawn(async move {
-        let result = GpuContext::initialize().await;
-        let _ = sender.send(result);
+        let outcome = GpuContext::initialize_with_report(&config).await;
+        let _ = sender.send(outcome);
     }).detach();
 
-    commands.insert_resource(PendingGpuInit(receiver));
+    commands.insert_resource(PendingGpuInit {
+        receiver,
+        started: now,
+    });
     info!(target: "substrate", "spawned asynchronous GPU task");
 }
 
-fn poll_gpu_initialization(mut commands: Commands, pending: Option<ResMut<PendingGpuInit>>) {
+fn start_gpu_initialization(mut commands: Commands, config: Res<GpuConfig>, time: Res<Time<Real>>) {
+    spawn_gpu_initialization(&mut commands, &config, time.elapsed());
+}
+
+/// Système : relance l’initialisation GPU lorsque la tentative planifiée est échue.
+fn retry_gpu_initialization(
+    mut commands: Commands,
+    config: Res<GpuConfig>,
+    mut health: ResMut<GpuHealth>,
+    pending: Option<Res<PendingGpuInit>>,
+    time: Res<Time<Real>>,
+) {
+    if pending.is_some() || health.status != GpuStatus::Unavailable {
+        return;
+    }
+    let now = time.elapsed();
+    if health.retry_at.is_some_and(|at| now >= at) {
+        info!(target: "substrate", attempt = health.attempts, "retrying GPU initialization");
+        health.status = GpuStatus::Initializing;
+        health.retry_at = None;
+        spawn_gpu_initialization(&mut commands, &config, now);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn poll_gpu_initialization(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingGpuInit>>,
+    recovery: Res<GpuRecoveryConfig>,
+    mut health: ResMut<GpuHealth>,
+    mut signals: ResMut<GpuSignals>,
+    mut events: MessageWriter<GpuEvent>,
+    time: Res<Time<Real>>,
+) {
     let Some(mut pending) = pending else {
         return;
     };
+    let now = time.elapsed();
 
-    match pending.0.try_recv() {
-        Ok(result) => match result {
+    match pending.receiver.try_recv() {
+        Ok((report, result)) => match result {
             Ok(context) => {
                 info!(target: "substrate", adapter = %context.adapter_name, "GPU context ready");
+                let recovered = health.attempts > 0 || health.device_losses > 0;
+                signals.watch(&context);
+                health.mark_ready();
+                events.write(GpuEvent::Ready {
+                    adapter: context.adapter_name.clone(),
+                    recovered,
+                });
+                let backend = ComputeBackend::Gpu(context.clone());
+                info!(target: "substrate", backend = %backend.describe(), "compute backend selected");
                 commands.insert_resource(context);
+                commands.insert_resource(backend);
+                commands.insert_resource(report);
                 commands.remove_resource::<PendingGpuInit>();
             }
             Err(err) => {
-                error!(target: "substrate", ?err, "failed to initialize GPU context");
+                error!(target: "substrate", %err, "failed to initialize GPU context");
+                commands.insert_resource(report);
+                compute::use_cpu_backend(&mut commands, "GPU initialization failed");
                 commands.remove_resource::<PendingGpuInit>();
+                events.write(GpuEvent::InitFailed {
+                    error: err.to_string(),
+                });
+                // Sans adaptateur visible, ou si la configuration les rejette tous, une
+                // nouvelle tentative ne changerait rien.
+                if matches!(
+                    err,
+                    GpuInitError::NoAdapter | GpuInitError::NoSuitableAdapter(_)
+                ) {
+                    health.status = GpuStatus::Abandoned;
+                    health.last_error = Some(err.to_string());
+                } else {
+                    health.schedule_retry(&recovery, now, err.to_string());
+                }
             }
         },
+        Err(oneshot::error::TryRecvError::Empty) => {
+            if let Some(waited) = recovery.startup_expired(pending.started, now) {
+                warn!(target: "substrate", ?waited, "GPU initialization timed out; continuing on CPU");
+                // La tâche continue mais son résultat sera ignoré (récepteur abandonné).
+                commands.remove_resource::<PendingGpuInit>();
+                compute::use_cpu_backend(&mut commands, "GPU initialization timed out");
+                events.write(GpuEvent::InitTimedOut { waited });
+                health.schedule_retry(&recovery, now, "initialization timed out".into());
+            }
+        }
         Err(err) => {
             error!(target: "substrate", ?err, "GPU initialization channel closed unexpectedly");
+            compute::use_cpu_backend(&mut commands, "GPU initialization channel closed");
             commands.remove_resource::<PendingGpuInit>();
+            events.write(GpuEvent::InitFailed {
+                error: "initialization task dropped".into(),
+            });
+            health.schedule_retry(&recovery, now, "initialization task dropped".into());
         }
     }
 }
@@ -143,8 +303,65 @@ fn poll_gpu_initialization(mut commands: Commands, pending: Option<ResMut<Pendin
 pub fn init(app: &mut App) {
     info!(target: "substrate", "initializing GPU substrate module");
 
-    app.add_systems(Startup, start_gpu_initialization)
-        .add_systems(Update, poll_gpu_initialization);
+    // La configuration peut être fournie par l’hôte avant l’initialisation.
+    if !app.world().contains_resource::<GpuConfig>() {
+        app.insert_resource(GpuConfig::from_env());
+    }
+
+    // Le CPU assure les calculs tant que le GPU n’est pas prêt (ou s’il est absent).
+    let cpu = app
+        .world_mut()
+        .get_resource_or_init::<CpuCompute>()
+        .clone();
+    app.insert_resource(ComputeBackend::Cpu(cpu))
+        .init_resource::<AdapterReport>()
+        .init_resource::<GpuRecoveryConfig>()
+        .init_resource::<GpuHealth>()
+        .init_resource::<GpuSignals>()
+        .register_type::<GpuRecoveryConfig>()
+        .register_type::<GpuHealth>()
+        .add_message::<GpuEvent>()
+        .init_resource::<GpuBuffers>()
+        .init_resource::<GpuReadbacks>()
+        .init_resource::<GpuMemoryUsage>()
+        .init_resource::<GpuSyncConfig>()
+        .init_resource::<KernelRegistry>()
+        .register_type::<GpuMemoryUsage>()
+        .register_type::<GpuSyncConfig>()
+        .init_resource::<EngineSeed>()
+        .init_resource::<VacuumConfig>()
+        .register_type::<VacuumConfig>()
+        .init_resource::<GlobalPotentials>()
+        .init_resource::<PotentialEnergy>()
+        .register_type::<Potential>()
+        .register_type::<PotentialForce>()
+        .register_type::<GlobalPotentials>()
+        .register_type::<PotentialEnergy>()
+        .init_resource::<DomainTopology>()
+        .register_type::<DomainTopology>()
+        .add_systems(Startup, (start_gpu_initialization, vacuum::seed_vacuum))
+        .add_systems(
+            Update,
+            (
+                recovery::watch_gpu_signals,
+                retry_gpu_initialization,
+                poll_gpu_initialization,
+                kernel::hot_reload_kernels,
+                vacuum::advance_vacuum,
+                (vacuum::perturb_fields, vacuum::perturb_entropy),
+                field::step_fields,
+            )
+                .chain(),
+        )
+        // Les jeux de données GPU sont synchronisés une fois les positions définitives.
+        .add_systems(
+            PostUpdate,
+            (
+                buffers::sync_gpu_datasets,
+                buffers::collect_gpu_readbacks,
+            )
+                .chain(),
+        );
 }
 
 /// Fonction de debug — affiche l’état du module `substrate`.
@@ -0,0 +1,185 @@
+//! Potentiels du substrat — sources d’énergie potentielle et forces de gradient.
+//!
+//! Un potentiel est déclaré soit comme composant [`Potential`] sur une entité (centré sur
+//! son `Transform`), soit dans la ressource [`GlobalPotentials`]. Pour chaque corps
+//! dynamique (`Mass` + `Transform`), `dynamics::forces::accumulate_forces` évalue
+//! `F = -∇U` et l’énergie `U` une fois par pas fixe et les écrit dans [`PotentialForce`].
+//! La somme des énergies est exposée par [`PotentialEnergy`].
+
+use bevy::prelude::*;
+
+use super::field::Field;
+use super::topology::DomainTopology;
+
+/// Forme analytique (ou échantillonnée) d’un potentiel.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum PotentialKind {
+    /// Puits gravitationnel adouci : `U = -m μ / √(r² + ε²)`.
+    GravityWell { strength: f32, softening: f32 },
+    /// Piège harmonique : `U = ½ k r²`.
+    HarmonicTrap { stiffness: f32 },
+    /// Lennard-Jones tronqué à `cutoff` : `U = 4ε [(σ/r)¹² - (σ/r)⁶]`.
+    LennardJones {
+        epsilon: f32,
+        sigma: f32,
+        cutoff: f32,
+    },
+    /// Potentiel échantillonné dans le champ scalaire nommé `field` : `U = scale · φ(x)`.
+    Grid { field: String, scale: f32 },
+}
+
+/// Composant : l’entité est la source d’un potentiel centré sur sa position.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Potential {
+    pub kind: PotentialKind,
+}
+
+/// Ressource : potentiels globaux, non attachés à une entité (centre, forme).
+#[derive(Resource, Default, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct GlobalPotentials(pub Vec<(Vec3, PotentialKind)>);
+
+/// Composant : force de gradient et énergie potentielle subies par un corps ce tick.
+#[derive(Component, Default, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct PotentialForce {
+    pub force: Vec3,
+    pub energy: f32,
+}
+
+/// Ressource : énergie potentielle totale des corps dynamiques.
+#[derive(Resource, Default, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PotentialEnergy {
+    pub total: f32,
+}
+
+impl PotentialKind {
+    /// Évalue la force et l’énergie pour un corps de masse `mass` à `offset` du centre.
+    ///
+    /// `position` est la position monde du corps (utilisée par les potentiels de grille).
+    pub fn evaluate<'a>(
+        &self,
+        offset: Vec3,
+        position: Vec3,
+        mass: f32,
+        fields: impl IntoIterator<Item = &'a Field>,
+    ) -> (Vec3, f32) {
+        match self {
+            Self::GravityWell {
+                strength,
+                softening,
+            } => {
+                let r2 = offset.length_squared() + softening * softening;
+                if r2 <= f32::EPSILON {
+                    return (Vec3::ZERO, 0.0);
+                }
+                let r = r2.sqrt();
+                (-offset * mass * strength / (r2 * r), -mass * strength / r)
+            }
+            Self::HarmonicTrap { stiffness } => (
+                -offset * *stiffness,
+                0.5 * stiffness * offset.length_squared(),
+            ),
+            Self::LennardJones {
+                epsilon,
+                sigma,
+                cutoff,
+            } => {
+                let r = offset.length();
+                if r <= f32::EPSILON || r > *cutoff {
+                    return (Vec3::ZERO, 0.0);
+                }
+                let s6 = (sigma / r).powi(6);
+                let s12 = s6 * s6;
+                let magnitude = 24.0 * epsilon * (2.0 * s12 - s6) / r;
+                (offset / r * magnitude, 4.0 * epsilon * (s12 - s6))
+            }
+            Self::Grid { field, scale } => {
+                let Some(field) = fields.into_iter().find(|f| &f.name == field) else {
+                    return (Vec3::ZERO, 0.0);
+                };
+                let Some(value) = field.sample_scalar(position) else {
+                    return (Vec3::ZERO, 0.0);
+                };
+                let h = field.spacing * 0.5;
+                // Différence centrée, ou décentrée d’un pas `h` au bord de la grille.
+                let derivative = |axis: Vec3| {
+                    let forward = field.sample_scalar(position + axis * h);
+                    let backward = field.sample_scalar(position - axis * h);
+                    match (forward, backward) {
+                        (Some(forward), Some(backward)) => (forward - backward) / (2.0 * h),
+                        (Some(forward), None) => (forward - value) / h,
+                        (None, Some(backward)) => (value - backward) / h,
+                        (None, None) => 0.0,
+                    }
+                };
+                let gradient = Vec3::new(
+                    derivative(Vec3::X),
+                    derivative(Vec3::Y),
+                    derivative(Vec3::Z),
+                );
+                (-gradient * *scale, value * scale)
+            }
+        }
+    }
+}
+
+/// Instantané des sources de potentiel, évaluable à n’importe quelle position.
+///
+/// Les intégrateurs d’ordre élevé réévaluent la force en des positions intermédiaires
+/// du pas ; les sources y sont considérées fixes.
+#[derive(Clone, Debug, Default)]
+pub struct PotentialSources {
+    /// (entité source éventuelle, centre, forme).
+    sources: Vec<(Option<Entity>, Vec3, PotentialKind)>,
+    topology: DomainTopology,
+}
+
+impl PotentialSources {
+    /// Rassemble les potentiels portés par des entités et les potentiels globaux.
+    pub fn collect<'a>(
+        local: impl IntoIterator<Item = (Entity, &'a Potential, Option<&'a Transform>)>,
+        global: &GlobalPotentials,
+        topology: &DomainTopology,
+    ) -> Self {
+        let local = local.into_iter().map(|(entity, potential, transform)| {
+            (
+                Some(entity),
+                transform.map_or(Vec3::ZERO, |t| t.translation),
+                potential.kind.clone(),
+            )
+        });
+        let global = global
+            .0
+            .iter()
+            .map(|(center, kind)| (None, *center, kind.clone()));
+        Self {
+            sources: local.chain(global).collect(),
+            topology: topology.clone(),
+        }
+    }
+
+    /// `true` si aucune source n’est présente.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Force et énergie potentielle subies par `body` (de masse `mass`) en `position`.
+    pub fn evaluate(&self, body: Entity, position: Vec3, mass: f32, fields: &[&Field]) -> (Vec3, f32) {
+        self.sources
+            .iter()
+            // Un corps ne subit pas son propre potentiel.
+            .filter(|(source, _, _)| *source != Some(body))
+            .fold((Vec3::ZERO, 0.0), |(force, energy), (_, center, kind)| {
+                let (f, u) = kind.evaluate(
+                    self.topology.displacement(*center, position),
+                    position,
+                    mass,
+                    fields.iter().copied(),
+                );
+                (force + f, energy + u)
+            })
+    }
+}
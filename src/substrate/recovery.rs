@@ -0,0 +1,253 @@
+//! Surveillance et reprise du GPU.
+//!
+//! Les callbacks wgpu (perte de device, erreurs non capturées) s’exécutent sur des threads
+//! arbitraires : ils déposent un [`GpuSignal`] dans un canal, relu à chaque tick par
+//! [`watch_gpu_signals`] et converti en messages [`GpuEvent`]. Tant que le GPU est
+//! indisponible (perte, échec ou délai d’initialisation dépassé), le substrat calcule sur
+//! CPU et une nouvelle initialisation est planifiée avec un recul exponentiel.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use tracing::{error, info, warn};
+use wgpu::DeviceLostReason;
+
+use super::GpuContext;
+
+/// Paramètres de surveillance et de reprise du GPU.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct GpuRecoveryConfig {
+    /// Délai maximal d’une initialisation avant abandon (temps réel).
+    pub startup_timeout: Duration,
+    /// Attente avant la première nouvelle tentative.
+    pub initial_backoff: Duration,
+    /// Attente maximale entre deux tentatives.
+    pub max_backoff: Duration,
+    /// Nombre maximal de nouvelles tentatives consécutives (`None` : illimité).
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for GpuRecoveryConfig {
+    fn default() -> Self {
+        Self {
+            startup_timeout: Duration::from_secs(10),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: Some(8),
+        }
+    }
+}
+
+impl GpuRecoveryConfig {
+    /// Attente avant la tentative numéro `attempt` (1 = première reprise).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Attente d’une initialisation lancée à `started`, si elle a atteint `startup_timeout`.
+    pub fn startup_expired(&self, started: Duration, now: Duration) -> Option<Duration> {
+        let waited = now.saturating_sub(started);
+        (waited >= self.startup_timeout).then_some(waited)
+    }
+}
+
+/// État de disponibilité du GPU.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum GpuStatus {
+    /// Initialisation en cours.
+    #[default]
+    Initializing,
+    /// Device opérationnel.
+    Ready,
+    /// Indisponible ; une nouvelle tentative est planifiée.
+    Unavailable,
+    /// Reprises épuisées (ou reprise impossible) : le CPU reste le backend.
+    Abandoned,
+}
+
+/// Ressource : santé du GPU et planification des reprises.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct GpuHealth {
+    pub status: GpuStatus,
+    /// Tentatives de reprise consécutives depuis le dernier succès.
+    pub attempts: u32,
+    /// Instant (temps réel écoulé) de la prochaine tentative.
+    pub retry_at: Option<Duration>,
+    /// Dernière erreur observée.
+    pub last_error: Option<String>,
+    /// Nombre de pertes de device depuis le démarrage.
+    pub device_losses: u32,
+    /// Nombre d’erreurs wgpu non capturées depuis le démarrage.
+    pub uncaptured_errors: u32,
+}
+
+impl GpuHealth {
+    /// Marque le GPU indisponible et planifie une tentative selon `config` ; abandonne
+    /// au-delà de `max_attempts` tentatives consécutives.
+    pub fn schedule_retry(&mut self, config: &GpuRecoveryConfig, now: Duration, reason: String) {
+        self.last_error = Some(reason);
+        self.attempts += 1;
+        if config.max_attempts.is_some_and(|max| self.attempts > max) {
+            warn!(target: "substrate", attempts = self.attempts - 1, "GPU recovery abandoned; staying on CPU");
+            self.status = GpuStatus::Abandoned;
+            self.retry_at = None;
+            return;
+        }
+        let delay = config.backoff(self.attempts);
+        info!(target: "substrate", attempt = self.attempts, ?delay, "GPU re-initialization scheduled");
+        self.status = GpuStatus::Unavailable;
+        self.retry_at = Some(now + delay);
+    }
+
+    /// Marque le GPU opérationnel.
+    pub(crate) fn mark_ready(&mut self) {
+        self.status = GpuStatus::Ready;
+        self.attempts = 0;
+        self.retry_at = None;
+    }
+}
+
+/// Message : événement de cycle de vie du GPU.
+#[derive(Message, Clone, Debug)]
+pub enum GpuEvent {
+    /// Un device est prêt (premier démarrage ou reprise).
+    Ready { adapter: String, recovered: bool },
+    /// L’initialisation a échoué.
+    InitFailed { error: String },
+    /// L’initialisation n’a pas abouti dans le délai imparti.
+    InitTimedOut { waited: Duration },
+    /// Le device a été perdu.
+    DeviceLost { reason: String, message: String },
+    /// Erreur wgpu non interceptée par un error scope.
+    UncapturedError { error: String },
+}
+
+/// Signal brut émis par un callback wgpu.
+#[derive(Debug)]
+pub enum GpuSignal {
+    DeviceLost {
+        /// Génération du device concerné (les signaux d’un device remplacé sont ignorés).
+        generation: u64,
+        reason: DeviceLostReason,
+        message: String,
+    },
+    UncapturedError {
+        generation: u64,
+        error: String,
+    },
+}
+
+/// Ressource : canal reliant les callbacks wgpu au monde ECS.
+#[derive(Resource)]
+pub struct GpuSignals {
+    sender: Sender<GpuSignal>,
+    receiver: Mutex<Receiver<GpuSignal>>,
+    /// Génération du device actuellement surveillé.
+    generation: u64,
+}
+
+impl Default for GpuSignals {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+            generation: 0,
+        }
+    }
+}
+
+impl GpuSignals {
+    /// Installe les callbacks de perte et d’erreur sur le device de `context`.
+    pub fn watch(&mut self, context: &GpuContext) {
+        self.generation += 1;
+        let generation = self.generation;
+
+        let sender = self.sender.clone();
+        context
+            .device
+            .set_device_lost_callback(move |reason, message| {
+                let _ = sender.send(GpuSignal::DeviceLost {
+                    generation,
+                    reason,
+                    message,
+                });
+            });
+
+        let sender = self.sender.clone();
+        context
+            .device
+            .on_uncaptured_error(Box::new(move |err: wgpu::Error| {
+                let _ = sender.send(GpuSignal::UncapturedError {
+                    generation,
+                    error: err.to_string(),
+                });
+            }));
+    }
+
+    /// Retire les signaux en attente émis par le device courant.
+    fn drain(&self) -> Vec<GpuSignal> {
+        let Ok(receiver) = self.receiver.lock() else {
+            return Vec::new();
+        };
+        receiver
+            .try_iter()
+            .filter(|signal| match signal {
+                GpuSignal::DeviceLost { generation, .. }
+                | GpuSignal::UncapturedError { generation, .. } => *generation == self.generation,
+            })
+            .collect()
+    }
+}
+
+/// Système : relaie les signaux wgpu et bascule sur CPU en cas de perte du device.
+pub fn watch_gpu_signals(
+    mut commands: Commands,
+    signals: Res<GpuSignals>,
+    context: Option<Res<GpuContext>>,
+    config: Res<GpuRecoveryConfig>,
+    mut health: ResMut<GpuHealth>,
+    mut events: MessageWriter<GpuEvent>,
+    time: Res<Time<Real>>,
+) {
+    // Les callbacks de perte ne sont délivrés que lors d’un `poll` du device.
+    if let Some(context) = context {
+        let _ = context.device.poll(wgpu::PollType::Poll);
+    }
+
+    for signal in signals.drain() {
+        match signal {
+            GpuSignal::DeviceLost {
+                reason, message, ..
+            } => {
+                error!(target: "substrate", ?reason, %message, "GPU device lost");
+                health.device_losses += 1;
+                commands.remove_resource::<GpuContext>();
+                super::compute::use_cpu_backend(&mut commands, "GPU device lost");
+                events.write(GpuEvent::DeviceLost {
+                    reason: format!("{reason:?}"),
+                    message: message.clone(),
+                });
+                health.attempts = 0;
+                health.schedule_retry(
+                    &config,
+                    time.elapsed(),
+                    format!("device lost ({reason:?}) {message}"),
+                );
+            }
+            GpuSignal::UncapturedError { error, .. } => {
+                error!(target: "substrate", %error, "uncaptured GPU error");
+                health.uncaptured_errors += 1;
+                health.last_error = Some(error.clone());
+                events.write(GpuEvent::UncapturedError { error });
+            }
+        }
+    }
+}
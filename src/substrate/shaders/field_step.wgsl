@@ -0,0 +1,90 @@
+// Pas d'intégration d'une grille de champ (substrate::field).
+//
+// Chaque invocation met à jour une composante d'une cellule.
+// rule 0 : réaction-diffusion  u' = u + dt (D ∇²u + r u (1 - u))
+// rule 1 : équation d'onde     u' = 2u - u_prev + (c dt)² ∇²u - γ dt (u - u_prev)
+
+struct Params {
+    nx: u32,
+    ny: u32,
+    nz: u32,
+    components: u32,
+    rule: u32,
+    boundary: u32,
+    _pad0: u32,
+    _pad1: u32,
+    spacing: f32,
+    dt: f32,
+    p0: f32,
+    p1: f32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> current: array<f32>;
+@group(0) @binding(2) var<storage, read> previous: array<f32>;
+@group(0) @binding(3) var<storage, read_write> next: array<f32>;
+
+// boundary 0 : Neumann (indices bornés), 1 : périodique, 2 : Dirichlet (zéro hors grille)
+fn resolve(v: i32, n: u32) -> i32 {
+    let size = i32(n);
+    if (params.boundary == 1u) {
+        // Les voisins sont à ±1 cellule : un seul repli suffit (et évite `%` sur un négatif).
+        if (v < 0) {
+            return v + size;
+        }
+        if (v >= size) {
+            return v - size;
+        }
+        return v;
+    }
+    if (params.boundary == 2u && (v < 0 || v >= size)) {
+        return -1;
+    }
+    return clamp(v, 0, size - 1);
+}
+
+fn value(x: i32, y: i32, z: i32, c: u32) -> f32 {
+    let cx = resolve(x, params.nx);
+    let cy = resolve(y, params.ny);
+    let cz = resolve(z, params.nz);
+    if (cx < 0 || cy < 0 || cz < 0) {
+        return 0.0;
+    }
+    return current[((u32(cz) * params.ny + u32(cy)) * params.nx + u32(cx)) * params.components + c];
+}
+
+@compute @workgroup_size(64)
+fn main(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let total = params.nx * params.ny * params.nz * params.components;
+    // Au-delà de 65 535 groupes, KernelRegistry::dispatch découpe la grille en 2D :
+    // chaque ligne y couvre groups.x * 64 invocations ; la dernière ligne déborde du total.
+    let index = id.y * groups.x * 64u + id.x;
+    if (index >= total) {
+        return;
+    }
+
+    let c = index % params.components;
+    let cell = index / params.components;
+    let x = i32(cell % params.nx);
+    let y = i32((cell / params.nx) % params.ny);
+    let z = i32(cell / (params.nx * params.ny));
+
+    let u = current[index];
+    var lap = value(x - 1, y, z, c) + value(x + 1, y, z, c)
+        + value(x, y - 1, z, c) + value(x, y + 1, z, c) - 4.0 * u;
+    if (params.nz > 1u) {
+        lap = lap + value(x, y, z - 1, c) + value(x, y, z + 1, c) - 2.0 * u;
+    }
+    lap = lap / (params.spacing * params.spacing);
+
+    if (params.rule == 0u) {
+        next[index] = u + params.dt * (params.p0 * lap + params.p1 * u * (1.0 - u));
+    } else {
+        let prev = previous[index];
+        let courant = params.p0 * params.dt;
+        next[index] = 2.0 * u - prev + courant * courant * lap - params.p1 * params.dt * (u - prev);
+    }
+}
@@ -0,0 +1,225 @@
+//! Topologie du domaine de simulation et conditions aux limites.
+//!
+//! [`DomainTopology`] décrit l’espace dans lequel évoluent les entités : illimité, boîte
+//! à parois réfléchissantes ou absorbantes, tore périodique, ou sphère. La même topologie
+//! s’applique :
+//! - aux positions des entités mobiles ([`apply_topology`], planifié par `dynamics`),
+//! - aux distances entre voisins ([`DomainTopology::displacement`], image minimale en périodique),
+//! - aux bords des grilles de champ ([`DomainTopology::field_boundary`]).
+
+use bevy::prelude::*;
+use tracing::debug;
+
+use super::field::FieldBoundary;
+use crate::dynamics::Velocity;
+use crate::structure::EntityTag;
+
+/// Comportement d’une paroi du domaine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum WallBehavior {
+    /// La composante normale de la vitesse est inversée.
+    #[default]
+    Reflecting,
+    /// L’entité qui franchit la paroi est retirée du monde.
+    Absorbing,
+}
+
+/// Topologie du domaine de simulation.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub enum DomainTopology {
+    /// Espace euclidien sans bord.
+    #[default]
+    Unbounded,
+    /// Boîte alignée sur les axes.
+    Box {
+        min: Vec3,
+        max: Vec3,
+        walls: WallBehavior,
+    },
+    /// Tore : chaque axe se referme sur lui-même.
+    ///
+    /// Un axe de taille nulle (`min == max`) n’est pas périodique : il reste illimité.
+    Periodic { min: Vec3, max: Vec3 },
+    /// Boule de centre `center` et de rayon `radius`.
+    Sphere {
+        center: Vec3,
+        radius: f32,
+        walls: WallBehavior,
+    },
+}
+
+/// Résultat de l’application des conditions aux limites à une position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundaryOutcome {
+    Inside,
+    Reflected,
+    Wrapped,
+    Absorbed,
+}
+
+impl DomainTopology {
+    /// Axes qui se referment sur eux-mêmes (axes de taille non nulle d’un domaine périodique).
+    pub fn periodic_axes(&self) -> BVec3 {
+        match self {
+            Self::Periodic { min, max } => {
+                let size = *max - *min;
+                size.cmpgt(Vec3::ZERO) & size.is_finite_mask()
+            }
+            _ => BVec3::FALSE,
+        }
+    }
+
+    /// Vecteur le plus court de `from` vers `to` (convention de l’image minimale en périodique).
+    pub fn displacement(&self, from: Vec3, to: Vec3) -> Vec3 {
+        let delta = to - from;
+        match self {
+            Self::Periodic { min, max } => {
+                let size = *max - *min;
+                Vec3::select(
+                    self.periodic_axes(),
+                    delta - size * (delta / size).round(),
+                    delta,
+                )
+            }
+            _ => delta,
+        }
+    }
+
+    /// Distance la plus courte entre deux points.
+    pub fn distance(&self, a: Vec3, b: Vec3) -> f32 {
+        self.displacement(a, b).length()
+    }
+
+    /// Ramène une position dans la cellule principale (axes périodiques uniquement).
+    pub fn wrap(&self, position: Vec3) -> Vec3 {
+        match self {
+            Self::Periodic { min, max } => {
+                let size = *max - *min;
+                Vec3::select(
+                    self.periodic_axes(),
+                    *min + (position - *min).rem_euclid(size),
+                    position,
+                )
+            }
+            _ => position,
+        }
+    }
+
+    /// Applique les conditions aux limites à une position et sa vitesse.
+    pub fn apply(&self, position: &mut Vec3, velocity: &mut Vec3) -> BoundaryOutcome {
+        match *self {
+            Self::Unbounded => BoundaryOutcome::Inside,
+            Self::Periodic { .. } => {
+                let wrapped = self.wrap(*position);
+                if wrapped == *position {
+                    BoundaryOutcome::Inside
+                } else {
+                    *position = wrapped;
+                    BoundaryOutcome::Wrapped
+                }
+            }
+            Self::Box { min, max, walls } => {
+                let outside = position.cmplt(min) | position.cmpgt(max);
+                if !outside.any() {
+                    return BoundaryOutcome::Inside;
+                }
+                if walls == WallBehavior::Absorbing {
+                    return BoundaryOutcome::Absorbed;
+                }
+                for axis in 0..3 {
+                    if position[axis] < min[axis] {
+                        position[axis] = 2.0 * min[axis] - position[axis];
+                        velocity[axis] = velocity[axis].abs();
+                    } else if position[axis] > max[axis] {
+                        position[axis] = 2.0 * max[axis] - position[axis];
+                        velocity[axis] = -velocity[axis].abs();
+                    }
+                }
+                // Un pas plus grand que la boîte peut encore dépasser : on borne.
+                *position = position.clamp(min, max);
+                BoundaryOutcome::Reflected
+            }
+            Self::Sphere {
+                center,
+                radius,
+                walls,
+            } => {
+                let offset = *position - center;
+                let distance = offset.length();
+                if distance <= radius {
+                    return BoundaryOutcome::Inside;
+                }
+                if walls == WallBehavior::Absorbing {
+                    return BoundaryOutcome::Absorbed;
+                }
+                let normal = offset / distance;
+                *position = center + normal * (2.0 * radius - distance).max(0.0);
+                let normal_speed = velocity.dot(normal);
+                if normal_speed > 0.0 {
+                    *velocity -= 2.0 * normal_speed * normal;
+                }
+                BoundaryOutcome::Reflected
+            }
+        }
+    }
+
+    /// Condition aux limites équivalente pour les grilles de champ.
+    ///
+    /// Les parois réfléchissantes donnent un bord de Neumann (flux nul), les parois
+    /// absorbantes un bord de Dirichlet (valeur nulle). La sphère s’applique aux bords
+    /// de la grille, qui l’englobe.
+    pub fn field_boundary(&self) -> FieldBoundary {
+        match self {
+            Self::Unbounded => FieldBoundary::Neumann,
+            Self::Periodic { .. } if self.periodic_axes().any() => FieldBoundary::Periodic,
+            Self::Periodic { .. } => FieldBoundary::Neumann,
+            Self::Box { walls, .. } | Self::Sphere { walls, .. } => match walls {
+                WallBehavior::Reflecting => FieldBoundary::Neumann,
+                WallBehavior::Absorbing => FieldBoundary::Dirichlet,
+            },
+        }
+    }
+}
+
+/// Entités soumises aux conditions aux limites : corps dynamiques et entités structurelles.
+type MobileQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Transform,
+        Option<&'static mut Velocity>,
+    ),
+    Or<(With<Velocity>, With<EntityTag>)>,
+>;
+
+/// Système : applique la topologie aux entités mobiles (après les déplacements du tick).
+pub fn apply_topology(
+    mut commands: Commands,
+    topology: Res<DomainTopology>,
+    mut query: MobileQuery,
+) {
+    if *topology == DomainTopology::Unbounded {
+        return;
+    }
+
+    for (entity, mut transform, velocity) in query.iter_mut() {
+        let mut position = transform.translation;
+        let mut linear = velocity.as_ref().map_or(Vec3::ZERO, |v| v.linear);
+
+        match topology.apply(&mut position, &mut linear) {
+            BoundaryOutcome::Inside => {}
+            BoundaryOutcome::Absorbed => {
+                debug!(target: "substrate", ?entity, "entity absorbed by domain wall");
+                commands.entity(entity).despawn();
+            }
+            BoundaryOutcome::Reflected | BoundaryOutcome::Wrapped => {
+                transform.translation = position;
+                if let Some(mut velocity) = velocity {
+                    velocity.linear = linear;
+                }
+            }
+        }
+    }
+}
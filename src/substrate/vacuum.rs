@@ -0,0 +1,171 @@
+//! Fluctuations du vide — perturbations stochastiques reproductibles du substrat.
+//!
+//! [`VacuumFluctuations`] regroupe trois générateurs, chacun dérivé de l’[`EngineSeed`] :
+//! - perturbation des grilles de [`Field`] (forçage additif par cellule),
+//! - perturbation des forces, exposée par [`VacuumFluctuations::force`] et appliquée par
+//!   `dynamics` aux corps portant une `Force` (contribution lisse `ForceSource::Vacuum`),
+//! - injection d’entropie dans le [`FeedbackLoop`] de `function`.
+//!
+//! Le bruit évolue avec une horloge propre avancée du pas simulé : à graine et pas
+//! identiques, deux exécutions produisent exactement les mêmes perturbations.
+//!
+//! Les fluctuations sont désactivées par défaut ([`VacuumConfig::enabled`]). Le spectre et
+//! les amplitudes sont relus à chaque tick : une modification de [`VacuumConfig`] prend
+//! effet immédiatement, sans réinitialiser l’horloge du bruit.
+
+use bevy::prelude::*;
+use tracing::{debug, info};
+
+use super::field::Field;
+use super::fluctuation::{FluctuationGenerator, FluctuationSpectrum};
+use crate::core::EngineSeed;
+use crate::function::FeedbackLoop;
+
+/// Configuration des fluctuations du vide.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct VacuumConfig {
+    /// Active les fluctuations (désactivées par défaut).
+    pub enabled: bool,
+    pub spectrum: FluctuationSpectrum,
+    /// Taux d’injection dans les champs (unités de champ par seconde simulée).
+    pub field_amplitude: f32,
+    /// Amplitude du décalage appliqué aux forces.
+    pub force_amplitude: f32,
+    /// Taux d’injection d’entropie dans la boucle de rétroaction.
+    pub entropy_amplitude: f32,
+}
+
+impl Default for VacuumConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            spectrum: FluctuationSpectrum::default(),
+            field_amplitude: 0.01,
+            force_amplitude: 0.05,
+            entropy_amplitude: 0.001,
+        }
+    }
+}
+
+/// État des fluctuations du vide (générateurs seedés et horloge propre).
+#[derive(Resource)]
+pub struct VacuumFluctuations {
+    pub field: FluctuationGenerator,
+    pub force: FluctuationGenerator,
+    pub entropy: FluctuationGenerator,
+    /// Temps simulé écoulé depuis l’ensemencement.
+    pub time: f64,
+}
+
+impl VacuumFluctuations {
+    /// Construit les générateurs à partir de la graine moteur.
+    pub fn new(seed: &EngineSeed, config: &VacuumConfig) -> Self {
+        Self {
+            field: FluctuationGenerator::new(
+                seed.derive("substrate::vacuum::field"),
+                config.spectrum,
+                config.field_amplitude,
+            ),
+            force: FluctuationGenerator::new(
+                seed.derive("substrate::vacuum::force"),
+                config.spectrum,
+                config.force_amplitude,
+            ),
+            entropy: FluctuationGenerator::new(
+                seed.derive("substrate::vacuum::entropy"),
+                config.spectrum,
+                config.entropy_amplitude,
+            ),
+            time: 0.0,
+        }
+    }
+
+    /// Perturbation de force en `position` à l’instant courant des fluctuations.
+    pub fn force(&self, position: Vec3) -> Vec3 {
+        self.force.sample_vec3(position, self.time)
+    }
+}
+
+/// Système (Startup) : ensemence les fluctuations à partir de la graine moteur.
+pub fn seed_vacuum(mut commands: Commands, seed: Res<EngineSeed>, config: Res<VacuumConfig>) {
+    info!(target: "substrate", seed = seed.0, "seeding vacuum fluctuations");
+    commands.insert_resource(VacuumFluctuations::new(&seed, &config));
+}
+
+/// Système : applique la configuration courante et avance l’horloge des fluctuations.
+///
+/// Les générateurs sont reconstruits (mêmes graines, même horloge) lorsque la
+/// configuration ou la graine change.
+pub fn advance_vacuum(
+    vacuum: Option<ResMut<VacuumFluctuations>>,
+    config: Res<VacuumConfig>,
+    seed: Res<EngineSeed>,
+    time: Res<Time>,
+) {
+    let Some(mut vacuum) = vacuum else {
+        return;
+    };
+    if config.is_changed() || seed.is_changed() {
+        let elapsed = vacuum.time;
+        *vacuum = VacuumFluctuations::new(&seed, &config);
+        vacuum.time = elapsed;
+        debug!(target: "substrate", spectrum = ?config.spectrum, "vacuum configuration applied");
+    }
+    if config.enabled {
+        vacuum.time += time.delta_secs_f64();
+    }
+}
+
+/// Système : injecte le bruit du vide dans chaque cellule des champs.
+pub fn perturb_fields(
+    mut fields: Query<&mut Field>,
+    vacuum: Option<Res<VacuumFluctuations>>,
+    config: Res<VacuumConfig>,
+) {
+    let Some(vacuum) = vacuum else {
+        return;
+    };
+    if !config.enabled || config.field_amplitude == 0.0 {
+        return;
+    }
+
+    for mut field in fields.iter_mut() {
+        let components = field.kind.components();
+        let dt = field.time_step;
+        let (nx, ny, nz) = (field.dims.x, field.dims.y, field.dims.z);
+
+        for z in 0..nz {
+            for y in 0..ny {
+                for x in 0..nx {
+                    let position = field.position(x, y, z);
+                    let offset = field.index(x, y, z) * components;
+                    let noise = vacuum.field.sample_vec3(position, vacuum.time) * dt;
+                    let data = field.data_mut();
+                    for (c, value) in data[offset..offset + components].iter_mut().enumerate() {
+                        *value += noise[c];
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Système : injecte une entropie fluctuante dans la boucle de rétroaction.
+pub fn perturb_entropy(
+    feedback: Option<ResMut<FeedbackLoop>>,
+    vacuum: Option<Res<VacuumFluctuations>>,
+    config: Res<VacuumConfig>,
+    time: Res<Time>,
+) {
+    let (Some(mut feedback), Some(vacuum)) = (feedback, vacuum) else {
+        return;
+    };
+    if !config.enabled || config.entropy_amplitude == 0.0 {
+        return;
+    }
+
+    let injection = vacuum.entropy.sample(Vec3::ZERO, vacuum.time).abs() * time.delta_secs();
+    feedback.global_entropy += injection;
+    debug!(target: "substrate", injection, "vacuum entropy injected");
+}
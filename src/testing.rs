@@ -0,0 +1,292 @@
+//! Module `testing` — harnais headless pour embarquer et vérifier le moteur
+//!
+//! `VoidEngine::init` construit un moteur complet (visualisation, persistance dans
+//! `void_state.json`). Pour les tests et l’embarquement, [`TestEngine`] compose uniquement
+//! les couches choisies sur `MinimalPlugins`, utilise un [`MemoryField`] en mémoire et
+//! avance un nombre fixe de ticks avec un pas de temps contrôlé.
+//!
+//! ```no_run
+//! use void_engine::{testing::TestEngine, Layer};
+//!
+//! let mut engine = TestEngine::builder().with_layer(Layer::Reflection).build();
+//! engine.run_ticks(10);
+//! engine.assert_snapshot("reflection", |snapshot| snapshot.get("coherence").is_some());
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde_json::Value;
+
+use crate::core::{EngineSeed, MemoryField};
+use crate::Layer;
+
+/// Pas de temps par défaut d’un tick de test (60 Hz).
+pub const DEFAULT_TICK: Duration = Duration::from_nanos(16_666_667);
+
+type SetupFn = Box<dyn FnOnce(&mut App)>;
+
+/// Constructeur d’un [`TestEngine`].
+pub struct TestEngineBuilder {
+    layers: Vec<Layer>,
+    tick: Duration,
+    memory_capacity: usize,
+    memory_sample_interval: Option<usize>,
+    seed: Option<u64>,
+    setup: Vec<SetupFn>,
+}
+
+impl Default for TestEngineBuilder {
+    fn default() -> Self {
+        Self {
+            layers: Vec::new(),
+            tick: DEFAULT_TICK,
+            memory_capacity: 512,
+            memory_sample_interval: None,
+            seed: None,
+            setup: Vec::new(),
+        }
+    }
+}
+
+impl TestEngineBuilder {
+    /// Ajoute une couche (ses dépendances sont ajoutées automatiquement).
+    pub fn with_layer(mut self, layer: Layer) -> Self {
+        for dependency in layer.dependencies() {
+            if !self.layers.contains(dependency) {
+                self = self.with_layer(*dependency);
+            }
+        }
+        if !self.layers.contains(&layer) {
+            self.layers.push(layer);
+        }
+        self
+    }
+
+    /// Ajoute plusieurs couches.
+    pub fn with_layers(self, layers: &[Layer]) -> Self {
+        layers
+            .iter()
+            .fold(self, |builder, layer| builder.with_layer(*layer))
+    }
+
+    /// Ajoute toutes les couches du moteur (visualisation exclue).
+    pub fn all_layers(self) -> Self {
+        self.with_layers(&Layer::ALL)
+    }
+
+    /// Durée simulée de chaque tick.
+    pub fn tick_duration(mut self, tick: Duration) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Capacité du `MemoryField` en mémoire.
+    pub fn memory_capacity(mut self, capacity: usize) -> Self {
+        self.memory_capacity = capacity;
+        self
+    }
+
+    /// Appels de [`MemoryField::record_sampled`] par instantané enregistré (1 : tous).
+    pub fn memory_sample_interval(mut self, interval: usize) -> Self {
+        self.memory_sample_interval = Some(interval);
+        self
+    }
+
+    /// Graine moteur (sinon `VOID_SEED` ou la graine par défaut).
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Exécute `setup` sur l’`App` après l’initialisation des couches
+    /// (insertion de ressources, spawn d’entités, systèmes additionnels…).
+    pub fn with_setup(mut self, setup: impl FnOnce(&mut App) + 'static) -> Self {
+        self.setup.push(Box::new(setup));
+        self
+    }
+
+    /// Construit le moteur de test.
+    pub fn build(mut self) -> TestEngine {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.tick));
+        if let Some(seed) = self.seed {
+            app.insert_resource(EngineSeed(seed));
+        }
+
+        self.layers.sort();
+        for layer in &self.layers {
+            layer.init(&mut app, true);
+        }
+
+        // Remplace le champ persistant éventuellement inséré par `core::init`.
+        let mut memory = MemoryField::in_memory(self.memory_capacity);
+        if let Some(interval) = self.memory_sample_interval {
+            memory = memory.with_sample_interval(interval);
+        }
+        app.insert_resource(memory);
+
+        for setup in self.setup {
+            setup(&mut app);
+        }
+
+        app.finish();
+        app.cleanup();
+
+        TestEngine {
+            app,
+            tick: self.tick,
+            ticks: 0,
+        }
+    }
+}
+
+/// Moteur headless piloté tick par tick, avec des assertions sur son état.
+pub struct TestEngine {
+    app: App,
+    tick: Duration,
+    ticks: u64,
+}
+
+impl TestEngine {
+    /// Retourne un constructeur vide (aucune couche).
+    pub fn builder() -> TestEngineBuilder {
+        TestEngineBuilder::default()
+    }
+
+    /// Accès à l’`App` sous-jacente.
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Accès mutable à l’`App` sous-jacente.
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Accès au monde ECS.
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    /// Accès mutable au monde ECS.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Avance d’un tick.
+    pub fn tick(&mut self) {
+        self.app.update();
+        self.ticks += 1;
+    }
+
+    /// Avance de `count` ticks.
+    pub fn run_ticks(&mut self, count: u64) {
+        for _ in 0..count {
+            self.tick();
+        }
+    }
+
+    /// Nombre de ticks exécutés.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Temps simulé écoulé.
+    pub fn elapsed(&self) -> Duration {
+        self.tick
+            .saturating_mul(u32::try_from(self.ticks).unwrap_or(u32::MAX))
+    }
+
+    /// Crée une entité.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.world_mut().spawn(bundle).id()
+    }
+
+    /// Ressource `R` (panique si absente).
+    pub fn resource<R: Resource>(&self) -> &R {
+        self.world().resource::<R>()
+    }
+
+    /// Ressource `R` mutable (panique si absente).
+    pub fn resource_mut<R: Resource>(&mut self) -> Mut<'_, R> {
+        self.world_mut().resource_mut::<R>()
+    }
+
+    /// Composant `C` de `entity`, s’il existe.
+    pub fn component<C: Component>(&self, entity: Entity) -> Option<&C> {
+        self.world().get::<C>(entity)
+    }
+
+    /// Nombre d’entités portant le composant `C`.
+    pub fn count<C: Component>(&mut self) -> usize {
+        let mut query = self.world_mut().query_filtered::<(), With<C>>();
+        query.iter(self.world()).count()
+    }
+
+    /// Champ mémoire en mémoire du moteur.
+    pub fn memory(&self) -> &MemoryField {
+        self.resource::<MemoryField>()
+    }
+
+    /// Snapshots enregistrés par `module` (clé `"module"`), du plus ancien au plus récent.
+    pub fn snapshots(&self, module: &str) -> Vec<&Value> {
+        self.memory()
+            .snapshots()
+            .iter()
+            .filter(|snapshot| snapshot.get("module").and_then(Value::as_str) == Some(module))
+            .collect()
+    }
+
+    /// Vérifie un prédicat sur la ressource `R`.
+    #[track_caller]
+    pub fn assert_resource<R: Resource>(&self, predicate: impl FnOnce(&R) -> bool) {
+        let Some(resource) = self.world().get_resource::<R>() else {
+            panic!("resource `{}` is missing", std::any::type_name::<R>());
+        };
+        assert!(
+            predicate(resource),
+            "resource `{}` failed assertion after {} ticks",
+            std::any::type_name::<R>(),
+            self.ticks
+        );
+    }
+
+    /// Vérifie un prédicat sur le composant `C` de `entity`.
+    #[track_caller]
+    pub fn assert_component<C: Component>(
+        &self,
+        entity: Entity,
+        predicate: impl FnOnce(&C) -> bool,
+    ) {
+        let Some(component) = self.component::<C>(entity) else {
+            panic!(
+                "component `{}` is missing on {entity}",
+                std::any::type_name::<C>()
+            );
+        };
+        assert!(
+            predicate(component),
+            "component `{}` on {entity} failed assertion after {} ticks",
+            std::any::type_name::<C>(),
+            self.ticks
+        );
+    }
+
+    /// Vérifie un prédicat sur le dernier snapshot enregistré par `module`.
+    #[track_caller]
+    pub fn assert_snapshot(&self, module: &str, predicate: impl FnOnce(&Value) -> bool) {
+        let Some(snapshot) = self.snapshots(module).last().copied() else {
+            panic!(
+                "no snapshot recorded by `{module}` after {} ticks",
+                self.ticks
+            );
+        };
+        assert!(
+            predicate(snapshot),
+            "snapshot from `{module}` failed assertion: {snapshot}"
+        );
+    }
+}
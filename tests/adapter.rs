@@ -0,0 +1,177 @@
+//! Sélection d’adaptateur : raisons de rejet (nom, adaptateur logiciel, fonctionnalités,
+//! limites), classement selon la préférence d’énergie et limites requises lues de
+//! `VOID_GPU_LIMITS`.
+
+use void_engine::substrate::adapter::{parse_limits, rank, reject, select};
+use void_engine::substrate::{AdapterCandidate, AdapterRejection, GpuConfig, LimitShortfall};
+use wgpu::{AdapterInfo, Backend, DeviceType, Features, Limits, PowerPreference};
+
+fn candidate(name: &str, device_type: DeviceType) -> AdapterCandidate {
+    AdapterCandidate {
+        info: AdapterInfo {
+            name: name.into(),
+            vendor: 0,
+            device: 0,
+            device_type,
+            driver: String::new(),
+            driver_info: String::new(),
+            backend: Backend::Vulkan,
+        },
+        features: Features::empty(),
+        limits: Limits::default(),
+        rejection: None,
+    }
+}
+
+#[test]
+fn name_filter_is_case_insensitive() {
+    let config = GpuConfig {
+        adapter_name: Some("GeForce".into()),
+        ..Default::default()
+    };
+    let geforce = candidate("NVIDIA geforce RTX 4070", DeviceType::DiscreteGpu);
+    assert!(reject(&geforce, &config).is_none());
+
+    let radeon = candidate("AMD Radeon RX 7800", DeviceType::DiscreteGpu);
+    match reject(&radeon, &config) {
+        Some(AdapterRejection::NameMismatch { filter }) => assert_eq!(filter, "GeForce"),
+        other => panic!("unexpected rejection: {other:?}"),
+    }
+}
+
+#[test]
+fn software_adapter_requires_fallback() {
+    let llvmpipe = candidate("llvmpipe", DeviceType::Cpu);
+    assert!(reject(&llvmpipe, &GpuConfig::default()).is_none());
+
+    let config = GpuConfig {
+        allow_fallback: false,
+        ..Default::default()
+    };
+    assert!(matches!(
+        reject(&llvmpipe, &config),
+        Some(AdapterRejection::FallbackDisallowed)
+    ));
+    let gpu = candidate("gpu", DeviceType::IntegratedGpu);
+    assert!(reject(&gpu, &config).is_none());
+}
+
+#[test]
+fn missing_features_are_listed() {
+    let mut gpu = candidate("gpu", DeviceType::DiscreteGpu);
+    gpu.features = Features::TIMESTAMP_QUERY;
+    let config = GpuConfig {
+        required_features: Features::TIMESTAMP_QUERY | Features::SHADER_F64,
+        ..Default::default()
+    };
+    match reject(&gpu, &config) {
+        Some(AdapterRejection::MissingFeatures(missing)) => {
+            assert_eq!(missing, Features::SHADER_F64)
+        }
+        other => panic!("unexpected rejection: {other:?}"),
+    }
+
+    gpu.features |= Features::SHADER_F64;
+    assert!(reject(&gpu, &config).is_none());
+}
+
+#[test]
+fn limit_shortfalls_are_reported() {
+    let mut gpu = candidate("gpu", DeviceType::DiscreteGpu);
+    gpu.limits = Limits::downlevel_defaults();
+    let config = GpuConfig {
+        required_limits: Limits {
+            max_storage_buffer_binding_size: 1 << 30,
+            max_compute_workgroup_size_x: 1024,
+            ..Limits::downlevel_defaults()
+        },
+        ..Default::default()
+    };
+    match reject(&gpu, &config) {
+        Some(AdapterRejection::InsufficientLimits(shortfalls)) => {
+            assert_eq!(shortfalls.len(), 2, "{shortfalls:?}");
+            assert!(shortfalls.contains(&LimitShortfall {
+                name: "max_storage_buffer_binding_size",
+                required: 1 << 30,
+                available: u64::from(gpu.limits.max_storage_buffer_binding_size),
+            }));
+            assert!(shortfalls
+                .iter()
+                .any(|shortfall| shortfall.name == "max_compute_workgroup_size_x"));
+        }
+        other => panic!("unexpected rejection: {other:?}"),
+    }
+
+    // Des limites supérieures aux exigences sont acceptées.
+    gpu.limits = Limits {
+        max_storage_buffer_binding_size: u32::MAX,
+        max_compute_workgroup_size_x: 1024,
+        ..Limits::default()
+    };
+    assert!(reject(&gpu, &config).is_none());
+}
+
+#[test]
+fn ranking_follows_power_preference() {
+    use DeviceType::*;
+    let high = PowerPreference::HighPerformance;
+    let low = PowerPreference::LowPower;
+    assert!(rank(DiscreteGpu, high) < rank(IntegratedGpu, high));
+    assert!(rank(IntegratedGpu, low) < rank(DiscreteGpu, low));
+    assert_eq!(
+        rank(DiscreteGpu, PowerPreference::None),
+        rank(IntegratedGpu, PowerPreference::None)
+    );
+    for preference in [high, low, PowerPreference::None] {
+        assert!(rank(IntegratedGpu, preference) < rank(VirtualGpu, preference));
+        assert!(rank(VirtualGpu, preference) < rank(Other, preference));
+        assert!(rank(Other, preference) < rank(Cpu, preference));
+    }
+
+    let mut candidates = vec![
+        candidate("llvmpipe", Cpu),
+        candidate("integrated", IntegratedGpu),
+        candidate("discrete", DiscreteGpu),
+        candidate("integrated 2", IntegratedGpu),
+    ];
+    assert_eq!(select(&candidates, high), Some(2));
+    assert_eq!(select(&candidates, low), Some(1));
+    // À rang égal, le premier énuméré l’emporte.
+    assert_eq!(select(&candidates, PowerPreference::None), Some(1));
+
+    // Les candidats rejetés ne sont jamais retenus ; le logiciel reste en dernier recours.
+    for candidate in &mut candidates[1..] {
+        candidate.rejection = Some(AdapterRejection::FallbackDisallowed);
+    }
+    assert_eq!(select(&candidates, high), Some(0));
+    candidates[0].rejection = Some(AdapterRejection::FallbackDisallowed);
+    assert_eq!(select(&candidates, high), None);
+}
+
+#[test]
+fn limits_spec_applies_preset_and_overrides() {
+    assert_eq!(parse_limits(""), Limits::default());
+    assert_eq!(parse_limits("downlevel"), Limits::downlevel_defaults());
+    assert_eq!(
+        parse_limits(" WebGL2 "),
+        Limits::downlevel_webgl2_defaults()
+    );
+
+    let limits = parse_limits(
+        "downlevel, max_storage_buffer_binding_size=268435456,max_buffer_size=1099511627776",
+    );
+    assert_eq!(
+        limits,
+        Limits {
+            max_storage_buffer_binding_size: 268_435_456,
+            max_buffer_size: 1 << 40,
+            ..Limits::downlevel_defaults()
+        }
+    );
+
+    // Entrées inconnues ou invalides ignorées.
+    assert_eq!(
+        parse_limits("max_texture_dimension_2d=abc,unknown_limit=4,ultra"),
+        Limits::default()
+    );
+}
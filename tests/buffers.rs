@@ -0,0 +1,84 @@
+//! Jeux de données GPU : relecture conservée lors d’un redimensionnement et
+//! correspondance indice ↔ entité. Ignoré sans adaptateur GPU.
+
+use bevy::prelude::*;
+use void_engine::substrate::{GpuBuffers, GpuConfig, GpuContext};
+
+fn gpu() -> Option<GpuContext> {
+    match bevy::tasks::block_on(GpuContext::initialize(&GpuConfig::from_env())) {
+        Ok(gpu) => Some(gpu),
+        Err(err) => {
+            eprintln!("skipping GPU buffer tests: {err}");
+            None
+        }
+    }
+}
+
+fn wait(gpu: &GpuContext) {
+    gpu.device
+        .poll(wgpu::PollType::Wait)
+        .expect("GPU poll failed");
+}
+
+#[test]
+fn readback_survives_reallocation() {
+    let Some(gpu) = gpu() else {
+        return;
+    };
+    let mut buffers = GpuBuffers::default();
+    buffers
+        .upload(&gpu, "values", &[1.0f32, 2.0, 3.0, 4.0])
+        .unwrap();
+    buffers.request_readback(&gpu, "values").unwrap();
+
+    // Agrandi pendant que la relecture est en vol.
+    let large = vec![7.0f32; 4096];
+    let capacity = buffers.upload(&gpu, "values", &large).unwrap().allocated();
+    assert!(capacity >= 4096 * 4);
+    assert!(buffers.get("values").unwrap().readback_pending());
+
+    wait(&gpu);
+    let readbacks = buffers.poll_readbacks(&gpu);
+    let (_, readback) = readbacks
+        .iter()
+        .find(|(name, _)| name == "values")
+        .expect("pending readback was dropped by the reallocation");
+    let values: Vec<f32> = bytemuck::pod_collect_to_vec(&readback.bytes);
+    assert_eq!(values, [1.0, 2.0, 3.0, 4.0]);
+
+    // La relecture suivante porte sur le buffer agrandi.
+    buffers.request_readback(&gpu, "values").unwrap();
+    wait(&gpu);
+    let readbacks = buffers.poll_readbacks(&gpu);
+    let values: Vec<f32> = bytemuck::pod_collect_to_vec(&readbacks[0].1.bytes);
+    assert_eq!(values, large);
+}
+
+#[test]
+fn entity_datasets_keep_their_index_map() {
+    let Some(gpu) = gpu() else {
+        return;
+    };
+    let mut world = World::new();
+    let entities: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    let mut buffers = GpuBuffers::default();
+    let dataset = buffers
+        .upload_entities(&gpu, "phases", &entities, &[0.1f32, 0.2, 0.3])
+        .unwrap();
+    assert_eq!(dataset.entities(), entities);
+    assert_eq!(dataset.index_of(entities[2]), Some(2));
+
+    buffers.request_readback(&gpu, "phases").unwrap();
+    // Un téléversement ultérieur ne change pas les entités de la relecture en vol.
+    buffers
+        .upload_entities(&gpu, "phases", &entities[..1], &[0.5f32])
+        .unwrap();
+    wait(&gpu);
+    let readbacks = buffers.poll_readbacks(&gpu);
+    let (_, readback) = &readbacks[0];
+    assert_eq!(readback.entities, entities);
+
+    // Sans entités, le jeu de données n’est plus indexé.
+    buffers.upload(&gpu, "phases", &[1.0f32]).unwrap();
+    assert!(buffers.get("phases").unwrap().entities().is_empty());
+}
@@ -0,0 +1,99 @@
+//! Capture hors écran : séquence de PNG écrite à l’intervalle configuré, et concordance
+//! des rendus GPU et CPU de la scène synthétique.
+
+use bevy::prelude::*;
+use void_engine::interface::capture::{
+    CaptureBackend, CaptureConfig, CaptureLayer, CaptureScene, CaptureState, GpuCaptureRenderer,
+    PixelRect,
+};
+use void_engine::substrate::{GpuConfig, GpuContext};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn scene() -> CaptureScene {
+    let gradient: Vec<u8> = (0..16u8)
+        .flat_map(|i| [i * 16, 255 - i * 16, i * 7, 255])
+        .collect();
+    CaptureScene {
+        width: 150,
+        height: 120,
+        background: [43, 44, 47, 255],
+        layers: vec![
+            CaptureLayer::solid(
+                PixelRect {
+                    x: 10,
+                    y: 10,
+                    width: 40,
+                    height: 30,
+                },
+                [200, 30, 60, 255],
+            ),
+            CaptureLayer {
+                rect: PixelRect {
+                    x: 60,
+                    y: 40,
+                    width: 64,
+                    height: 64,
+                },
+                width: 4,
+                height: 4,
+                pixels: gradient,
+            },
+        ],
+    }
+}
+
+#[test]
+fn captures_are_written_as_png_sequence() {
+    let directory = std::env::temp_dir().join(format!("void_capture_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    let config = CaptureConfig {
+        enabled: true,
+        directory: directory.clone(),
+        prefix: "test".into(),
+        interval: 2,
+        width: 96,
+        height: 64,
+        backend: CaptureBackend::Cpu,
+        max_frames: Some(3),
+        ..default()
+    };
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Interface])
+        .with_setup(move |app| {
+            app.insert_resource(config);
+        })
+        .build();
+
+    engine.run_ticks(10);
+    assert_eq!(engine.resource::<CaptureState>().frames, 3);
+    for frame in 0..3 {
+        let path = directory.join(format!("test_{frame:06}.png"));
+        let image = image::open(&path)
+            .unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+            .into_rgba8();
+        assert_eq!(image.dimensions(), (96, 64));
+        // Coin hors des tuiles : couleur de fond.
+        assert_eq!(image.get_pixel(0, 0).0, [43, 44, 47, 255]);
+    }
+    assert!(!directory.join("test_000003.png").exists());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn gpu_capture_matches_cpu_rasterizer() {
+    let Ok(gpu) = bevy::tasks::block_on(GpuContext::initialize(&GpuConfig::from_env())) else {
+        eprintln!("skipping GPU capture: no GPU adapter");
+        return;
+    };
+    let scene = scene();
+    let renderer = GpuCaptureRenderer::new(&gpu);
+
+    let pending = renderer.submit(&gpu, &scene).expect("capture submitted");
+    gpu.device.poll(wgpu::PollType::Wait).expect("GPU poll");
+    let pixels = pending
+        .try_finish()
+        .expect("readback completed")
+        .expect("readback succeeded");
+    assert_eq!(pixels, scene.rasterize_cpu());
+}
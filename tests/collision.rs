@@ -0,0 +1,164 @@
+//! Collisions : contacts calculés par la narrowphase selon les formes, réponse par
+//! impulsions (restitution, frottement) et taille de cellule de la broadphase.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{Collider, CollisionEvent, CollisionStats, Mass, Velocity};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn engine() -> TestEngine {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(11)
+        .build();
+    // Le premier tick ne fait pas avancer l’horloge du pas fixe.
+    engine.tick();
+    engine
+}
+
+fn body(position: Vec3, velocity: Vec3, collider: Collider) -> impl Bundle {
+    (
+        Transform::from_translation(position),
+        Velocity {
+            linear: velocity,
+            ..default()
+        },
+        Mass { value: 1.0 },
+        collider,
+    )
+}
+
+/// Avance d’un tick et retourne les contacts publiés pendant ce tick.
+fn step(engine: &mut TestEngine) -> Vec<CollisionEvent> {
+    engine.tick();
+    engine
+        .resource::<Messages<CollisionEvent>>()
+        .iter_current_update_messages()
+        .copied()
+        .collect()
+}
+
+fn assert_near(actual: Vec3, expected: Vec3, tolerance: f32) {
+    assert!(
+        (actual - expected).length() < tolerance,
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn narrowphase_computes_contact_per_shape_pair() {
+    let mut engine = engine();
+    // Sphère contre sphère, pénétration de 0.2 selon X.
+    let a = engine.spawn(body(Vec3::ZERO, Vec3::ZERO, Collider::sphere(0.5)));
+    let b = engine.spawn(body(
+        Vec3::new(0.8, 0.0, 0.0),
+        Vec3::ZERO,
+        Collider::sphere(0.5),
+    ));
+    // Capsule verticale posée dans une boîte statique (sans `Velocity`), pénétration 0.1.
+    let ground = engine.spawn((
+        Transform::from_translation(Vec3::new(10.0, -1.0, 0.0)),
+        Collider::aabb(Vec3::new(5.0, 1.0, 5.0)),
+    ));
+    let capsule = engine.spawn(body(
+        Vec3::new(10.0, 0.9, 0.0),
+        Vec3::ZERO,
+        Collider::capsule(0.25, 0.75),
+    ));
+
+    let events = step(&mut engine);
+    assert_eq!(events.len(), 2, "{events:?}");
+
+    let spheres = events.iter().find(|e| e.a == a || e.b == a).unwrap();
+    let sign = if spheres.a == a { 1.0 } else { -1.0 };
+    assert_near(spheres.normal * sign, Vec3::X, 1e-5);
+    assert!((spheres.depth - 0.2).abs() < 1e-4, "{}", spheres.depth);
+    assert_near(spheres.point, Vec3::new(0.4, 0.0, 0.0), 0.15);
+
+    let resting = events
+        .iter()
+        .find(|e| e.a == ground || e.b == ground)
+        .unwrap();
+    let sign = if resting.a == ground { 1.0 } else { -1.0 };
+    assert!(resting.a == capsule || resting.b == capsule);
+    assert_near(resting.normal * sign, Vec3::Y, 1e-5);
+    assert!((resting.depth - 0.1).abs() < 1e-4, "{}", resting.depth);
+
+    // La correction de position sépare les corps sans déplacer la boîte statique.
+    let separation = engine.component::<Transform>(b).unwrap().translation.x
+        - engine.component::<Transform>(a).unwrap().translation.x;
+    assert!(separation > 0.8);
+    assert_eq!(
+        engine.component::<Transform>(ground).unwrap().translation,
+        Vec3::new(10.0, -1.0, 0.0)
+    );
+}
+
+#[test]
+fn elastic_head_on_impact_exchanges_velocities() {
+    let mut engine = engine();
+    let elastic = Collider::sphere(0.5)
+        .with_restitution(1.0)
+        .with_friction(0.0);
+    let a = engine.spawn(body(Vec3::new(-0.6, 0.0, 0.0), Vec3::X, elastic));
+    let b = engine.spawn(body(Vec3::new(0.6, 0.0, 0.0), -Vec3::X, elastic));
+
+    let mut impulse = 0.0;
+    for _ in 0..20 {
+        impulse += step(&mut engine).iter().map(|e| e.impulse).sum::<f32>();
+    }
+    let va = engine.component::<Velocity>(a).unwrap().linear;
+    let vb = engine.component::<Velocity>(b).unwrap().linear;
+    assert_near(va, -Vec3::X, 1e-4);
+    assert_near(vb, Vec3::X, 1e-4);
+    // Impulsion totale : Δp = 2 m v pour chaque corps.
+    assert!((impulse - 2.0).abs() < 1e-3, "J = {impulse}");
+}
+
+#[test]
+fn friction_impulse_is_bounded_by_coulomb_cone() {
+    let mut engine = engine();
+    engine.spawn((
+        Transform::from_translation(Vec3::new(0.0, -1.0, 0.0)),
+        Collider::aabb(Vec3::new(50.0, 1.0, 50.0))
+            .with_restitution(0.0)
+            .with_friction(1.0),
+    ));
+    // Choc mou : la vitesse normale de 0.5 m/s est annulée, le glissement de 1 m/s freiné.
+    let block = engine.spawn(body(
+        Vec3::new(0.0, 0.45, 0.0),
+        Vec3::new(1.0, -0.5, 0.0),
+        Collider::sphere(0.5)
+            .with_restitution(0.0)
+            .with_friction(1.0),
+    ));
+
+    let events = step(&mut engine);
+    let normal = events[0].impulse;
+    let velocity = engine.component::<Velocity>(block).unwrap().linear;
+    // Le choc annule la vitesse normale ; le frottement retire au plus μ J_n.
+    assert!(velocity.y.abs() < 1e-4, "{velocity}");
+    assert!((normal - 0.5).abs() < 1e-3, "J_n = {normal}");
+    assert!((velocity.x - 0.5).abs() < 1e-3, "{velocity}");
+}
+
+#[test]
+fn broadphase_cells_follow_typical_collider_size() {
+    let mut engine = engine();
+    // Un sol très étendu parmi cent petites sphères.
+    engine.spawn((
+        Transform::from_translation(Vec3::new(0.0, -10.0, 0.0)),
+        Collider::aabb(Vec3::new(500.0, 1.0, 500.0)),
+    ));
+    for i in 0..100 {
+        let position = Vec3::new((i % 10) as f32 * 3.0, 0.0, (i / 10) as f32 * 3.0);
+        engine.spawn(body(position, Vec3::ZERO, Collider::sphere(0.5)));
+    }
+
+    step(&mut engine);
+    let stats = engine.resource::<CollisionStats>();
+    assert_eq!(stats.colliders, 101);
+    assert!((stats.cell_size - 1.0).abs() < 1e-5, "{}", stats.cell_size);
+    assert_eq!(stats.large_colliders, 1);
+    assert_eq!(stats.candidate_pairs, 0);
+}
@@ -0,0 +1,66 @@
+//! Choix du backend de calcul : sans adaptateur utilisable, le moteur reste sur le CPU et
+//! les champs du substrat avancent comme un pas CPU direct.
+
+use bevy::prelude::*;
+use void_engine::substrate::{
+    AdapterReport, ComputeBackend, ComputeBackendKind, CpuCompute, Field, FieldRule, GpuConfig,
+    GpuHealth, GpuStatus,
+};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn bump() -> Field {
+    let mut field = Field::scalar_2d(
+        "bump",
+        FieldRule::ReactionDiffusion {
+            diffusion: 0.2,
+            reaction: 0.5,
+        },
+        24,
+        24,
+    )
+    .with_time_step(0.01);
+    let center = 11.5;
+    for y in 0..24 {
+        for x in 0..24 {
+            let r2 = (x as f32 - center).powi(2) + (y as f32 - center).powi(2);
+            let index = field.index(x, y, 0);
+            field.set_scalar(index, (-r2 / 8.0).exp());
+        }
+    }
+    field
+}
+
+#[test]
+fn no_usable_adapter_selects_cpu_and_fields_advance() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Substrate])
+        .with_setup(|app| {
+            // Aucun backend autorisé : équivalent d’un `VOID_GPU_BACKENDS` indisponible.
+            app.insert_resource(GpuConfig {
+                backends: wgpu::Backends::empty(),
+                ..default()
+            });
+        })
+        .build();
+    let field = engine.spawn(bump());
+
+    let ticks = 10;
+    engine.run_ticks(ticks);
+
+    let backend = engine.resource::<ComputeBackend>();
+    assert_eq!(backend.kind(), ComputeBackendKind::Cpu, "{backend:?}");
+    assert!(engine.resource::<AdapterReport>().candidates.is_empty());
+    assert_eq!(engine.resource::<GpuHealth>().status, GpuStatus::Abandoned);
+
+    // Un pas par tick, identique au pas CPU direct.
+    let mut expected = bump();
+    let cpu = CpuCompute::new();
+    for _ in 0..ticks {
+        expected.step_cpu(&cpu);
+    }
+    let advanced = engine.component::<Field>(field).unwrap();
+    assert_ne!(advanced.data(), bump().data());
+    assert!(advanced.max_deviation(&expected) < 1e-6);
+    assert_eq!(advanced.stats, expected.compute_stats());
+}
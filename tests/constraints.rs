@@ -0,0 +1,125 @@
+//! Contraintes XPBD : résidu d’une contrainte de distance sous la pesanteur, rotation des
+//! petits corps ancrés hors de leur centre et alignement des axes d’une charnière.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{Gravity, Inertia, Mass, Velocity};
+use void_engine::structure::{Constraint, ConstraintKind, ConstraintStats};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn engine() -> TestEngine {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics, Layer::Structure])
+        .seed(23)
+        .build();
+    engine.resource_mut::<Gravity>().0 = Vec3::new(0.0, -9.81, 0.0);
+    // Le premier tick ne fait pas avancer l’horloge du pas fixe.
+    engine.tick();
+    engine
+}
+
+fn body(position: Vec3, velocity: Vec3) -> impl Bundle {
+    (
+        Transform::from_translation(position),
+        Velocity {
+            linear: velocity,
+            ..default()
+        },
+        Mass { value: 1.0 },
+    )
+}
+
+#[test]
+fn distance_constraint_keeps_its_length() {
+    let mut engine = engine();
+    // Pendule ancré au monde, et paire de corps qui s’écartent.
+    let bob = engine.spawn(body(Vec3::new(2.0, 0.0, 0.0), Vec3::ZERO));
+    let pendulum = engine.spawn(Constraint::new(
+        bob,
+        None,
+        ConstraintKind::Distance { length: 2.0 },
+    ));
+    let a = engine.spawn(body(Vec3::new(10.0, 0.0, 0.0), Vec3::new(-3.0, 1.0, 0.0)));
+    let b = engine.spawn(body(Vec3::new(11.5, 0.0, 0.0), Vec3::new(3.0, 0.0, 2.0)));
+    let pair = engine.spawn(Constraint::distance(a, b, 1.5));
+
+    for _ in 0..120 {
+        engine.tick();
+        let stats = engine.resource::<ConstraintStats>();
+        assert_eq!(stats.constraints, 2);
+        assert!(stats.max_residual < 1e-3, "{}", stats.max_residual);
+    }
+
+    let length = engine
+        .component::<Transform>(bob)
+        .unwrap()
+        .translation
+        .length();
+    assert!((length - 2.0).abs() < 1e-3, "|x| = {length}");
+    assert!(engine.component::<Constraint>(pendulum).unwrap().residual < 1e-3);
+    // Le pendule est descendu : la contrainte porte le poids.
+    assert!(engine.component::<Transform>(bob).unwrap().translation.y < -0.5);
+    assert!(engine.component::<Constraint>(pendulum).unwrap().force > 0.0);
+
+    let separation = engine.component::<Transform>(a).unwrap().translation
+        - engine.component::<Transform>(b).unwrap().translation;
+    assert!((separation.length() - 1.5).abs() < 1e-3, "{separation}");
+    assert!(engine.component::<Constraint>(pair).unwrap().residual < 1e-3);
+}
+
+#[test]
+fn small_body_rotates_about_off_center_anchor() {
+    let mut engine = engine();
+    // Sphère de 10 cm et 1 kg : `det I ≈ 6.4e-8`, sous un seuil absolu de déterminant.
+    let ball = engine.spawn((
+        body(Vec3::new(1.1, 0.0, 0.0), Vec3::ZERO),
+        Inertia::solid_sphere(1.0, 0.1),
+    ));
+    let mut rope = Constraint::new(ball, None, ConstraintKind::Distance { length: 1.0 });
+    rope.anchor_a = Vec3::new(-0.1, 0.0, 0.0);
+    let rope = engine.spawn(rope);
+
+    engine.run_ticks(60);
+    let rotation = engine.component::<Transform>(ball).unwrap().rotation;
+    assert!(rotation.angle_between(Quat::IDENTITY) > 0.5, "{rotation:?}");
+    assert!(engine.component::<Constraint>(rope).unwrap().residual < 1e-3);
+}
+
+#[test]
+fn hinge_aligns_axes_and_leaves_rotation_about_them_free() {
+    let mut engine = engine();
+    engine.resource_mut::<Gravity>().0 = Vec3::ZERO;
+    let wheel = engine.spawn((
+        Transform::default(),
+        Velocity {
+            angular: Vec3::new(1.0, 0.5, 3.0),
+            ..default()
+        },
+        Mass { value: 1.0 },
+    ));
+    let hinge = engine.spawn(Constraint::new(
+        wheel,
+        None,
+        ConstraintKind::Hinge {
+            axis_a: Vec3::Z,
+            axis_b: Vec3::Z,
+        },
+    ));
+
+    for _ in 0..60 {
+        engine.tick();
+        let angular = engine
+            .component::<Constraint>(hinge)
+            .unwrap()
+            .angular_residual;
+        assert!(angular < 1e-3, "{angular}");
+    }
+
+    let transform = engine.component::<Transform>(wheel).unwrap();
+    assert!((transform.rotation * Vec3::Z).dot(Vec3::Z) > 1.0 - 1e-6);
+    assert!(transform.translation.length() < 1e-4);
+    // Seule la rotation autour de l’axe subsiste.
+    let angular = engine.component::<Velocity>(wheel).unwrap().angular;
+    assert!(angular.truncate().length() < 1e-2, "{angular}");
+    assert!(angular.z > 2.0, "{angular}");
+}
@@ -0,0 +1,168 @@
+//! Couplage de Kuramoto : paramètre d’ordre de phases connues, synchronisation au-delà du
+//! couplage critique, incohérence sans couplage, et réseaux reproductibles pour une graine.
+
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+use bevy::prelude::*;
+use void_engine::core::EngineSeed;
+use void_engine::dynamics::{
+    coupling::order_parameter, CouplingConfig, CouplingNetwork, CouplingStats, CouplingTopology,
+};
+use void_engine::function::Oscillator;
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+const OSCILLATORS: usize = 40;
+/// Demi-largeur de la distribution uniforme des fréquences propres (Hz).
+const FREQUENCY_SPREAD: f32 = 0.05;
+
+/// Oscillateurs aux phases régulièrement réparties et aux fréquences étalées sur
+/// `1 ± FREQUENCY_SPREAD` Hz, sans corrélation entre phase et fréquence.
+fn engine(strength: f32) -> TestEngine {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics, Layer::Function])
+        .seed(11)
+        .build();
+    *engine.resource_mut::<CouplingConfig>() = CouplingConfig {
+        enabled: true,
+        strength,
+        topology: CouplingTopology::AllToAll,
+        parallel: true,
+    };
+    for i in 0..OSCILLATORS {
+        let rank = (i * 17) % OSCILLATORS;
+        let offset = rank as f32 / (OSCILLATORS - 1) as f32 * 2.0 - 1.0;
+        engine.spawn(Oscillator {
+            frequency: 1.0 + FREQUENCY_SPREAD * offset,
+            amplitude: 1.0,
+            phase: TAU * i as f32 / OSCILLATORS as f32,
+        });
+    }
+    engine
+}
+
+fn entities(count: usize) -> Vec<Entity> {
+    let mut world = World::new();
+    (0..count).map(|_| world.spawn_empty().id()).collect()
+}
+
+fn build(count: usize, topology: CouplingTopology, seed: u64) -> CouplingNetwork {
+    let mut rng = EngineSeed(seed).rng("dynamics::coupling");
+    CouplingNetwork::build(entities(count), topology, &mut rng)
+}
+
+fn edges(network: &CouplingNetwork) -> Vec<(usize, usize)> {
+    network
+        .graph()
+        .raw_edges()
+        .iter()
+        .map(|edge| (edge.source().index(), edge.target().index()))
+        .collect()
+}
+
+#[test]
+fn order_parameter_of_known_phases() {
+    assert_eq!(order_parameter([]), (0.0, 0.0));
+
+    let (r, psi) = order_parameter([1.0; 5]);
+    assert!(
+        (r - 1.0).abs() < 1e-6 && (psi - 1.0).abs() < 1e-6,
+        "{r} {psi}"
+    );
+
+    let (r, _) = order_parameter([0.0, PI]);
+    assert!(r < 1e-6, "{r}");
+    let (r, _) = order_parameter((0..7).map(|k| TAU * k as f32 / 7.0));
+    assert!(r < 1e-6, "{r}");
+
+    let (r, psi) = order_parameter([0.0, FRAC_PI_2]);
+    assert!((r - FRAC_PI_4.cos()).abs() < 1e-6, "{r}");
+    assert!((psi - FRAC_PI_4).abs() < 1e-6, "{psi}");
+
+    // La phase moyenne est ramenée dans [0, 2π).
+    let (r, psi) = order_parameter([-FRAC_PI_2, -FRAC_PI_2 + TAU]);
+    assert!((r - 1.0).abs() < 1e-6, "{r}");
+    assert!((psi - 3.0 * FRAC_PI_2).abs() < 1e-5, "{psi}");
+}
+
+#[test]
+fn strong_all_to_all_coupling_synchronizes() {
+    // Fréquences uniformes de demi-largeur Δω : K_c = 4Δω/π ≈ 0.4 rad/s.
+    let critical = 4.0 * TAU * FREQUENCY_SPREAD / PI;
+    let mut engine = engine(10.0 * critical);
+    engine.run_ticks(2);
+    let initial = engine.resource::<CouplingStats>().order;
+    assert!(initial < 0.1, "initial order {initial}");
+
+    engine.run_ticks(600);
+    let stats = engine.resource::<CouplingStats>();
+    assert_eq!(stats.oscillators, OSCILLATORS);
+    assert_eq!(stats.edges, OSCILLATORS * (OSCILLATORS - 1) / 2);
+    assert!(stats.order > 0.95, "order {}", stats.order);
+}
+
+#[test]
+fn uncoupled_phases_stay_incoherent() {
+    let mut engine = engine(0.0);
+    for _ in 0..600 {
+        engine.tick();
+        let order = engine.resource::<CouplingStats>().order;
+        assert!(order < 0.25, "order {order}");
+    }
+}
+
+#[test]
+fn networks_have_expected_edges() {
+    assert_eq!(build(10, CouplingTopology::AllToAll, 1).edge_count(), 45);
+
+    let ring = build(10, CouplingTopology::Ring { neighbors: 2 }, 1);
+    assert_eq!(ring.edge_count(), 20);
+    assert_eq!(ring.mean_degree(), 4.0);
+    // Voisinage plus large que le demi-anneau : graphe complet, sans arête en double.
+    assert_eq!(
+        build(6, CouplingTopology::Ring { neighbors: 5 }, 1).edge_count(),
+        15
+    );
+
+    let random = |probability| build(100, CouplingTopology::Random { probability }, 1);
+    assert_eq!(random(0.0).edge_count(), 0);
+    assert_eq!(random(1.0).edge_count(), 4950);
+    // E ~ Binomiale(4950, 0.3) : moyenne 1485, écart type ≈ 32.
+    let count = random(0.3).edge_count();
+    assert!((1485 - 160..=1485 + 160).contains(&count), "{count} edges");
+
+    // La redirection conserve le nombre d’arêtes de l’anneau, sans boucle ni doublon.
+    let small_world = |rewiring| {
+        build(
+            50,
+            CouplingTopology::SmallWorld {
+                neighbors: 3,
+                rewiring,
+            },
+            1,
+        )
+    };
+    assert_eq!(
+        edges(&small_world(0.0)),
+        edges(&build(50, CouplingTopology::Ring { neighbors: 3 }, 1))
+    );
+    let rewired = small_world(0.3);
+    assert_eq!(rewired.edge_count(), 150);
+    assert!(edges(&rewired).iter().all(|(i, j)| i != j));
+    assert_ne!(edges(&rewired), edges(&small_world(0.0)));
+}
+
+#[test]
+fn random_networks_are_reproducible_per_seed() {
+    for topology in [
+        CouplingTopology::Random { probability: 0.2 },
+        CouplingTopology::SmallWorld {
+            neighbors: 2,
+            rewiring: 0.4,
+        },
+    ] {
+        let first = edges(&build(60, topology, 7));
+        assert_eq!(first, edges(&build(60, topology, 7)), "{topology:?}");
+        assert_ne!(first, edges(&build(60, topology, 8)), "{topology:?}");
+    }
+}
@@ -0,0 +1,119 @@
+//! Systèmes ouverts : mesure projective d’une matrice densité, demandes de mesure servies
+//! après conversion d’un registre pur, et facteur des taux borné en mode entropique.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use void_engine::dynamics::{
+    Decoherence, DecoherenceConfig, DecoherenceStats, DensityMatrix, DensityRegister, Gate,
+    MeasureRequest, MeasurementOutcome, QuantumRegister, QuantumState,
+};
+use void_engine::function::FeedbackLoop;
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+const TOLERANCE: f64 = 1e-9;
+
+fn bell() -> QuantumState {
+    let mut state = QuantumState::qubits(2);
+    state.apply(&Gate::h(), &[0]).unwrap();
+    state.apply(&Gate::cnot(), &[0, 1]).unwrap();
+    state
+}
+
+fn engine() -> TestEngine {
+    TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(3)
+        .build()
+}
+
+#[test]
+fn density_matrix_measurement_projects_state() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut ones = 0;
+    for _ in 0..400 {
+        let mut rho = DensityMatrix::from_state(&bell());
+        let (levels, probability) = rho.measure(&[0], &mut rng).unwrap();
+        assert!((probability - 0.5).abs() < TOLERANCE);
+        // P ρ P / p : état pur |kk⟩⟨kk|, corrélé sur le second qubit.
+        let k = levels[0];
+        let populations = rho.populations();
+        assert!(
+            (populations[3 * k] - 1.0).abs() < TOLERANCE,
+            "{populations:?}"
+        );
+        assert!((rho.purity() - 1.0).abs() < TOLERANCE);
+        assert_eq!(rho.measure(&[1], &mut rng).unwrap().0, vec![k]);
+        ones += k;
+    }
+    assert!((ones as f64 / 400.0 - 0.5).abs() < 0.08, "{ones}");
+
+    // État maximalement mixte d’un qutrit : issues équiprobables, état réduit pur.
+    let mut rho = DensityMatrix::maximally_mixed(&[3]).unwrap();
+    let (_, probability) = rho.measure(&[0], &mut rng).unwrap();
+    assert!((probability - 1.0 / 3.0).abs() < TOLERANCE);
+    assert!((rho.purity() - 1.0).abs() < TOLERANCE);
+}
+
+#[test]
+fn measure_request_reaches_converted_register() {
+    let mut engine = engine();
+    let register = engine.spawn((
+        QuantumRegister::new(bell()),
+        Decoherence {
+            dephasing: 0.1,
+            damping: 0.0,
+        },
+    ));
+    engine.run_ticks(2);
+    assert!(engine.component::<QuantumRegister>(register).is_none());
+    assert!(engine.component::<DensityRegister>(register).is_some());
+
+    engine.world_mut().write_message(MeasureRequest {
+        entity: register,
+        targets: Vec::new(),
+    });
+    engine.tick();
+    let outcomes: Vec<MeasurementOutcome> = engine
+        .resource::<Messages<MeasurementOutcome>>()
+        .iter_current_update_messages()
+        .cloned()
+        .collect();
+    assert_eq!(outcomes.len(), 1, "{outcomes:?}");
+    let outcome = &outcomes[0];
+    assert_eq!(outcome.targets, vec![0, 1]);
+    assert_eq!(outcome.levels[0], outcome.levels[1]);
+    assert!((outcome.probability - 0.5).abs() < 1e-2);
+
+    // Le déphasage ne touche que les cohérences : l’état réduit reste |kk⟩⟨kk|.
+    let k = outcome.levels[0];
+    let state = &engine.component::<DensityRegister>(register).unwrap().state;
+    assert!((state.populations()[3 * k] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn entropy_driven_rate_scale_is_bounded() {
+    let mut engine = engine();
+    engine.world_mut().insert_resource(FeedbackLoop {
+        global_entropy: 1e9,
+        ..default()
+    });
+    engine.resource_mut::<DecoherenceConfig>().entropy_driven = true;
+    let register = engine.spawn((
+        DensityRegister::new(DensityMatrix::from_state(&bell())),
+        Decoherence {
+            dephasing: 1.0,
+            damping: 0.0,
+        },
+    ));
+
+    engine.run_ticks(2);
+    let max_rate_scale = engine.resource::<DecoherenceConfig>().max_rate_scale;
+    assert_eq!(
+        engine.resource::<DecoherenceStats>().rate_scale,
+        max_rate_scale
+    );
+    let state = &engine.component::<DensityRegister>(register).unwrap().state;
+    assert!((state.trace() - 1.0).abs() < TOLERANCE);
+}
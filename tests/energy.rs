@@ -0,0 +1,93 @@
+//! Bilan énergétique : dissipation cumulée sur plusieurs pas fixes par frame, consommée
+//! par la boucle de rétroaction, et corps retirés exclus de la dissipation.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{BodyEnergy, Drag, EnergyLedger, IntegratorConfig, Mass, Velocity};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn body(speed: f32) -> impl Bundle {
+    (
+        Transform::default(),
+        Velocity {
+            linear: Vec3::X * speed,
+            ..default()
+        },
+        Mass { value: 1.0 },
+    )
+}
+
+fn drag() -> Drag {
+    Drag {
+        linear: 1.0,
+        quadratic: 0.0,
+    }
+}
+
+#[test]
+fn dissipation_accumulates_across_fixed_steps() {
+    let timestep = IntegratorConfig::default().timestep;
+    // Quatre pas fixes par frame.
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .tick_duration(timestep * 4)
+        .build();
+    let ball = engine.spawn((body(2.0), drag()));
+
+    engine.run_ticks(2);
+    let start = engine.resource::<EnergyLedger>().clone();
+    engine.run_ticks(5);
+
+    let ledger = engine.resource::<EnergyLedger>();
+    assert!(
+        ledger.samples - start.samples >= 16,
+        "{} samples",
+        ledger.samples
+    );
+    let lost = start.total - ledger.total;
+    assert!(lost > 0.5, "lost {lost}");
+    // Sans consommateur, la dissipation de chaque pas s’ajoute : le cumul est la perte.
+    let accumulated = ledger.unconsumed_dissipation() - start.unconsumed_dissipation();
+    assert!(ledger.dissipated < 0.1 * accumulated);
+    assert!(
+        (accumulated - lost).abs() < 1e-4 * lost,
+        "{accumulated} vs {lost}"
+    );
+    assert_eq!(
+        engine.component::<BodyEnergy>(ball).unwrap().total(),
+        ledger.total
+    );
+}
+
+#[test]
+fn feedback_loop_consumes_accumulated_dissipation() {
+    let timestep = IntegratorConfig::default().timestep;
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics, Layer::Function])
+        .tick_duration(timestep * 4)
+        .build();
+    engine.spawn((body(2.0), drag()));
+
+    engine.run_ticks(6);
+    let ledger = engine.resource::<EnergyLedger>();
+    assert!(ledger.dissipated > 0.0);
+    assert_eq!(ledger.unconsumed_dissipation(), 0.0);
+}
+
+#[test]
+fn removed_bodies_are_not_dissipated() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .build();
+    engine.spawn(body(1.0));
+    let fast = engine.spawn(body(10.0));
+    engine.run_ticks(3);
+    assert!(engine.resource::<EnergyLedger>().total > 50.0);
+
+    engine.world_mut().despawn(fast);
+    engine.run_ticks(3);
+    let ledger = engine.resource::<EnergyLedger>();
+    assert_eq!(ledger.bodies, 1);
+    assert!((ledger.total - 0.5).abs() < 1e-5, "{}", ledger.total);
+    assert_eq!(ledger.unconsumed_dissipation(), 0.0);
+}
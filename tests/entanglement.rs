@@ -0,0 +1,94 @@
+//! Intrication : concurrence et information mutuelle d’un état de Bell et d’un état
+//! produit, et états réduits obtenus par trace partielle.
+
+use nalgebra::DMatrix;
+use void_engine::dynamics::entanglement::{concurrence, mutual_information, partial_trace};
+use void_engine::dynamics::quantum::C64;
+use void_engine::dynamics::{DensityMatrix, EntanglementMeasures, Gate, QuantumState};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn bell() -> DensityMatrix {
+    let mut state = QuantumState::qubits(2);
+    state.apply(&Gate::h(), &[0]).unwrap();
+    state.apply(&Gate::cnot(), &[0, 1]).unwrap();
+    DensityMatrix::from_state(&state)
+}
+
+/// `Ry(0.7)|0⟩ ⊗ H|0⟩`.
+fn product() -> DensityMatrix {
+    let mut state = QuantumState::qubits(2);
+    state.apply(&Gate::ry(0.7), &[0]).unwrap();
+    state.apply(&Gate::h(), &[1]).unwrap();
+    DensityMatrix::from_state(&state)
+}
+
+fn assert_matrix(found: &DensityMatrix, expected: &DMatrix<C64>) {
+    assert!(
+        (found.matrix() - expected).norm() < TOLERANCE,
+        "{} vs {expected}",
+        found.matrix()
+    );
+}
+
+#[test]
+fn bell_state_is_maximally_entangled() {
+    let rho = bell();
+    let c = concurrence(&rho).unwrap();
+    assert!((c - 1.0).abs() < 1e-6, "C = {c}");
+    let information = mutual_information(&rho, &[0], &[1]).unwrap();
+    assert!((information - 2.0).abs() < 1e-6, "I = {information}");
+
+    // Chaque qubit, pris seul, est maximalement mixte.
+    let half = DMatrix::<C64>::identity(2, 2) * C64::from(0.5);
+    for k in 0..2 {
+        let reduced = partial_trace(&rho, &[k]).unwrap();
+        assert_matrix(&reduced, &half);
+        assert!((reduced.von_neumann_entropy() - 1.0).abs() < 1e-6);
+    }
+
+    let measures = EntanglementMeasures::measure(&rho).unwrap();
+    let pair = measures.pair(1, 0).unwrap();
+    assert!((pair.mutual_information - 2.0).abs() < 1e-6);
+    assert!((pair.concurrence.unwrap() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn product_state_carries_no_correlation() {
+    let rho = product();
+    let c = concurrence(&rho).unwrap();
+    assert!(c.abs() < 1e-6, "C = {c}");
+    let information = mutual_information(&rho, &[0], &[1]).unwrap();
+    assert!(information.abs() < 1e-6, "I = {information}");
+
+    // Les états réduits sont les facteurs purs du produit.
+    let mut first = QuantumState::qubits(1);
+    first.apply(&Gate::ry(0.7), &[0]).unwrap();
+    let mut second = QuantumState::qubits(1);
+    second.apply(&Gate::h(), &[0]).unwrap();
+    for (k, factor) in [first, second].iter().enumerate() {
+        let reduced = partial_trace(&rho, &[k]).unwrap();
+        assert_matrix(&reduced, DensityMatrix::from_state(factor).matrix());
+        assert!((reduced.purity() - 1.0).abs() < TOLERANCE);
+    }
+}
+
+#[test]
+fn partial_trace_keeps_requested_order() {
+    // |0⟩ ⊗ |1⟩ ⊗ |+⟩ : garder (2, 0) donne |+⟩ ⊗ |0⟩.
+    let mut state = QuantumState::qubits(3);
+    state.apply(&Gate::x(), &[1]).unwrap();
+    state.apply(&Gate::h(), &[2]).unwrap();
+    let rho = DensityMatrix::from_state(&state);
+
+    let reduced = partial_trace(&rho, &[2, 0]).unwrap();
+    assert_eq!(reduced.dims(), [2, 2]);
+    let mut expected = QuantumState::qubits(2);
+    expected.apply(&Gate::h(), &[0]).unwrap();
+    assert_matrix(&reduced, DensityMatrix::from_state(&expected).matrix());
+
+    // Garder tous les sous-systèmes rend l’état ; les cibles invalides sont refusées.
+    assert_matrix(&partial_trace(&rho, &[0, 1, 2]).unwrap(), rho.matrix());
+    assert!(partial_trace(&rho, &[0, 0]).is_err());
+    assert!(partial_trace(&rho, &[3]).is_err());
+}
@@ -0,0 +1,76 @@
+//! Concordance des chemins CPU et GPU des champs : la même grille avancée sur les deux
+//! backends reste à [`GPU_CPU_TOLERANCE`] près. Ignoré sans adaptateur GPU.
+
+use bevy::prelude::*;
+use void_engine::substrate::field::{verify_backends, GPU_CPU_TOLERANCE};
+use void_engine::substrate::{Field, FieldBoundary, FieldRule, GpuConfig, GpuContext};
+
+const STEPS: usize = 16;
+
+fn gpu() -> Option<GpuContext> {
+    match bevy::tasks::block_on(GpuContext::initialize(&GpuConfig::from_env())) {
+        Ok(gpu) => Some(gpu),
+        Err(err) => {
+            eprintln!("skipping GPU/CPU comparison: {err}");
+            None
+        }
+    }
+}
+
+/// Bosse gaussienne centrée, sur une grille 2D de `n × n`.
+fn bump(rule: FieldRule, n: u32) -> Field {
+    let mut field = Field::scalar_2d("bump", rule, n, n).with_time_step(0.01);
+    let center = (n as f32 - 1.0) * 0.5;
+    for y in 0..n {
+        for x in 0..n {
+            let r2 = (x as f32 - center).powi(2) + (y as f32 - center).powi(2);
+            let index = field.index(x, y, 0);
+            field.set_scalar(index, (-r2 / 8.0).exp());
+        }
+    }
+    field
+}
+
+#[test]
+fn reaction_diffusion_matches_cpu() {
+    let Some(gpu) = gpu() else {
+        return;
+    };
+    let rule = FieldRule::ReactionDiffusion {
+        diffusion: 0.2,
+        reaction: 0.5,
+    };
+    for boundary in [FieldBoundary::Neumann, FieldBoundary::Periodic] {
+        let mut field = bump(rule, 32);
+        field.boundary = boundary;
+        let deviation = verify_backends(&field, &gpu, STEPS).expect("GPU step");
+        assert!(
+            deviation <= GPU_CPU_TOLERANCE,
+            "{boundary:?}: deviation {deviation} exceeds {GPU_CPU_TOLERANCE}"
+        );
+    }
+}
+
+#[test]
+fn wave_matches_cpu() {
+    let Some(gpu) = gpu() else {
+        return;
+    };
+    let rule = FieldRule::Wave {
+        speed: 1.0,
+        damping: 0.05,
+    };
+    let mut field = bump(rule, 48);
+    field.boundary = FieldBoundary::Dirichlet;
+    let deviation = verify_backends(&field, &gpu, STEPS).expect("GPU step");
+    assert!(
+        deviation <= GPU_CPU_TOLERANCE,
+        "deviation {deviation} exceeds {GPU_CPU_TOLERANCE}"
+    );
+
+    let mut volume = Field::scalar_3d("volume", rule, UVec3::splat(12)).with_time_step(0.01);
+    let center = volume.index(6, 6, 6);
+    volume.set_scalar(center, 1.0);
+    let deviation = verify_backends(&volume, &gpu, STEPS).expect("GPU step");
+    assert!(deviation <= GPU_CPU_TOLERANCE);
+}
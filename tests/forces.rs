@@ -0,0 +1,148 @@
+//! Accumulation des forces : ressorts ancrés sur une entité sans masse ou entre deux corps,
+//! et détail par source des contributions d’un pas.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{
+    BodyEnergy, Drag, ForceAccumulator, ForceSource, Gravity, Integrator, IntegratorKind, Mass,
+    Spring, SpringAnchor, Velocity,
+};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn engine() -> TestEngine {
+    TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(5)
+        .build()
+}
+
+fn body(position: Vec3) -> impl Bundle {
+    (
+        Transform::from_translation(position),
+        Velocity::default(),
+        Mass { value: 1.0 },
+        Integrator(IntegratorKind::VelocityVerlet),
+    )
+}
+
+#[test]
+fn spring_to_massless_entity_treats_it_as_immovable() {
+    let mut engine = engine();
+    // Ancre sans `Mass` ni `Velocity` : seule sa position compte.
+    let anchor = engine.spawn(Transform::from_translation(Vec3::ZERO));
+    let bob = engine.spawn((
+        body(Vec3::new(2.0, 0.0, 0.0)),
+        Spring {
+            anchor: SpringAnchor::Entity(anchor),
+            stiffness: 4.0,
+            rest_length: 1.0,
+            damping: 0.0,
+        },
+    ));
+
+    // Le premier tick ne fait pas avancer l’horloge : le premier pas fixe suit.
+    engine.run_ticks(2);
+    let spring = engine
+        .component::<ForceAccumulator>(bob)
+        .unwrap()
+        .get(ForceSource::Spring);
+    assert!(
+        (spring - Vec3::new(-4.0, 0.0, 0.0)).length() < 1e-5,
+        "{spring}"
+    );
+
+    engine.run_ticks(120);
+    // ω = √(k / m) = 2 : x(t) = 1 + cos 2t autour de la longueur au repos.
+    let t = engine.resource::<Time<Fixed>>().elapsed_secs();
+    let x = engine.component::<Transform>(bob).unwrap().translation.x;
+    assert!((x - (1.0 + (2.0 * t).cos())).abs() < 2e-2, "x = {x} at {t}");
+    assert_eq!(
+        engine.component::<Transform>(anchor).unwrap().translation,
+        Vec3::ZERO
+    );
+
+    // L’énergie du ressort revient entière au corps.
+    let energy = engine.component::<BodyEnergy>(bob).unwrap().total();
+    assert!((energy - 2.0).abs() < 2e-2, "E = {energy}");
+}
+
+#[test]
+fn spring_between_bodies_acts_equally_on_both_ends() {
+    let mut engine = engine();
+    let a = engine.spawn(body(Vec3::new(-1.5, 0.0, 0.0)));
+    let b = engine.spawn((
+        body(Vec3::new(1.5, 0.5, 0.0)),
+        Spring {
+            anchor: SpringAnchor::Entity(a),
+            stiffness: 2.0,
+            rest_length: 1.0,
+            damping: 0.1,
+        },
+    ));
+
+    engine.run_ticks(2);
+    let on_a = engine
+        .component::<ForceAccumulator>(a)
+        .unwrap()
+        .get(ForceSource::Spring);
+    let on_b = engine
+        .component::<ForceAccumulator>(b)
+        .unwrap()
+        .get(ForceSource::Spring);
+    assert!(on_a.length() > 1.0);
+    assert!((on_a + on_b).length() < 1e-5, "{on_a} vs {on_b}");
+
+    engine.run_ticks(200);
+    let momentum = engine.component::<Velocity>(a).unwrap().linear
+        + engine.component::<Velocity>(b).unwrap().linear;
+    assert!(momentum.length() < 1e-4, "p = {momentum}");
+}
+
+#[test]
+fn accumulator_reports_each_source() {
+    let mut engine = engine();
+    engine.resource_mut::<Gravity>().0 = Vec3::new(0.0, -10.0, 0.0);
+    let ball = engine.spawn((
+        Transform::default(),
+        Velocity {
+            linear: Vec3::X,
+            ..default()
+        },
+        Mass { value: 2.0 },
+        Drag {
+            linear: 0.5,
+            quadratic: 0.0,
+        },
+    ));
+
+    engine.run_ticks(2);
+    let accumulator = engine.component::<ForceAccumulator>(ball).unwrap();
+    assert_eq!(
+        accumulator.get(ForceSource::Gravity),
+        Vec3::new(0.0, -20.0, 0.0)
+    );
+    assert_eq!(
+        accumulator.get(ForceSource::Drag),
+        Vec3::new(-0.5, 0.0, 0.0)
+    );
+    assert_eq!(accumulator.get(ForceSource::Impulse), Vec3::ZERO);
+
+    // Une impulsion est convertie en force moyenne sur un seul pas.
+    engine
+        .world_mut()
+        .get_mut::<ForceAccumulator>(ball)
+        .unwrap()
+        .apply_impulse(Vec3::new(0.0, 0.0, 4.0));
+    let before = engine.component::<Velocity>(ball).unwrap().linear.z;
+    engine.tick();
+    let after = engine.component::<Velocity>(ball).unwrap().linear.z;
+    assert!(
+        (after - before - 2.0).abs() < 1e-4,
+        "Δv = {}",
+        after - before
+    );
+    engine.tick();
+    let accumulator = engine.component::<ForceAccumulator>(ball).unwrap();
+    assert_eq!(accumulator.get(ForceSource::Impulse), Vec3::ZERO);
+    assert_eq!(accumulator.pending_impulse(), Vec3::ZERO);
+}
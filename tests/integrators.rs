@@ -0,0 +1,150 @@
+//! Conservation de l’énergie d’un oscillateur harmonique (k = m = 1) selon le schéma
+//! d’intégration : oscillation bornée pour les schémas symplectiques, dissipation lente
+//! pour RK4.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use void_engine::core::MemoryField;
+use void_engine::dynamics::{
+    self, BodyState, Integrator, IntegratorConfig, IntegratorKind, Mass, Velocity,
+};
+use void_engine::substrate::potential::{GlobalPotentials, PotentialForce, PotentialKind};
+
+fn energy(state: BodyState) -> f32 {
+    0.5 * state.velocity.length_squared() + 0.5 * state.position.length_squared()
+}
+
+/// Intègre l’oscillateur depuis x = 1, v = 0 et retourne l’énergie après chaque pas.
+fn oscillate(kind: IntegratorKind, h: f32, steps: usize) -> Vec<f32> {
+    let mut state = BodyState {
+        position: Vec3::X,
+        velocity: Vec3::ZERO,
+    };
+    (0..steps)
+        .map(|_| {
+            state = kind.step(state, h, |x, _| -x);
+            energy(state)
+        })
+        .collect()
+}
+
+fn max_relative_error(energies: &[f32]) -> f32 {
+    energies
+        .iter()
+        .map(|e| (e - 0.5).abs() / 0.5)
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn semi_implicit_euler_energy_oscillates_without_drift() {
+    let energies = oscillate(IntegratorKind::SemiImplicitEuler, 0.05, 20_000);
+    let quarter = energies.len() / 4;
+    let early = max_relative_error(&energies[..quarter]);
+    let late = max_relative_error(&energies[energies.len() - quarter..]);
+
+    // Erreur d’ordre h, mais bornée : aucune croissance au fil des ~160 périodes.
+    assert!(early < 0.05, "early error {early}");
+    assert!(
+        late < early * 1.1 + 1e-4,
+        "energy drifts: {early} -> {late}"
+    );
+}
+
+#[test]
+fn velocity_verlet_energy_error_is_second_order_and_bounded() {
+    let fine = max_relative_error(&oscillate(IntegratorKind::VelocityVerlet, 0.05, 20_000));
+    assert!(fine < 1e-3, "fine error {fine}");
+
+    // Même avec un pas grossier, l’énergie reste bornée.
+    let coarse = oscillate(IntegratorKind::VelocityVerlet, 0.5, 2_000);
+    let error = max_relative_error(&coarse);
+    assert!(error < 0.1, "coarse error {error}");
+    let last = *coarse.last().unwrap();
+    assert!((last - 0.5).abs() / 0.5 < 0.1, "final energy {last}");
+}
+
+#[test]
+fn rk4_is_accurate_but_slowly_dissipates_energy() {
+    // Une période : trajectoire quasi exacte.
+    let h = 0.05;
+    let steps = (std::f32::consts::TAU / h).round() as usize;
+    let mut state = BodyState {
+        position: Vec3::X,
+        velocity: Vec3::ZERO,
+    };
+    for _ in 0..steps {
+        state = IntegratorKind::Rk4.step(state, h, |x, _| -x);
+    }
+    let t = steps as f32 * h;
+    assert!((state.position.x - t.cos()).abs() < 1e-4, "{state:?}");
+
+    // Pas grossier : l’énergie décroît de façon monotone.
+    let coarse = oscillate(IntegratorKind::Rk4, 0.5, 2_000);
+    assert!(coarse.windows(2).all(|w| w[1] <= w[0] + 1e-6));
+    assert!(
+        coarse.last().unwrap() < &0.45,
+        "final energy {}",
+        coarse.last().unwrap()
+    );
+}
+
+#[test]
+fn engine_integrates_on_fixed_timestep_with_per_entity_override() {
+    // Dynamique seule sur `MinimalPlugins` : aucune initialisation GPU du substrat.
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            16,
+        )))
+        .insert_resource(MemoryField::in_memory(16))
+        .insert_resource(GlobalPotentials(vec![(
+            Vec3::ZERO,
+            PotentialKind::HarmonicTrap { stiffness: 1.0 },
+        )]))
+        .insert_resource(IntegratorConfig {
+            kind: IntegratorKind::Rk4,
+            substeps: 4,
+            timestep: Duration::from_millis(20),
+        });
+    dynamics::init(&mut app);
+
+    let body = || {
+        (
+            Transform::from_translation(Vec3::X),
+            Velocity::default(),
+            Mass { value: 1.0 },
+        )
+    };
+    let global = app.world_mut().spawn(body()).id();
+    let verlet = app
+        .world_mut()
+        .spawn((body(), Integrator(IntegratorKind::VelocityVerlet)))
+        .id();
+
+    for _ in 0..600 {
+        app.update();
+    }
+
+    let world = app.world();
+    let fixed = world.resource::<Time<Fixed>>();
+    assert_eq!(fixed.timestep(), Duration::from_millis(20));
+    let t = fixed.elapsed_secs();
+    assert!(t > 9.0, "fixed time {t}");
+
+    for entity in [global, verlet] {
+        let state = BodyState {
+            position: world.get::<Transform>(entity).unwrap().translation,
+            velocity: world.get::<Velocity>(entity).unwrap().linear,
+        };
+        assert!(
+            (state.position.x - t.cos()).abs() < 1e-2,
+            "{entity}: {state:?} at {t}"
+        );
+        assert!((energy(state) - 0.5).abs() < 1e-3, "{entity}: {state:?}");
+        // Le potentiel n’est évalué qu’une fois par pas, par l’accumulateur de forces.
+        let potential = world.get::<PotentialForce>(entity).unwrap();
+        assert!((potential.energy - 0.5 * state.position.length_squared()).abs() < 0.05);
+    }
+}
@@ -0,0 +1,53 @@
+//! Registre des noyaux : validation selon les fonctionnalités du device.
+
+use naga::valid::Capabilities;
+use void_engine::substrate::kernel::device_capabilities;
+use void_engine::substrate::{GpuConfig, GpuContext, KernelError, KernelRegistry};
+
+const F16_KERNEL: &str = r#"
+enable f16;
+
+@group(0) @binding(0) var<storage, read_write> data: array<f16>;
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    data[id.x] = data[id.x] * 2.0h;
+}
+"#;
+
+#[test]
+fn capabilities_follow_device_features() {
+    let none = device_capabilities(wgpu::Features::empty());
+    assert!(!none.contains(Capabilities::SHADER_FLOAT16));
+    assert!(!none.contains(Capabilities::FLOAT64));
+
+    let features = wgpu::Features::SHADER_F16 | wgpu::Features::SHADER_INT64_ATOMIC_ALL_OPS;
+    let capabilities = device_capabilities(features);
+    assert!(capabilities.contains(Capabilities::SHADER_FLOAT16));
+    assert!(capabilities.contains(Capabilities::SHADER_INT64_ATOMIC_MIN_MAX));
+    assert!(!capabilities.contains(Capabilities::FLOAT64));
+}
+
+#[test]
+fn kernels_are_validated_against_the_device() {
+    // Sans device, seules les règles indépendantes du matériel s’appliquent.
+    let mut registry = KernelRegistry::default();
+    registry
+        .register_source("half", F16_KERNEL, "main")
+        .expect("f16 kernel registers before a device is known");
+
+    let Ok(gpu) = bevy::tasks::block_on(GpuContext::initialize(&GpuConfig::from_env())) else {
+        eprintln!("skipping device validation: no GPU adapter");
+        return;
+    };
+    let result = registry.compiled(&gpu, "half").map(|_| ());
+    if gpu.device.features().contains(wgpu::Features::SHADER_F16) {
+        assert!(result.is_ok(), "{result:?}");
+    } else {
+        assert!(
+            matches!(result, Err(KernelError::Validation { .. })),
+            "{result:?}"
+        );
+    }
+    assert!(registry.compiled(&gpu, "field_step").is_ok());
+}
@@ -0,0 +1,157 @@
+//! Index spatial : requêtes par rayon, k plus proches voisins et boîte comparées à une
+//! recherche exhaustive, en domaine ouvert et périodique, et axe périodique de taille nulle
+//! traité comme illimité.
+
+use bevy::prelude::*;
+use void_engine::structure::SpatialIndex;
+use void_engine::substrate::DomainTopology;
+
+/// Nuage reproductible de `count` points dans `[lo, hi]³`.
+fn cloud(count: usize, lo: f32, hi: f32) -> Vec<Vec3> {
+    let mut state = 0x9e37_79b9_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        lo + (hi - lo) * (state as f32 / u32::MAX as f32)
+    };
+    (0..count)
+        .map(|_| Vec3::new(next(), next(), next()))
+        .collect()
+}
+
+fn index(
+    points: &[Vec3],
+    cell_size: f32,
+    topology: &DomainTopology,
+) -> (Vec<Entity>, SpatialIndex) {
+    let mut world = World::new();
+    let entities: Vec<Entity> = points.iter().map(|_| world.spawn_empty().id()).collect();
+    let index = SpatialIndex::build(
+        entities.iter().copied().zip(points.iter().copied()),
+        cell_size,
+        topology,
+    );
+    (entities, index)
+}
+
+/// Compare les trois requêtes à la recherche exhaustive autour de plusieurs centres.
+fn assert_matches_brute_force(points: &[Vec3], cell_size: f32, topology: &DomainTopology) {
+    let (entities, index) = index(points, cell_size, topology);
+    let wrapped: Vec<Vec3> = points.iter().map(|&p| topology.wrap(p)).collect();
+    for center in cloud(12, -14.0, 14.0) {
+        let distances: Vec<(Entity, f32)> = entities
+            .iter()
+            .zip(&wrapped)
+            .map(|(&e, &p)| (e, topology.distance(topology.wrap(center), p)))
+            .collect();
+
+        for radius in [0.5, 2.5, 7.0] {
+            let mut found: Vec<Entity> = index
+                .within_radius(center, radius)
+                .into_iter()
+                .map(|(e, _)| e)
+                .collect();
+            let mut expected: Vec<Entity> = distances
+                .iter()
+                .filter(|(_, d)| *d <= radius)
+                .map(|(e, _)| *e)
+                .collect();
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected, "radius {radius} around {center}");
+        }
+
+        let mut sorted: Vec<f32> = distances.iter().map(|(_, d)| *d).collect();
+        sorted.sort_by(f32::total_cmp);
+        for k in [1, 5, 40, points.len() + 3] {
+            let nearest: Vec<f32> = index.nearest(center, k).iter().map(|(_, d)| *d).collect();
+            assert_eq!(
+                nearest,
+                sorted[..k.min(points.len())],
+                "k = {k} around {center}"
+            );
+        }
+
+        let half = Vec3::new(1.5, 3.0, 6.0);
+        let mut found = index.in_box(center, half);
+        let mut expected: Vec<Entity> = entities
+            .iter()
+            .zip(&wrapped)
+            .filter(|(_, &p)| {
+                topology
+                    .displacement(topology.wrap(center), p)
+                    .abs()
+                    .cmple(half)
+                    .all()
+            })
+            .map(|(&e, _)| e)
+            .collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected, "box around {center}");
+    }
+}
+
+#[test]
+fn queries_match_brute_force_in_open_domain() {
+    let points = cloud(300, -10.0, 10.0);
+    assert_matches_brute_force(&points, 1.0, &DomainTopology::Unbounded);
+    assert_matches_brute_force(&points, 3.7, &DomainTopology::Unbounded);
+}
+
+#[test]
+fn queries_match_brute_force_in_periodic_domain() {
+    let points = cloud(300, -15.0, 15.0);
+    for max in [Vec3::splat(10.0), Vec3::new(9.0, 4.5, 10.0)] {
+        let torus = DomainTopology::Periodic {
+            min: Vec3::ZERO,
+            max,
+        };
+        assert_matches_brute_force(&points, 1.0, &torus);
+        assert_matches_brute_force(&points, 2.2, &torus);
+    }
+}
+
+#[test]
+fn sparse_bodies_are_found_without_scanning_empty_cells() {
+    // Quelques corps très éloignés : des millions de cellules vides entre eux.
+    let points: Vec<Vec3> = cloud(200, -5.0e3, 5.0e3);
+    assert_matches_brute_force(&points, 0.5, &DomainTopology::Unbounded);
+    let (_, index) = index(&points, 0.5, &DomainTopology::Unbounded);
+    assert_eq!(index.nearest(Vec3::ZERO, 1000).len(), points.len());
+}
+
+/// Tore plan : `x` et `y` périodiques sur `[0, 10]`, `z` de taille nulle.
+fn flat_torus() -> DomainTopology {
+    DomainTopology::Periodic {
+        min: Vec3::ZERO,
+        max: Vec3::new(10.0, 10.0, 0.0),
+    }
+}
+
+#[test]
+fn spatial_index_handles_zero_size_axis() {
+    let mut world = World::new();
+    let entities: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+    let positions = [
+        Vec3::new(0.5, 5.0, 0.0),
+        Vec3::new(9.5, 5.0, 0.0),
+        Vec3::new(0.5, 5.0, 6.0),
+        Vec3::new(5.0, 5.0, 0.0),
+    ];
+    let index = SpatialIndex::build(entities.iter().copied().zip(positions), 1.0, &flat_torus());
+    assert_eq!(index.len(), 4);
+
+    let near = index.within_radius(Vec3::new(0.5, 5.0, 0.0), 1.5);
+    let mut found: Vec<Entity> = near.iter().map(|(entity, _)| *entity).collect();
+    let mut expected = vec![entities[0], entities[1]];
+    found.sort();
+    expected.sort();
+    assert_eq!(found, expected);
+    assert!(near.iter().all(|(_, distance)| distance.is_finite()));
+
+    let nearest = index.nearest(Vec3::new(0.5, 5.0, 5.0), 1);
+    assert_eq!(nearest[0].0, entities[2]);
+    assert!((nearest[0].1 - 1.0).abs() < 1e-5);
+}
@@ -0,0 +1,63 @@
+//! Mémoire : moyennes et tendances calculées sur les seuls instantanés portant la clé, et
+//! échantillonnage des modules qui rendent compte à chaque pas.
+
+use bevy::prelude::*;
+use serde_json::json;
+use void_engine::core::MemoryField;
+use void_engine::dynamics::Emitter;
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+#[test]
+fn average_skips_snapshots_without_key() {
+    let mut memory = MemoryField::in_memory(64);
+    for coherence in [0.2, 0.4, 0.6, 0.8] {
+        memory.record(json!({ "module": "reflection", "coherence": coherence }));
+        // Instantanés d’autres modules intercalés.
+        for _ in 0..5 {
+            memory.record(json!({ "module": "substrate::field", "fields": {} }));
+        }
+    }
+
+    // Les deux dernières valeurs portant la clé, malgré les instantanés intercalés.
+    let average = memory.average("coherence", 2).unwrap();
+    assert!((average - 0.7).abs() < 1e-6, "{average}");
+    let average = memory.average("coherence", 120).unwrap();
+    assert!((average - 0.5).abs() < 1e-6, "{average}");
+    let trend = memory.trend("coherence", 4).unwrap();
+    assert!((trend - 0.6).abs() < 1e-6, "{trend}");
+    assert_eq!(memory.average("entropy", 10), None);
+}
+
+#[test]
+fn sampled_records_keep_one_snapshot_per_interval() {
+    let mut memory = MemoryField::in_memory(64).with_sample_interval(4);
+    for step in 0..10 {
+        memory.record_sampled(json!({ "module": "a", "step": step }));
+        memory.record_sampled(json!({ "module": "b", "step": step }));
+    }
+    let steps = |module: &str| -> Vec<u64> {
+        memory
+            .snapshots()
+            .iter()
+            .filter(|s| s["module"] == module)
+            .map(|s| s["step"].as_u64().unwrap())
+            .collect()
+    };
+    // Chaque module a sa propre cadence, à partir du premier appel.
+    assert_eq!(steps("a"), vec![0, 4, 8]);
+    assert_eq!(steps("b"), vec![0, 4, 8]);
+}
+
+#[test]
+fn per_step_modules_are_sampled() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .memory_sample_interval(10)
+        .build();
+    engine.spawn((Transform::default(), Emitter::new(30.0)));
+
+    engine.run_ticks(41);
+    let snapshots = engine.snapshots("dynamics::particles").len();
+    assert!((4..=5).contains(&snapshots), "{snapshots} snapshots");
+}
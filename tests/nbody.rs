@@ -0,0 +1,62 @@
+//! Barnes-Hut : écart du champ de l’`Octree` à la somme directe selon l’angle d’ouverture,
+//! et accumulateur de forces fourni avec la masse.
+
+use bevy::prelude::*;
+use void_engine::dynamics::nbody::{sample_direct, PointSource};
+use void_engine::dynamics::{ForceAccumulator, Mass, Octree};
+use void_engine::substrate::DomainTopology;
+
+/// Nuage reproductible : positions dans `[-10, 10]³`, poids dans `[0.5, 1.5]`.
+fn cloud(count: usize) -> Vec<PointSource> {
+    let mut state = 0x2545_f491_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32
+    };
+    (0..count)
+        .map(|_| PointSource {
+            position: Vec3::new(next(), next(), next()) * 20.0 - 10.0,
+            weight: 0.5 + next(),
+        })
+        .collect()
+}
+
+/// Plus grand écart relatif du champ de l’arbre à la somme directe, sur toutes les sources.
+fn max_relative_error(sources: &[PointSource], theta: f32) -> f32 {
+    let topology = DomainTopology::default();
+    let tree = Octree::build(sources.to_vec());
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let exact = sample_direct(sources, source.position, Some(i), 0.1, &topology);
+            let approx = tree.sample(source.position, Some(i), theta, 0.1, &topology);
+            (approx.field - exact.field).length() / exact.field.length()
+        })
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn octree_matches_direct_sum_within_tolerance() {
+    let sources = cloud(400);
+
+    // θ = 0 : aucun nœud n’est résumé, le résultat est celui de la somme directe.
+    let exact = max_relative_error(&sources, 0.0);
+    assert!(exact < 1e-4, "θ = 0: {exact}");
+
+    // Approximation monopolaire : l’erreur décroît avec l’angle d’ouverture.
+    let coarse = max_relative_error(&sources, 0.5);
+    assert!(coarse < 5e-2, "θ = 0.5: {coarse}");
+    let fine = max_relative_error(&sources, 0.2);
+    assert!(fine < 1e-3, "θ = 0.2: {fine}");
+    assert!(fine <= coarse);
+}
+
+#[test]
+fn mass_brings_its_force_accumulator() {
+    let mut world = World::new();
+    let body = world.spawn(Mass { value: 1.0 }).id();
+    assert!(world.get::<ForceAccumulator>(body).is_some());
+}
@@ -0,0 +1,63 @@
+//! Réserve de particules : une particule expirée perd tous ses composants, y compris ceux
+//! ajoutés par la simulation ou par l’utilisateur, avant d’être réutilisée.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{
+    BodyEnergy, Collider, Drag, Emitter, ForceAccumulator, Mass, Particle, ParticleStats,
+    PooledParticle, Velocity,
+};
+use void_engine::substrate::PotentialForce;
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+#[test]
+fn pooled_particles_are_stripped_before_reuse() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(17)
+        .build();
+    let emitter = engine.spawn((
+        Transform::default(),
+        Emitter::burst(4).with_lifetime(0.1, 0.0),
+    ));
+
+    engine.run_ticks(2);
+    let particles: Vec<Entity> = engine
+        .world_mut()
+        .query_filtered::<Entity, With<Particle>>()
+        .iter(engine.world())
+        .collect();
+    assert_eq!(particles.len(), 4);
+    for &particle in &particles {
+        assert!(engine.component::<ForceAccumulator>(particle).is_some());
+        assert!(engine.component::<PotentialForce>(particle).is_some());
+        assert!(engine.component::<BodyEnergy>(particle).is_some());
+        engine
+            .world_mut()
+            .entity_mut(particle)
+            .insert((Collider::sphere(0.1), Drag::default()));
+    }
+
+    engine.run_ticks(10);
+    assert_eq!(engine.resource::<ParticleStats>().pooled, 4);
+    for &particle in &particles {
+        let entity = engine.world().entity(particle);
+        assert!(entity.contains::<PooledParticle>());
+        assert_eq!(entity.archetype().component_count(), 1);
+    }
+
+    // La salve suivante réutilise les entités, sans les composants de leur vie passée.
+    engine
+        .world_mut()
+        .get_mut::<Emitter>(emitter)
+        .unwrap()
+        .burst = 4;
+    engine.run_ticks(2);
+    assert_eq!(engine.resource::<ParticleStats>().pooled, 0);
+    for &particle in &particles {
+        let entity = engine.world().entity(particle);
+        assert!(entity.contains::<Particle>() && entity.contains::<Mass>());
+        assert!(entity.contains::<Velocity>());
+        assert!(!entity.contains::<Collider>() && !entity.contains::<Drag>());
+    }
+}
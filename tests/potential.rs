@@ -0,0 +1,51 @@
+//! Potentiels de grille : échantillonnage des grilles 2D hors de leur plan et gradient
+//! aux bords de la grille.
+
+use bevy::prelude::*;
+use void_engine::substrate::{Field, FieldRule, PotentialKind};
+
+/// Rampe `φ = x` sur une grille 2D de pas unitaire.
+fn ramp() -> Field {
+    let rule = FieldRule::ReactionDiffusion {
+        diffusion: 0.0,
+        reaction: 0.0,
+    };
+    let mut field = Field::scalar_2d("ramp", rule, 8, 8);
+    for y in 0..8 {
+        for x in 0..8 {
+            let index = field.index(x, y, 0);
+            field.set_scalar(index, x as f32);
+        }
+    }
+    field
+}
+
+#[test]
+fn planar_grid_is_sampled_off_plane() {
+    let field = ramp();
+    assert_eq!(field.sample_scalar(Vec3::new(2.5, 3.0, 0.0)), Some(2.5));
+    assert_eq!(field.sample_scalar(Vec3::new(2.5, 3.0, 4.0)), Some(2.5));
+    assert_eq!(field.sample_scalar(Vec3::new(2.5, 3.0, -1.0)), Some(2.5));
+    assert_eq!(field.sample_scalar(Vec3::new(9.0, 3.0, 0.0)), None);
+}
+
+#[test]
+fn grid_gradient_is_exact_at_edges() {
+    let field = ramp();
+    let kind = PotentialKind::Grid {
+        field: "ramp".into(),
+        scale: 2.0,
+    };
+    for x in [0.0, 0.2, 3.5, 6.8, 7.0] {
+        let position = Vec3::new(x, 4.0, 1.5);
+        let (force, energy) = kind.evaluate(Vec3::ZERO, position, 1.0, [&field]);
+        assert!(
+            (energy - 2.0 * x).abs() < 1e-5,
+            "energy at x = {x}: {energy}"
+        );
+        assert!(
+            (force - Vec3::new(-2.0, 0.0, 0.0)).length() < 1e-4,
+            "force at x = {x}: {force}"
+        );
+    }
+}
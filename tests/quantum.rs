@@ -0,0 +1,162 @@
+//! Simulation quantique : état de Bell préparé par H + CNOT, oscillation de Rabi sous σx,
+//! unitarité du propagateur d’un hamiltonien complexe et statistiques de mesure à graine
+//! fixée.
+
+use nalgebra::DMatrix;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use void_engine::dynamics::quantum::C64;
+use void_engine::dynamics::{Gate, Hamiltonian, QuantumState};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn c(re: f64, im: f64) -> C64 {
+    C64::new(re, im)
+}
+
+fn sigma_x() -> DMatrix<C64> {
+    Gate::x().matrix().clone()
+}
+
+fn sigma_y() -> DMatrix<C64> {
+    Gate::y().matrix().clone()
+}
+
+fn bell() -> QuantumState {
+    let mut state = QuantumState::qubits(2);
+    state.apply(&Gate::h(), &[0]).unwrap();
+    state.apply(&Gate::cnot(), &[0, 1]).unwrap();
+    state
+}
+
+#[test]
+fn hadamard_and_cnot_prepare_bell_state() {
+    let state = bell();
+    let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+    let expected = [amplitude, 0.0, 0.0, amplitude];
+    for (found, expected) in state.amplitudes().iter().zip(expected) {
+        assert!((found - c(expected, 0.0)).norm() < TOLERANCE, "{found}");
+    }
+    // Chaque qubit, pris seul, est parfaitement aléatoire.
+    for target in 0..2 {
+        let marginal = state.marginal(&[target]).unwrap();
+        assert!((marginal[0] - 0.5).abs() < TOLERANCE, "{marginal:?}");
+    }
+}
+
+#[test]
+fn rabi_oscillation_under_sigma_x() {
+    // H = Ω/2 σx : P₁(t) = sin²(Ω t / 2).
+    let omega = 2.0;
+    let hamiltonian = Hamiltonian::new(sigma_x() * c(omega / 2.0, 0.0)).unwrap();
+    for step in 0..=40 {
+        let t = step as f64 * 0.1;
+        let mut state = QuantumState::qubits(1);
+        state.apply_unitary(&hamiltonian.propagator(t)).unwrap();
+        let excited = state.probabilities()[1];
+        let expected = (omega * t / 2.0).sin().powi(2);
+        assert!(
+            (excited - expected).abs() < 1e-9,
+            "t = {t}: {excited} vs {expected}"
+        );
+        assert!((state.norm() - 1.0).abs() < TOLERANCE);
+    }
+}
+
+#[test]
+fn propagator_of_complex_hamiltonian_is_unitary() {
+    // Hamiltonien hermitien à coefficients complexes sur deux qubits.
+    let h = sigma_y().kronecker(&sigma_x()) * c(0.7, 0.0)
+        + DMatrix::from_row_slice(
+            4,
+            4,
+            &[
+                c(1.0, 0.0),
+                c(0.2, -0.5),
+                c(0.0, 0.0),
+                c(0.0, 0.3),
+                c(0.2, 0.5),
+                c(-0.4, 0.0),
+                c(0.1, 0.1),
+                c(0.0, 0.0),
+                c(0.0, 0.0),
+                c(0.1, -0.1),
+                c(0.3, 0.0),
+                c(0.6, 0.0),
+                c(0.0, -0.3),
+                c(0.0, 0.0),
+                c(0.6, 0.0),
+                c(-0.9, 0.0),
+            ],
+        );
+    let hamiltonian = Hamiltonian::new(h.clone()).unwrap();
+    let identity = DMatrix::<C64>::identity(4, 4);
+
+    for t in [0.1, 1.0, 7.3] {
+        let u = hamiltonian.propagator(t);
+        assert!(
+            (u.adjoint() * &u - &identity).norm() < 1e-9,
+            "U†U ≠ I at t = {t}"
+        );
+        // Groupe à un paramètre : U(t) U(t) = U(2t).
+        assert!((&u * &u - hamiltonian.propagator(2.0 * t)).norm() < 1e-9);
+    }
+
+    // Dérivée en 0 : (U(ε) - U(-ε)) / 2ε = -i H.
+    let epsilon = 1e-5;
+    let derivative = (hamiltonian.propagator(epsilon) - hamiltonian.propagator(-epsilon))
+        * c(0.5 / epsilon, 0.0);
+    assert!((derivative - h * c(0.0, -1.0)).norm() < 1e-6);
+
+    // exp(-i θ σy) = cos θ I - i sin θ σy.
+    let theta = 0.8_f64;
+    let u = Hamiltonian::new(sigma_y()).unwrap().propagator(theta);
+    let expected =
+        DMatrix::<C64>::identity(2, 2) * c(theta.cos(), 0.0) - sigma_y() * c(0.0, theta.sin());
+    assert!((u - expected).norm() < 1e-12);
+}
+
+#[test]
+fn measurement_statistics_follow_born_rule() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let runs = 4000;
+
+    // État de Bell : résultats corrélés, équiprobables.
+    let mut ones = 0;
+    for _ in 0..runs {
+        let mut state = bell();
+        let (levels, probability) = state.measure(&[0, 1], &mut rng).unwrap();
+        assert_eq!(levels[0], levels[1], "uncorrelated outcome {levels:?}");
+        assert!((probability - 0.5).abs() < TOLERANCE);
+        ones += levels[0];
+        // Réduction : mesurer à nouveau redonne le même résultat.
+        assert_eq!(state.measure(&[0, 1], &mut rng).unwrap().0, levels);
+    }
+    let fraction = ones as f64 / runs as f64;
+    assert!((fraction - 0.5).abs() < 0.03, "P(11) = {fraction}");
+
+    // Ry(θ)|0⟩ : P₁ = sin²(θ/2).
+    let theta = 1.0_f64;
+    let mut ones = 0;
+    for _ in 0..runs {
+        let mut state = QuantumState::qubits(1);
+        state.apply(&Gate::ry(theta), &[0]).unwrap();
+        ones += state.measure(&[0], &mut rng).unwrap().0[0];
+    }
+    let expected = (theta / 2.0).sin().powi(2);
+    let fraction = ones as f64 / runs as f64;
+    // Écart type de la fréquence : √(p(1-p)/n) ≈ 0.007.
+    assert!(
+        (fraction - expected).abs() < 0.03,
+        "{fraction} vs {expected}"
+    );
+
+    // Même graine, même suite de résultats.
+    let outcomes = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..32)
+            .map(|_| bell().measure(&[0], &mut rng).unwrap().0[0])
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(outcomes(7), outcomes(7));
+}
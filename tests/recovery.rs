@@ -0,0 +1,186 @@
+//! Reprise du GPU : recul exponentiel borné, délai d’initialisation dépassé, abandon après
+//! `max_attempts` ou lorsque la configuration rejette tous les adaptateurs, et bascule sur
+//! le pool CPU partagé à la perte du device.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use void_engine::substrate::{
+    ComputeBackend, ComputeBackendKind, CpuCompute, GpuConfig, GpuContext, GpuEvent, GpuHealth,
+    GpuRecoveryConfig, GpuStatus,
+};
+use void_engine::testing::{TestEngine, DEFAULT_TICK};
+use void_engine::Layer;
+
+fn engine(recovery: GpuRecoveryConfig) -> TestEngine {
+    TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Substrate])
+        .with_setup(move |app| {
+            app.insert_resource(recovery);
+        })
+        .build()
+}
+
+fn events(engine: &TestEngine) -> Vec<GpuEvent> {
+    engine
+        .resource::<Messages<GpuEvent>>()
+        .iter_current_update_messages()
+        .cloned()
+        .collect()
+}
+
+/// Le backend actif est le CPU, sur le pool partagé créé au démarrage.
+fn assert_shared_cpu(engine: &TestEngine) {
+    let backend = engine.resource::<ComputeBackend>();
+    assert_eq!(backend.kind(), ComputeBackendKind::Cpu);
+    assert_eq!(backend.cpu(), Some(engine.resource::<CpuCompute>()));
+}
+
+#[test]
+fn backoff_doubles_up_to_max() {
+    let config = GpuRecoveryConfig {
+        initial_backoff: Duration::from_millis(250),
+        max_backoff: Duration::from_secs(3),
+        ..default()
+    };
+    let delays: Vec<u128> = (1..=6)
+        .map(|attempt| config.backoff(attempt).as_millis())
+        .collect();
+    assert_eq!(delays, [250, 500, 1000, 2000, 3000, 3000]);
+    assert_eq!(config.backoff(0), config.initial_backoff);
+    assert_eq!(config.backoff(u32::MAX), config.max_backoff);
+}
+
+#[test]
+fn startup_timeout_expires_pending_initialization() {
+    let config = GpuRecoveryConfig {
+        startup_timeout: Duration::from_secs(10),
+        ..default()
+    };
+    let started = Duration::from_secs(5);
+    assert_eq!(
+        config.startup_expired(started, Duration::from_secs(14)),
+        None
+    );
+    assert_eq!(
+        config.startup_expired(started, Duration::from_secs(15)),
+        Some(Duration::from_secs(10))
+    );
+    // Horloge antérieure au lancement : aucune attente.
+    assert_eq!(config.startup_expired(started, Duration::ZERO), None);
+}
+
+#[test]
+fn retries_back_off_until_max_attempts() {
+    let config = GpuRecoveryConfig {
+        initial_backoff: Duration::from_secs(1),
+        max_attempts: Some(3),
+        ..default()
+    };
+    let mut health = GpuHealth::default();
+    let now = Duration::from_secs(100);
+    for attempt in 1..=3 {
+        health.schedule_retry(&config, now, "initialization timed out".into());
+        assert_eq!(health.status, GpuStatus::Unavailable);
+        assert_eq!(health.attempts, attempt);
+        assert_eq!(health.retry_at, Some(now + config.backoff(attempt)));
+    }
+
+    health.schedule_retry(&config, now, "initialization timed out".into());
+    assert_eq!(health.status, GpuStatus::Abandoned);
+    assert_eq!(health.retry_at, None);
+    assert_eq!(
+        health.last_error.as_deref(),
+        Some("initialization timed out")
+    );
+
+    // Sans limite, les reprises continuent au recul maximal.
+    let unlimited = GpuRecoveryConfig {
+        max_attempts: None,
+        ..config
+    };
+    let mut health = GpuHealth::default();
+    for _ in 0..20 {
+        health.schedule_retry(&unlimited, now, "device lost".into());
+    }
+    assert_eq!(health.status, GpuStatus::Unavailable);
+    assert_eq!(health.retry_at, Some(now + unlimited.max_backoff));
+}
+
+#[test]
+fn rejected_adapters_abandon_recovery() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Substrate])
+        .with_setup(|app| {
+            app.insert_resource(GpuConfig {
+                adapter_name: Some("no such adapter".into()),
+                ..default()
+            });
+        })
+        .build();
+    engine.run_ticks(3);
+
+    // La configuration rejettera les mêmes adaptateurs à chaque tentative.
+    let health = engine.resource::<GpuHealth>();
+    assert_eq!(health.status, GpuStatus::Abandoned);
+    assert_eq!(health.retry_at, None);
+    assert!(health.last_error.is_some());
+    assert_shared_cpu(&engine);
+}
+
+#[test]
+fn device_loss_switches_to_cpu_and_recovers() {
+    let mut engine = engine(GpuRecoveryConfig {
+        initial_backoff: DEFAULT_TICK,
+        ..default()
+    });
+    if !wait_for_gpu(&mut engine) {
+        eprintln!(
+            "skipping device loss test: {:?}",
+            engine.resource::<GpuHealth>().last_error
+        );
+        return;
+    }
+
+    engine.resource::<GpuContext>().device.destroy();
+    let mut lost = false;
+    for _ in 0..20 {
+        engine.tick();
+        if events(&engine)
+            .iter()
+            .any(|event| matches!(event, GpuEvent::DeviceLost { .. }))
+        {
+            lost = true;
+            break;
+        }
+    }
+    assert!(lost, "device loss was not reported");
+    assert_eq!(engine.resource::<GpuHealth>().device_losses, 1);
+    assert_eq!(
+        engine.resource::<GpuHealth>().status,
+        GpuStatus::Unavailable
+    );
+    assert!(engine.world().get_resource::<GpuContext>().is_none());
+    assert_shared_cpu(&engine);
+
+    // La reprise planifiée réinitialise un nouveau device.
+    assert!(wait_for_gpu(&mut engine), "GPU did not recover");
+    assert_eq!(engine.resource::<GpuHealth>().attempts, 0);
+    assert_eq!(
+        engine.resource::<ComputeBackend>().kind(),
+        ComputeBackendKind::Gpu
+    );
+}
+
+/// Avance jusqu’à ce que le GPU soit prêt ; `false` s’il est abandonné ou trop lent.
+fn wait_for_gpu(engine: &mut TestEngine) -> bool {
+    for _ in 0..400 {
+        engine.tick();
+        match engine.resource::<GpuHealth>().status {
+            GpuStatus::Ready => return true,
+            GpuStatus::Abandoned => return false,
+            _ => std::thread::sleep(Duration::from_millis(5)),
+        }
+    }
+    false
+}
@@ -0,0 +1,79 @@
+//! Dynamique de rotation : accélération angulaire des petits corps et conservation du
+//! moment cinétique d’une toupie asymétrique libre.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{Inertia, Mass, Torque, Velocity};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+fn engine() -> TestEngine {
+    TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(3)
+        .build()
+}
+
+#[test]
+fn small_sphere_accelerates_under_constant_torque() {
+    let mut engine = engine();
+    // Sphère pleine de 10 cm et 1 kg : I = 0.004, det I = 6.4e-8.
+    let inertia = Inertia::solid_sphere(1.0, 0.1);
+    assert!(Inertia::invert(inertia.tensor).is_some());
+
+    let torque = Vec3::new(0.0, 0.0, 1e-3);
+    let sphere = engine.spawn((
+        Transform::default(),
+        Velocity::default(),
+        Mass { value: 1.0 },
+        inertia,
+        Torque { vector: torque },
+    ));
+    engine.run_ticks(120);
+
+    let t = engine.resource::<Time<Fixed>>().elapsed_secs();
+    let angular = engine.component::<Velocity>(sphere).unwrap().angular;
+    let expected = torque / 0.004 * t;
+    assert!(
+        (angular - expected).length() < 1e-3 * expected.length(),
+        "ω = {angular} after {t} s, expected {expected}"
+    );
+}
+
+#[test]
+fn free_asymmetric_top_conserves_angular_momentum() {
+    let mut engine = engine();
+    let inertia = Inertia::diagonal(Vec3::new(1.0, 2.0, 3.0));
+    // Rotation proche de l’axe intermédiaire (instable) : le corps bascule périodiquement.
+    let initial = Vec3::new(0.05, 2.0, 0.05);
+    let top = engine.spawn((
+        Transform::default(),
+        Velocity {
+            angular: initial,
+            ..default()
+        },
+        Mass { value: 1.0 },
+        inertia,
+    ));
+    let momentum = inertia.world(Quat::IDENTITY) * initial;
+    let energy = inertia.angular_kinetic_energy(Quat::IDENTITY, initial);
+
+    let mut tumbled = false;
+    for _ in 0..60 {
+        engine.run_ticks(10);
+        let rotation = engine.component::<Transform>(top).unwrap().rotation;
+        let angular = engine.component::<Velocity>(top).unwrap().angular;
+        let now = inertia.world(rotation) * angular;
+        assert!(
+            (now - momentum).length() < 1e-3 * momentum.length(),
+            "L = {now}, expected {momentum}"
+        );
+        let kinetic = inertia.angular_kinetic_energy(rotation, angular);
+        assert!(
+            (kinetic - energy).abs() < 1e-2 * energy,
+            "E = {kinetic}, expected {energy}"
+        );
+        // Effet Djanibekov : la rotation propre autour de l’axe intermédiaire s’inverse.
+        tumbled |= (rotation.inverse() * angular).y < 0.0;
+    }
+    assert!(tumbled, "ω never left the intermediate axis");
+}
@@ -0,0 +1,103 @@
+//! Harnais headless : construction d’un [`TestEngine`], avance tick par tick et helpers
+//! d’assertion.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use void_engine::core::EngineSeed;
+use void_engine::dynamics::{IntegratorConfig, Mass, Velocity};
+use void_engine::testing::{TestEngine, DEFAULT_TICK};
+use void_engine::Layer;
+
+#[test]
+fn test_engine_advances_ticks_and_simulated_time() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .tick_duration(Duration::from_millis(10))
+        .seed(42)
+        .build();
+    assert_eq!(engine.ticks(), 0);
+
+    engine.run_ticks(25);
+    assert_eq!(engine.ticks(), 25);
+    assert_eq!(engine.elapsed(), Duration::from_millis(250));
+    engine.assert_resource::<EngineSeed>(|seed| seed.0 == 42);
+}
+
+#[test]
+fn test_engine_moves_bodies_and_checks_components() {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .build();
+    let body = engine.spawn((
+        Transform::default(),
+        Velocity {
+            linear: Vec3::X,
+            angular: Vec3::ZERO,
+        },
+        Mass { value: 1.0 },
+    ));
+    assert_eq!(engine.count::<Velocity>(), 1);
+
+    engine.run_ticks(60);
+    let expected = DEFAULT_TICK.as_secs_f32() * 60.0;
+    engine.assert_component::<Transform>(body, |t| t.translation.x > 0.5 * expected);
+    engine.assert_component::<Velocity>(body, |v| (v.linear - Vec3::X).length() < 1e-4);
+}
+
+#[test]
+fn test_engine_records_snapshots_in_memory() {
+    let mut engine = TestEngine::builder()
+        .with_layer(Layer::Reflection)
+        .memory_capacity(16)
+        .build();
+    engine.run_ticks(10);
+
+    assert!(!engine.snapshots("reflection").is_empty());
+    assert!(engine.memory().snapshots().len() <= 16);
+    engine.assert_snapshot("reflection", |snapshot| snapshot.get("coherence").is_some());
+}
+
+#[test]
+fn test_engine_runs_setup_hooks() {
+    #[derive(Resource)]
+    struct Marker(u32);
+
+    let engine = TestEngine::builder()
+        .with_setup(|app| {
+            app.insert_resource(Marker(7));
+        })
+        .build();
+    engine.assert_resource::<Marker>(|marker| marker.0 == 7);
+}
+
+#[test]
+#[should_panic(expected = "failed assertion")]
+fn test_engine_assertions_report_failures() {
+    let engine = TestEngine::builder().with_layer(Layer::Core).seed(1).build();
+    engine.assert_resource::<EngineSeed>(|seed| seed.0 == 2);
+}
+
+#[test]
+fn each_layer_runs_with_its_declared_dependencies() {
+    for layer in Layer::ALL {
+        // Les dépendances précèdent toujours la couche dans l’ordre d’initialisation.
+        assert!(
+            layer
+                .dependencies()
+                .iter()
+                .all(|dependency| *dependency < layer),
+            "{layer:?}"
+        );
+
+        let mut engine = TestEngine::builder().with_layer(layer).build();
+        engine.run_ticks(3);
+        assert!(
+            engine.world().contains_resource::<EngineSeed>(),
+            "{layer:?}"
+        );
+    }
+
+    let engine = TestEngine::builder().with_layer(Layer::Structure).build();
+    assert!(engine.world().contains_resource::<IntegratorConfig>());
+}
@@ -0,0 +1,132 @@
+//! Thermostat : Langevin et Berendsen amènent la température cinétique mesurée à la cible,
+//! le bruit de Langevin est reproductible pour une graine, et la cible entropique suit
+//! l’entropie globale de la boucle de rétroaction.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{Mass, ThermostatConfig, ThermostatKind, ThermostatStats, Velocity};
+use void_engine::function::FeedbackLoop;
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+const BODIES: usize = 200;
+
+/// Corps massifs sans collision, aux vitesses initiales `velocity(i)`.
+fn engine(seed: u64, config: ThermostatConfig, velocity: impl Fn(usize) -> Vec3) -> TestEngine {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Dynamics])
+        .seed(seed)
+        .build();
+    *engine.resource_mut::<ThermostatConfig>() = config;
+    for i in 0..BODIES {
+        engine.spawn((
+            Transform::from_xyz(i as f32 * 2.0, 0.0, 0.0),
+            Velocity {
+                linear: velocity(i),
+                ..default()
+            },
+            Mass {
+                value: 0.5 + (i % 4) as f32 * 0.5,
+            },
+        ));
+    }
+    engine
+}
+
+/// Température cinétique moyenne sur `ticks` ticks, une fois `warmup` ticks écoulés.
+fn mean_temperature(engine: &mut TestEngine, warmup: u64, ticks: u64) -> f32 {
+    engine.run_ticks(warmup);
+    let mut sum = 0.0;
+    for _ in 0..ticks {
+        engine.tick();
+        sum += engine.resource::<ThermostatStats>().kinetic_temperature;
+    }
+    sum / ticks as f32
+}
+
+fn velocities(engine: &mut TestEngine) -> Vec<Vec3> {
+    engine
+        .world_mut()
+        .query::<&Velocity>()
+        .iter(engine.world())
+        .map(|velocity| velocity.linear)
+        .collect()
+}
+
+fn langevin(target_temperature: f32) -> ThermostatConfig {
+    ThermostatConfig {
+        enabled: true,
+        kind: ThermostatKind::Langevin,
+        target_temperature,
+        damping: 5.0,
+        ..default()
+    }
+}
+
+#[test]
+fn langevin_thermalizes_bodies_at_rest() {
+    let mut engine = engine(5, langevin(2.0), |_| Vec3::ZERO);
+    let temperature = mean_temperature(&mut engine, 120, 240);
+    let stats = engine.resource::<ThermostatStats>();
+    assert_eq!(stats.bodies, BODIES);
+    assert_eq!(stats.target_temperature, 2.0);
+    assert!((temperature - 2.0).abs() < 0.1, "T = {temperature}");
+}
+
+#[test]
+fn berendsen_relaxes_toward_target() {
+    let config = ThermostatConfig {
+        enabled: true,
+        kind: ThermostatKind::Berendsen,
+        target_temperature: 3.0,
+        damping: 5.0,
+        ..default()
+    };
+    // Vitesses déterministes et hétérogènes, température initiale bien en dessous de T₀.
+    let mut engine = engine(5, config, |i| {
+        let angle = i as f32 * 0.7;
+        Vec3::new(angle.cos(), angle.sin(), (angle * 0.3).cos()) * 0.5
+    });
+    engine.run_ticks(2);
+    let initial = engine.resource::<ThermostatStats>().kinetic_temperature;
+    assert!(initial < 1.0, "T = {initial}");
+
+    engine.run_ticks(240);
+    let temperature = engine.resource::<ThermostatStats>().kinetic_temperature;
+    assert!((temperature - 3.0).abs() < 0.01, "T = {temperature}");
+}
+
+#[test]
+fn langevin_noise_is_reproducible_per_seed() {
+    let run = |seed| {
+        let mut engine = engine(seed, langevin(1.0), |_| Vec3::ZERO);
+        engine.run_ticks(30);
+        velocities(&mut engine)
+    };
+    let first = run(9);
+    assert!(first.iter().any(|velocity| *velocity != Vec3::ZERO));
+    assert_eq!(first, run(9));
+    assert_ne!(first, run(10));
+}
+
+#[test]
+fn entropy_driven_target_follows_global_entropy() {
+    let config = ThermostatConfig {
+        entropy_driven: true,
+        entropy_scale: 0.5,
+        ..langevin(10.0)
+    };
+    let mut engine = engine(5, config, |_| Vec3::ZERO);
+    engine.world_mut().insert_resource(FeedbackLoop {
+        global_entropy: 4.0,
+        ..default()
+    });
+
+    let temperature = mean_temperature(&mut engine, 120, 240);
+    assert_eq!(engine.resource::<ThermostatStats>().target_temperature, 2.0);
+    assert!((temperature - 2.0).abs() < 0.1, "T = {temperature}");
+
+    // La cible suit l’entropie au pas suivant.
+    engine.resource_mut::<FeedbackLoop>().global_entropy = 1.0;
+    engine.run_ticks(2);
+    assert_eq!(engine.resource::<ThermostatStats>().target_temperature, 0.5);
+}
@@ -0,0 +1,38 @@
+//! Domaines périodiques dont un axe est de taille nulle : l’axe reste illimité, sans NaN
+//! dans les distances ni les positions repliées.
+
+use bevy::prelude::*;
+use void_engine::substrate::{DomainTopology, FieldBoundary};
+
+/// Tore plan : `x` et `y` périodiques sur `[0, 10]`, `z` de taille nulle.
+fn flat_torus() -> DomainTopology {
+    DomainTopology::Periodic {
+        min: Vec3::ZERO,
+        max: Vec3::new(10.0, 10.0, 0.0),
+    }
+}
+
+#[test]
+fn zero_size_axis_is_not_periodic() {
+    let topology = flat_torus();
+    assert_eq!(topology.periodic_axes(), BVec3::new(true, true, false));
+
+    let displacement = topology.displacement(Vec3::new(1.0, 1.0, 0.0), Vec3::new(9.0, 1.0, 3.0));
+    assert_eq!(displacement, Vec3::new(-2.0, 0.0, 3.0));
+
+    let wrapped = topology.wrap(Vec3::new(12.0, -1.0, 5.0));
+    assert_eq!(wrapped, Vec3::new(2.0, 9.0, 5.0));
+
+    let mut position = Vec3::new(1.0, 1.0, -4.0);
+    let mut velocity = Vec3::Z;
+    topology.apply(&mut position, &mut velocity);
+    assert_eq!(position, Vec3::new(1.0, 1.0, -4.0));
+    assert_eq!(topology.field_boundary(), FieldBoundary::Periodic);
+
+    let degenerate = DomainTopology::Periodic {
+        min: Vec3::ONE,
+        max: Vec3::ONE,
+    };
+    assert!(degenerate.distance(Vec3::ZERO, Vec3::X).is_finite());
+    assert_eq!(degenerate.field_boundary(), FieldBoundary::Neumann);
+}
@@ -0,0 +1,82 @@
+//! Fluctuations du vide : à graine identique, deux moteurs perturbent champs, forces et
+//! entropie exactement de la même façon ; une autre graine donne d’autres perturbations.
+
+use bevy::prelude::*;
+use void_engine::dynamics::{Force, ForceAccumulator, ForceSource, Mass};
+use void_engine::function::FeedbackLoop;
+use void_engine::substrate::{Field, FieldRule, GpuConfig, VacuumConfig};
+use void_engine::testing::TestEngine;
+use void_engine::Layer;
+
+/// Perturbations observées : grille du champ, force du vide à chaque pas, entropie globale.
+#[derive(Debug, PartialEq)]
+struct Perturbations {
+    field: Vec<f32>,
+    forces: Vec<Vec3>,
+    entropy: f32,
+}
+
+fn run(seed: u64) -> Perturbations {
+    let mut engine = TestEngine::builder()
+        .with_layers(&[Layer::Core, Layer::Substrate, Layer::Dynamics])
+        .seed(seed)
+        .with_setup(|app| {
+            // Champs pas à pas sur CPU : aucune relecture GPU asynchrone.
+            app.insert_resource(GpuConfig {
+                adapter_name: Some("no such adapter".into()),
+                ..default()
+            })
+            .insert_resource(FeedbackLoop::default())
+            .insert_resource(VacuumConfig {
+                enabled: true,
+                field_amplitude: 1.0,
+                force_amplitude: 1.0,
+                entropy_amplitude: 1.0,
+                ..default()
+            });
+        })
+        .build();
+    let field = engine.spawn(Field::scalar_2d(
+        "vacuum",
+        FieldRule::ReactionDiffusion {
+            diffusion: 0.1,
+            reaction: 0.0,
+        },
+        16,
+        16,
+    ));
+    let body = engine.spawn((
+        Transform::from_xyz(0.3, -1.2, 2.5),
+        Force {
+            direction: Vec3::X,
+            magnitude: 1.0,
+        },
+        Mass { value: 1.0 },
+    ));
+
+    let mut forces = Vec::new();
+    for _ in 0..40 {
+        engine.tick();
+        let accumulator = engine.component::<ForceAccumulator>(body).unwrap();
+        forces.push(accumulator.get(ForceSource::Vacuum));
+    }
+    Perturbations {
+        field: engine.component::<Field>(field).unwrap().data().to_vec(),
+        forces,
+        entropy: engine.resource::<FeedbackLoop>().global_entropy,
+    }
+}
+
+#[test]
+fn perturbations_are_reproducible_per_seed() {
+    let first = run(42);
+    assert!(first.field.iter().any(|value| *value != 0.0));
+    assert!(first.forces.iter().any(|force| *force != Vec3::ZERO));
+    assert!(first.entropy > 0.0);
+    assert_eq!(first, run(42));
+
+    let other = run(43);
+    assert_ne!(first.field, other.field);
+    assert_ne!(first.forces, other.forces);
+    assert_ne!(first.entropy, other.entropy);
+}